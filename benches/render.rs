@@ -0,0 +1,47 @@
+//! Benchmarks of the render path.
+//!
+//! The target is rendering a 10k character string in under a millisecond, run them
+//! with `cargo bench`.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use print_big_text_rs::{
+    color::{ColorStyle, Rgb},
+    layout::Alignment,
+    BigText,
+};
+use std::hint::black_box;
+
+/// Creates a string of `length` characters from the built-in maps.
+fn text(length: usize) -> String {
+    "HELLO, WORLD! 0123456789 "
+        .chars()
+        .cycle()
+        .take(length)
+        .collect()
+}
+
+fn render(c: &mut Criterion) {
+    let printer = BigText::new(&text(10_000), None);
+    c.bench_function("render 10k", |b| b.iter(|| black_box(&printer).to_string()));
+
+    let mut printer = BigText::new(&text(10_000), None);
+    printer
+        .set_width(Some(200))
+        .set_alignment(Alignment::Center);
+    c.bench_function("render 10k wrapped", |b| {
+        b.iter(|| black_box(&printer).to_string())
+    });
+
+    let mut printer = BigText::new(&text(10_000), None);
+    printer.set_color(Some(ColorStyle::Solid(Rgb::new(255, 0, 0))));
+    c.bench_function("render 10k colored", |b| {
+        b.iter(|| black_box(&printer).to_string())
+    });
+}
+
+fn construct(c: &mut Criterion) {
+    c.bench_function("new", |b| b.iter(|| BigText::new(black_box("HI"), None)));
+}
+
+criterion_group!(benches, render, construct);
+criterion_main!(benches);
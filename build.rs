@@ -0,0 +1,57 @@
+//! Generates the glyphs of the built-in character maps from the JSON files in `src`,
+//! so they don't have to be parsed at runtime.
+
+use std::{collections::BTreeMap, env, fs, path::Path};
+
+/// The built-in maps, the ones combined by `printables` first.
+const MAPS: [&str; 9] = [
+    "letters",
+    "digits",
+    "punctuation",
+    "whitespace",
+    "latin",
+    "arabic_indic_digits",
+    "symbols",
+    "blocks",
+    "slant",
+];
+
+/// The built-in maps only embedded when their feature is enabled, with the name of
+/// the feature.
+const OPTIONAL_MAPS: [(&str, &str); 3] = [
+    ("greek", "greek"),
+    ("hiragana", "kana"),
+    ("katakana", "kana"),
+];
+
+fn main() {
+    let mut generated = String::new();
+
+    let enabled = OPTIONAL_MAPS
+        .into_iter()
+        .filter(|(_, feature)| {
+            env::var_os(format!("CARGO_FEATURE_{}", feature.to_uppercase())).is_some()
+        })
+        .map(|(name, _)| name);
+
+    for name in MAPS.into_iter().chain(enabled) {
+        let path = format!("src/{name}.json");
+        println!("cargo:rerun-if-changed={path}");
+
+        let data = fs::read_to_string(&path).unwrap();
+        let map: BTreeMap<char, [String; 5]> = serde_json::from_str(&data)
+            .unwrap_or_else(|error| panic!("{path} is not a valid character map: {error}"));
+
+        generated.push_str(&format!(
+            "static {}_GLYPHS: &[(char, [&str; 5])] = &[\n",
+            name.to_uppercase()
+        ));
+        for (c, rows) in map {
+            generated.push_str(&format!("    ({c:?}, {rows:?}),\n"));
+        }
+        generated.push_str("];\n");
+    }
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("character_maps.rs");
+    fs::write(out, generated).unwrap();
+}
@@ -0,0 +1,34 @@
+#ifndef PRINT_BIG_TEXT_H
+#define PRINT_BIG_TEXT_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// A struct that prints strings in it's ascii-art form.
+typedef struct BigText BigText;
+
+// Creates a printer using the printables map, returning null if `text` isn't
+// valid UTF-8. A null `text` creates a printer without text.
+//
+// The printer must be freed with [pbt_free()].
+BigText *pbt_new(const char *text);
+
+// Sets the text of a printer, returning `0` on success and `-1` if `printer` is
+// null or `text` is null or isn't valid UTF-8.
+int pbt_set_text(BigText *printer, const char *text);
+
+// Renders the text of a printer into its ascii-art form, returning null if
+// `printer` is null.
+//
+// The string must be freed with [pbt_string_free()].
+char *pbt_render(const BigText *printer);
+
+// Frees a string returned by [pbt_render()], doing nothing if it is null.
+void pbt_string_free(char *string);
+
+// Frees a printer created by [pbt_new()], doing nothing if it is null.
+void pbt_free(BigText *printer);
+
+#endif  /* PRINT_BIG_TEXT_H */
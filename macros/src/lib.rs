@@ -0,0 +1,27 @@
+//! Procedural macros for [print_big_text_rs], rendering ascii-art at compile time.
+
+use print_big_text_rs::BigText;
+use proc_macro::{Literal, TokenStream, TokenTree};
+use syn::{parse_macro_input, LitStr};
+
+/// Renders a string literal into its ascii-art form at compile time, with the
+/// default printables map.
+///
+/// The macro expands to a `&'static str`, so static banners cost no work at
+/// runtime.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_macros::big_text;
+/// use print_big_text_rs::BigText;
+///
+/// const BANNER: &str = big_text!("HI");
+/// assert_eq!(BigText::new("HI", None).to_string(), BANNER);
+/// ```
+#[proc_macro]
+pub fn big_text(input: TokenStream) -> TokenStream {
+    let text = parse_macro_input!(input as LitStr).value();
+    let rendered = BigText::new(&text, None).to_string();
+
+    TokenTree::Literal(Literal::string(&rendered)).into()
+}
@@ -0,0 +1,13 @@
+use print_big_text_macros::big_text;
+use print_big_text_rs::BigText;
+
+static BANNER: &str = big_text!("HELLO\nWORLD");
+
+#[test]
+fn test_big_text() {
+    assert_eq!(BigText::new("HELLO\nWORLD", None).to_string(), BANNER);
+    assert_eq!(
+        BigText::new("\"42\"", None).to_string(),
+        big_text!("\"42\"")
+    );
+}
@@ -0,0 +1,820 @@
+//! A module for animating a [BigText] in the terminal.
+//!
+//! Animations are a sequence of frames, each one being the rows to print. The
+//! frames can be drawn in place with [play()] or in the alternate screen with an
+//! [AnimationSession].
+
+use crate::{
+    canvas::{self, Canvas},
+    color::{self, ColorStyle, Rgb},
+    display_width,
+    random::Random,
+    BigText,
+};
+use std::{
+    io::{self, Error, Stdout, Write},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Horizontally scrolls the ascii-art of a [BigText] through a fixed-width window.
+///
+/// The whole text is rendered on a single line followed by a blank gap as wide as
+/// the window, which loops around so the text scrolls in from the right after
+/// leaving on the left.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::BigText;
+///
+/// let printer = BigText::new("I", None);
+/// let marquee = printer.marquee(4);
+///
+/// assert_eq!(10, marquee.frame_count());
+/// assert_eq!("  * ", marquee.frame(0)[1]);
+/// assert_eq!(" *  ", marquee.frame(1)[1]);
+/// assert_eq!("   *", marquee.frame(9)[1]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Marquee {
+    /// The cells of the rows of the text followed by the gap, one per column.
+    rows: Vec<Vec<char>>,
+    /// The amount of columns shown at once.
+    window: usize,
+    /// The rows of every frame colored like the [BigText].
+    colors: Option<(color::ColorStyle, color::ColorFormat)>,
+}
+
+impl Marquee {
+    /// Gets the amount of columns shown at once.
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    /// Gets the amount of frames before the marquee loops around.
+    pub fn frame_count(&self) -> usize {
+        self.rows.first().map_or(0, |row| row.len())
+    }
+
+    /// Gets the rows of the frame scrolled by `index` columns.
+    ///
+    /// Indexes past the [frame count](Marquee::frame_count()) loop around.
+    pub fn frame(&self, index: usize) -> Vec<String> {
+        let count = self.frame_count();
+
+        self.rows
+            .iter()
+            .map(|row| {
+                let cells: Vec<char> = (0..self.window)
+                    .map(|column| row[(index + column) % count])
+                    .collect();
+                let row = canvas::join_cells(&cells);
+                match &self.colors {
+                    Some((style, format)) => {
+                        color::colorize_row(style, *format, &row, 0, self.window)
+                    }
+                    None => row,
+                }
+            })
+            .collect()
+    }
+
+    /// Gets the frames of a single loop of the marquee.
+    pub fn frames(&self) -> impl Iterator<Item = Vec<String>> + '_ {
+        (0..self.frame_count()).map(|index| self.frame(index))
+    }
+}
+
+impl BigText {
+    /// Creates a [Marquee] scrolling the stored string through a window of `window`
+    /// columns.
+    ///
+    /// Newlines are replaced by spaces and the [width](BigText::set_width) is ignored,
+    /// as the text is scrolled on a single line.
+    pub fn marquee(&self, window: usize) -> Marquee {
+        let chars: Vec<char> = self
+            .visible_chars(self.text())
+            .into_iter()
+            .map(|c| if c == '\n' { ' ' } else { c })
+            .collect();
+
+        let rows: Vec<Vec<char>> = self
+            .render_line(&self.cells(&chars), 0)
+            .into_iter()
+            .map(|row| canvas::row_cells(&row))
+            .collect();
+
+        // Padding every row to the widest one, as glyph rows can be ragged
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0) + window;
+        let rows = rows
+            .into_iter()
+            .map(|mut row| {
+                row.resize(width, ' ');
+                row
+            })
+            .collect();
+
+        Marquee {
+            rows,
+            window,
+            colors: self.color.clone().map(|style| (style, self.color_format)),
+        }
+    }
+}
+
+/// How much of a [BigText] each frame of a [typewriter](BigText::typewriter())
+/// animation reveals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RevealUnit {
+    /// Every frame reveals one more character.
+    #[default]
+    Character,
+    /// Every frame reveals one more column of the ascii-art.
+    Column,
+}
+
+impl BigText {
+    /// Gets the frames revealing the stored string one [unit](RevealUnit) at a time,
+    /// the last frame being the whole text.
+    ///
+    /// When revealing characters, every frame is the text typed so far laid out
+    /// like the whole text would be, so words move to the next line as they grow
+    /// past the [width](BigText::set_width). When revealing columns, the rows of
+    /// the whole text are cut off after the revealed columns.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{animation::RevealUnit, BigText};
+    ///
+    /// let printer = BigText::new("HI", None);
+    ///
+    /// let frames = printer.typewriter(RevealUnit::Character);
+    /// assert_eq!(2, frames.len());
+    /// assert_eq!("*   * ", frames[0][0]);
+    /// assert_eq!("*   * ***** ", frames[1][0]);
+    ///
+    /// let frames = printer.typewriter(RevealUnit::Column);
+    /// assert_eq!(12, frames.len());
+    /// assert_eq!("*  ", frames[2][0]);
+    /// ```
+    pub fn typewriter(&self, unit: RevealUnit) -> Vec<Vec<String>> {
+        let layout = self.render_rows(self.text());
+
+        let frames: Vec<Vec<String>> = match unit {
+            RevealUnit::Character => {
+                let chars = self.visible_chars(self.text());
+                (0..chars.len())
+                    .filter(|index| chars[*index] != '\n')
+                    .map(|index| {
+                        let typed: String = chars[..=index].iter().collect();
+                        self.render_prepared(&typed)
+                    })
+                    .collect()
+            }
+            RevealUnit::Column => {
+                let cells: Vec<Vec<char>> =
+                    layout.iter().map(|row| canvas::row_cells(row)).collect();
+                let width = cells.iter().map(Vec::len).max().unwrap_or(0);
+                (1..=width)
+                    .map(|columns| {
+                        cells
+                            .iter()
+                            .map(|row| canvas::join_cells(&row[..columns.min(row.len())]))
+                            .collect()
+                    })
+                    .collect()
+            }
+        };
+
+        frames
+            .iter()
+            .map(|frame| self.colorize_rows(frame, &layout))
+            .collect()
+    }
+}
+
+/// Draws every frame in place, waiting `delay` between frames.
+///
+/// After the first frame, the cursor is moved back up over the previous frame with
+/// ANSI escape codes and its rows are cleared before drawing the next one. The
+/// cursor is left below the last frame.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{animation, BigText};
+/// use std::time::Duration;
+///
+/// let printer = BigText::new("I", None);
+/// let mut vec = Vec::new();
+/// animation::play(printer.marquee(4).frames(), &mut vec, Duration::ZERO).unwrap();
+///
+/// let output = String::from_utf8(vec).unwrap();
+/// assert!(output.starts_with("\x1b[2K****\n\x1b[2K  * \n"));
+/// assert!(output.contains("\x1b[5A\x1b[2K****\n\x1b[2K *  \n"));
+/// ```
+pub fn play<I>(frames: I, stream: &mut dyn Write, delay: Duration) -> Result<(), Error>
+where
+    I: IntoIterator<Item = Vec<String>>,
+{
+    let mut previous = 0;
+
+    for (index, frame) in frames.into_iter().enumerate() {
+        if index > 0 {
+            thread::sleep(delay);
+        }
+        if previous > 0 {
+            write!(stream, "\x1b[{previous}A")?;
+        }
+        for row in &frame {
+            writeln!(stream, "\x1b[2K{row}")?;
+        }
+        stream.flush()?;
+        previous = frame.len();
+    }
+
+    Ok(())
+}
+
+/// An animation drawing a [Canvas] for any point in time.
+///
+/// Animations are driven by [AnimationSession::run] at a target frame rate,
+/// receiving the time since the animation started.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{animation::Animation, canvas::Canvas, BigText};
+/// use std::time::Duration;
+///
+/// /// Blinks a text every half a second.
+/// struct Blink(BigText);
+///
+/// impl Animation for Blink {
+///     fn frame(&mut self, t: Duration) -> Canvas {
+///         let mut canvas = Canvas::new(20, 5);
+///         if t.as_millis() / 500 % 2 == 0 {
+///             canvas.put_text(0, 0, &self.0);
+///         }
+///         canvas
+///     }
+/// }
+///
+/// let mut blink = Blink(BigText::new("HI", None));
+/// assert_ne!(Canvas::new(20, 5), blink.frame(Duration::ZERO));
+/// assert_eq!(Canvas::new(20, 5), blink.frame(Duration::from_millis(600)));
+/// ```
+pub trait Animation {
+    /// Draws the frame at `t` after the start of the animation.
+    fn frame(&mut self, t: Duration) -> Canvas;
+
+    /// Checks if the animation is over at `t`, animations never end by default.
+    fn is_finished(&self, _t: Duration) -> bool {
+        false
+    }
+}
+
+/// The amount of rain drops falling down every column.
+const MATRIX_PASSES: usize = 2;
+/// How long the revealed text stays on screen at the end of a [MatrixRain].
+const MATRIX_HOLD: Duration = Duration::from_secs(1);
+
+/// An [Animation] raining the characters of a font down the screen, revealing the
+/// text in big letters behind the drops.
+///
+/// The text is centered on the screen. Every column is passed by a few drops
+/// falling at different speeds, the cells of the text are revealed once the last
+/// drop of their column passed them.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{animation::{Animation, MatrixRain}, canvas::Canvas, BigText};
+/// use std::time::Duration;
+///
+/// let text = BigText::new("HI", None);
+/// let mut rain = MatrixRain::new(&text, 20, 9);
+///
+/// let mut revealed = Canvas::new(20, 9);
+/// revealed.put_text(4, 2, &text);
+/// assert_eq!(revealed, rain.frame(rain.duration()));
+/// ```
+#[derive(Debug, Clone)]
+pub struct MatrixRain {
+    /// The revealed text.
+    target: Canvas,
+    /// The characters the drops are made of.
+    glyphs: Vec<char>,
+    /// The drops of every column.
+    columns: Vec<Vec<Raindrop>>,
+}
+
+/// A drop of a [MatrixRain].
+#[derive(Debug, Clone, Copy)]
+struct Raindrop {
+    /// When the drop starts falling.
+    delay: Duration,
+    /// The rows fallen every second.
+    speed: f64,
+    /// The amount of rows trailing behind the head of the drop.
+    trail: usize,
+    /// Where the characters of the drop start in the glyphs.
+    offset: usize,
+}
+
+impl Raindrop {
+    /// Gets the row of the head of the drop at `t`, [None] before it starts falling.
+    fn head(&self, t: Duration) -> Option<usize> {
+        let falling = t.checked_sub(self.delay)?;
+        Some((falling.as_secs_f64() * self.speed) as usize)
+    }
+
+    /// Gets when the end of the trail falls below `height` rows.
+    fn end(&self, height: usize) -> Duration {
+        self.delay + Duration::from_secs_f64((height + self.trail + 1) as f64 / self.speed)
+    }
+}
+
+impl MatrixRain {
+    /// Constructor Function for [MatrixRain], on a screen of `width` by `height`.
+    pub fn new(text: &BigText, width: usize, height: usize) -> Self {
+        let mut target = Canvas::new(width, height);
+        let rows = text.render_rows(text.text());
+        let text_width = rows.iter().map(|row| display_width(row)).max().unwrap_or(0);
+        target.put_text(
+            width.saturating_sub(text_width) / 2,
+            height.saturating_sub(rows.len()) / 2,
+            text,
+        );
+
+        let mut glyphs: Vec<char> = text
+            .supported_characters()
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect();
+        if glyphs.is_empty() {
+            glyphs.push('*');
+        }
+
+        // Seeding from the text, so the same text always rains the same way
+        let mut random = Random::new(
+            text.text()
+                .chars()
+                .fold(0, |seed, c| (seed ^ c as u64).wrapping_mul(0x100_0000_01b3)),
+        );
+        let columns = (0..width)
+            .map(|_| {
+                (0..MATRIX_PASSES)
+                    .map(|pass| Raindrop {
+                        delay: Duration::from_millis(pass as u64 * 800 + random.below(1200)),
+                        speed: 10.0 + random.below(20) as f64,
+                        trail: 3 + random.below(8) as usize,
+                        offset: random.below(glyphs.len() as u64) as usize,
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            target,
+            glyphs,
+            columns,
+        }
+    }
+
+    /// Gets how long the drops take to reveal the whole text.
+    pub fn duration(&self) -> Duration {
+        let height = self.target.height();
+        self.columns
+            .iter()
+            .flatten()
+            .map(|drop| drop.end(height))
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+impl Animation for MatrixRain {
+    fn frame(&mut self, t: Duration) -> Canvas {
+        let mut canvas = Canvas::new(self.target.width(), self.target.height());
+
+        for (x, drops) in self.columns.iter().enumerate() {
+            // Revealing the text above the tail of the last drop
+            let last = drops
+                .last()
+                .and_then(|drop| drop.head(t).map(|head| (head, drop.trail)));
+            let revealed = match last {
+                Some((head, trail)) => (head + 1).saturating_sub(trail + 1),
+                None => 0,
+            };
+            for y in 0..revealed.min(canvas.height()) {
+                if let Some(c) = self.target.get(x, y) {
+                    canvas.set(x, y, c);
+                }
+            }
+
+            for drop in drops {
+                let Some(head) = drop.head(t) else {
+                    continue;
+                };
+                for (distance, y) in (head.saturating_sub(drop.trail)..=head).rev().enumerate() {
+                    let glyph = self.glyphs[(drop.offset + y + distance) % self.glyphs.len()];
+                    canvas.set(x, y, glyph);
+                }
+            }
+        }
+
+        canvas
+    }
+
+    fn is_finished(&self, t: Duration) -> bool {
+        t > self.duration() + MATRIX_HOLD
+    }
+}
+
+/// An [Animation] blinking a text by alternating between the text and blanks, for
+/// terminals without [ANSI blinking](BigText::set_blink).
+///
+/// The colors of the text are kept.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{animation::{Animation, Blink}, canvas::Canvas, BigText};
+/// use std::time::Duration;
+///
+/// let text = BigText::new("HI", None);
+/// let mut blink = Blink::new(&text, Duration::from_millis(500));
+///
+/// let mut shown = Canvas::new(12, 5);
+/// shown.put_text(0, 0, &text);
+/// assert_eq!(shown, blink.frame(Duration::from_millis(200)));
+/// assert_eq!(Canvas::new(12, 5), blink.frame(Duration::from_millis(700)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Blink {
+    /// The text shown with its colors.
+    shown: Canvas,
+    /// How long the text is shown and hidden.
+    interval: Duration,
+}
+
+impl Blink {
+    /// Constructor Function for [Blink], showing and hiding the text for `interval`
+    /// each.
+    pub fn new(text: &BigText, interval: Duration) -> Self {
+        let rows = text.render_rows(text.text());
+        let width = rows.iter().map(|row| display_width(row)).max().unwrap_or(0);
+
+        let mut shown = Canvas::new(width, rows.len());
+        shown.put_text(0, 0, text).set_color(text.color().cloned());
+
+        Self { shown, interval }
+    }
+
+    /// Gets how long the text is shown and hidden.
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+}
+
+impl Animation for Blink {
+    fn frame(&mut self, t: Duration) -> Canvas {
+        let phase = t.as_nanos() / self.interval.as_nanos().max(1);
+        match phase % 2 {
+            0 => self.shown.clone(),
+            _ => Canvas::new(self.shown.width(), self.shown.height()),
+        }
+    }
+}
+
+/// The columns moved every second by a [Bounce].
+const BOUNCE_SPEED: f64 = 10.0;
+
+/// An [Animation] bouncing a text around the screen like a screensaver, changing
+/// its color every time it hits a wall.
+///
+/// The text moves diagonally, twice as fast horizontally as vertically since
+/// terminal cells are about twice as tall as they are wide. It bounces until
+/// interrupted.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{animation::{Animation, Bounce}, canvas::Canvas, BigText};
+/// use std::time::Duration;
+///
+/// let text = BigText::new("I", None);
+/// let mut bounce = Bounce::new(&text, 20, 10);
+/// bounce.set_colors(Vec::new());
+///
+/// let mut start = Canvas::new(20, 10);
+/// start.put_text(0, 0, &text);
+/// assert_eq!(start, bounce.frame(Duration::ZERO));
+///
+/// // The text reaches the right wall after 14 columns and moves back
+/// let mut back = Canvas::new(20, 10);
+/// back.put_text(13, 3, &text);
+/// assert_eq!(back, bounce.frame(Duration::from_millis(1500)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Bounce {
+    /// The bouncing text.
+    text: BigText,
+    /// The amount of columns of the screen.
+    width: usize,
+    /// The amount of rows of the screen.
+    height: usize,
+    /// The size of the rendered text.
+    size: (usize, usize),
+    /// The colors cycled through on every wall hit.
+    colors: Vec<Rgb>,
+}
+
+impl Bounce {
+    /// Constructor Function for [Bounce], on a screen of `width` by `height`,
+    /// cycling through the colors of the rainbow.
+    pub fn new(text: &BigText, width: usize, height: usize) -> Self {
+        let rows = text.render_rows(text.text());
+        let text_width = rows.iter().map(|row| display_width(row)).max().unwrap_or(0);
+        let rainbow = color::Gradient::rainbow();
+
+        Self {
+            text: text.clone(),
+            width,
+            height,
+            size: (text_width, rows.len()),
+            colors: (0..6).map(|index| rainbow.at(index as f32 / 6.0)).collect(),
+        }
+    }
+
+    /// Sets the colors cycled through on every wall hit, the text isn't colored
+    /// when there are none.
+    pub fn set_colors(&mut self, colors: Vec<Rgb>) -> &mut Self {
+        self.colors = colors;
+        self
+    }
+
+    /// Gets the colors cycled through on every wall hit.
+    pub fn colors(&self) -> &[Rgb] {
+        &self.colors
+    }
+}
+
+impl Animation for Bounce {
+    fn frame(&mut self, t: Duration) -> Canvas {
+        let distance = t.as_secs_f64() * BOUNCE_SPEED;
+        let (x, horizontal_hits) = reflect(distance, self.width.saturating_sub(self.size.0));
+        let (y, vertical_hits) = reflect(distance / 2.0, self.height.saturating_sub(self.size.1));
+
+        let mut canvas = Canvas::new(self.width, self.height);
+        canvas.put_text(x, y, &self.text);
+        if !self.colors.is_empty() {
+            let hits = (horizontal_hits + vertical_hits) % self.colors.len();
+            canvas.set_color(Some(ColorStyle::Solid(self.colors[hits])));
+        }
+
+        canvas
+    }
+}
+
+/// Gets the position after moving `distance` cells back and forth between `0` and
+/// `range`, along with the amount of walls hit.
+fn reflect(distance: f64, range: usize) -> (usize, usize) {
+    if range == 0 {
+        return (0, 0);
+    }
+
+    let moved = distance as usize;
+    let hits = moved / range;
+    let phase = moved % range;
+    match hits % 2 {
+        0 => (phase, hits),
+        _ => (range - phase, hits),
+    }
+}
+
+/// A full-screen animation in the alternate screen of the terminal.
+///
+/// Creating a session switches to the alternate screen and hides the cursor, so
+/// the frames don't end up in the scrollback. Both are restored when the session
+/// is dropped, even when the animation is stopped by an error.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{animation::AnimationSession, BigText};
+///
+/// let mut vec = Vec::new();
+/// {
+///     let mut session = AnimationSession::new(&mut vec).unwrap();
+///     session.draw_frame(&BigText::new("1", None)).unwrap();
+///     session.draw_frame(&BigText::new("2", None)).unwrap();
+/// }
+///
+/// let output = String::from_utf8(vec).unwrap();
+/// assert!(output.starts_with("\x1b[?1049h\x1b[?25l\x1b[H\x1b[2J"));
+/// assert!(output.ends_with("\x1b[?25h\x1b[?1049l"));
+/// ```
+#[derive(Debug)]
+pub struct AnimationSession<W: Write> {
+    /// The terminal the frames are drawn to.
+    stream: W,
+}
+
+impl AnimationSession<Stdout> {
+    /// Creates an [AnimationSession] on the standard output.
+    pub fn stdout() -> Result<Self, Error> {
+        Self::new(io::stdout())
+    }
+}
+
+impl<W: Write> AnimationSession<W> {
+    /// Constructor Function for [AnimationSession], entering the alternate screen
+    /// and hiding the cursor.
+    pub fn new(mut stream: W) -> Result<Self, Error> {
+        write!(stream, "\x1b[?1049h\x1b[?25l")?;
+        stream.flush()?;
+        Ok(Self { stream })
+    }
+
+    /// Clears the screen and draws `text` in the top left corner, with its colors.
+    pub fn draw_frame(&mut self, text: &BigText) -> Result<(), Error> {
+        self.draw_rows(&text.output_rows())
+    }
+
+    /// Draws the frames of `animation` at `fps` frames per second, until it is
+    /// [finished](Animation::is_finished).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{animation::{Animation, AnimationSession}, canvas::Canvas};
+    /// use std::time::Duration;
+    ///
+    /// /// Ends after its third frame.
+    /// struct Three(usize);
+    ///
+    /// impl Animation for Three {
+    ///     fn frame(&mut self, _t: Duration) -> Canvas {
+    ///         self.0 += 1;
+    ///         Canvas::new(1, 1)
+    ///     }
+    ///
+    ///     fn is_finished(&self, _t: Duration) -> bool {
+    ///         self.0 == 3
+    ///     }
+    /// }
+    ///
+    /// let mut animation = Three(0);
+    /// AnimationSession::new(Vec::new()).unwrap().run(&mut animation, 1000).unwrap();
+    /// assert_eq!(3, animation.0);
+    /// ```
+    pub fn run(&mut self, animation: &mut impl Animation, fps: u32) -> Result<(), Error> {
+        let interval = Duration::from_secs(1) / fps.max(1);
+        let start = Instant::now();
+        let mut next = start;
+
+        while !animation.is_finished(start.elapsed()) {
+            let canvas = animation.frame(start.elapsed());
+            self.draw_canvas(&canvas)?;
+
+            // Waiting for the next frame, skipping frames when drawing is too slow
+            next += interval;
+            let now = Instant::now();
+            if next > now {
+                thread::sleep(next - now);
+            } else {
+                next = now;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Clears the screen and draws a [Canvas] in the top left corner.
+    pub fn draw_canvas(&mut self, canvas: &Canvas) -> Result<(), Error> {
+        write!(self.stream, "\x1b[H\x1b[2J{canvas}")?;
+        self.stream.flush()
+    }
+
+    /// Clears the screen and draws the rows of a frame, such as a
+    /// [Marquee::frame], in the top left corner.
+    pub fn draw_rows(&mut self, rows: &[String]) -> Result<(), Error> {
+        write!(self.stream, "\x1b[H\x1b[2J")?;
+        for row in rows {
+            writeln!(self.stream, "{row}")?;
+        }
+        self.stream.flush()
+    }
+}
+
+impl<W: Write> Drop for AnimationSession<W> {
+    fn drop(&mut self) {
+        // Errors can't be reported here, the terminal is restored on a best effort
+        let _ = write!(self.stream, "\x1b[?25h\x1b[?1049l");
+        let _ = self.stream.flush();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn matrix_rain_reveals() {
+        let text = BigText::new("1", None);
+        let mut rain = MatrixRain::new(&text, 10, 7);
+        assert_eq!(rain.clone().frame(Duration::ZERO), Canvas::new(10, 7));
+        assert!(!rain.is_finished(rain.duration()));
+        assert!(rain.is_finished(rain.duration() + MATRIX_HOLD * 2));
+
+        // Every drop has fallen off the screen at the end
+        let end = rain.frame(rain.duration());
+        let mut expected = Canvas::new(10, 7);
+        expected.put_text(2, 1, &text);
+        assert_eq!(expected, end);
+    }
+
+    #[test]
+    fn reflect_walls() {
+        assert_eq!((3, 0), reflect(3.5, 5));
+        assert_eq!((4, 1), reflect(6.0, 5));
+        assert_eq!((0, 2), reflect(10.0, 5));
+        assert_eq!((0, 0), reflect(10.0, 0));
+    }
+
+    #[test]
+    fn bounce_changes_color() {
+        let mut bounce = Bounce::new(&BigText::new("I", None), 10, 5);
+        let start = bounce.frame(Duration::ZERO);
+        let hit = bounce.frame(Duration::from_millis(500));
+        assert_ne!(start.color(), hit.color());
+    }
+
+    #[test]
+    fn marquee_loops() {
+        let marquee = BigText::new("I", None).marquee(4);
+        assert_eq!(marquee.frame(0), marquee.frame(10));
+        assert_eq!(10, marquee.frames().count());
+    }
+
+    #[test]
+    fn full_width_glyphs() {
+        let map = HashMap::from([('A', [0; 5].map(|_| "漢漢".into()))]);
+        let text = BigText::new("A", Some(map));
+
+        let marquee = text.marquee(2);
+        assert_eq!(7, marquee.frame_count());
+        assert_eq!("漢", marquee.frame(0)[0]);
+        assert_eq!("  ", marquee.frame(1)[0]);
+
+        let mut blink = Blink::new(&text, Duration::from_millis(500));
+        assert_eq!(5, blink.frame(Duration::ZERO).width());
+        assert_eq!(
+            "漢漢 ",
+            blink
+                .frame(Duration::ZERO)
+                .to_string()
+                .lines()
+                .next()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn marquee_ragged_rows() {
+        let map = HashMap::from([(
+            'A',
+            [
+                "*****".into(),
+                "*".into(),
+                "*".into(),
+                "*".into(),
+                "*".into(),
+            ],
+        )]);
+        let marquee = BigText::new("A", Some(map)).marquee(4);
+
+        assert_eq!(vec!["    "; 5], marquee.frame(6));
+        assert_eq!(
+            vec!["****", "    ", "    ", "    ", "    "],
+            marquee.frame(1)
+        );
+        assert!(marquee.frames().all(|frame| frame.len() == 5));
+    }
+
+    #[test]
+    fn typewriter_skips_newlines() {
+        let printer = BigText::new("A\nB", None);
+        let frames = printer.typewriter(RevealUnit::Character);
+
+        assert_eq!(2, frames.len());
+        assert_eq!(printer.to_string().lines().count(), frames[1].len());
+    }
+
+    #[test]
+    fn marquee_single_line() {
+        let mut printer = BigText::new("I\nI", None);
+        printer.set_width(Some(1));
+        let marquee = printer.marquee(12);
+        assert_eq!(5, marquee.frame(0).len());
+        assert_eq!(30, marquee.frame_count());
+        assert_eq!("*****       ", marquee.frame(0)[0]);
+        assert_eq!("***** ", &marquee.frame(12)[0][..6]);
+    }
+}
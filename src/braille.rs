@@ -0,0 +1,98 @@
+//! A module for rendering a [BigText] with braille patterns, fitting 2 by 4 cells
+//! of the ascii-art into every character.
+
+use crate::{canvas, BigText};
+
+/// The bit of every dot of a braille pattern, indexed by row and column.
+const DOTS: [[u32; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+impl BigText {
+    /// Renders the stored string with braille patterns, every character drawing 2
+    /// columns and 4 rows of the ascii-art as dots.
+    ///
+    /// This fits much larger texts into a small terminal. Characters without any
+    /// dots are left blank and colors aren't included.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// let printer = BigText::new("I", None);
+    /// let braille = printer.to_braille();
+    ///
+    /// // 6 columns and 5 rows fit into 3 characters over 2 rows
+    /// assert_eq!("⠉⡏⠁\n⠉⠉⠁\n", braille);
+    /// ```
+    pub fn to_braille(&self) -> String {
+        let rows: Vec<Vec<bool>> = self
+            .render_rows(self.text())
+            .iter()
+            .map(|row| {
+                canvas::row_cells(row)
+                    .into_iter()
+                    .map(|c| !c.is_whitespace())
+                    .collect()
+            })
+            .collect();
+        let columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+        let filled = |x: usize, y: usize| {
+            rows.get(y)
+                .and_then(|row| row.get(x))
+                .copied()
+                .unwrap_or(false)
+        };
+
+        let mut output = String::new();
+        for y in (0..rows.len()).step_by(4) {
+            for x in (0..columns).step_by(2) {
+                let mut pattern = 0;
+                for (dy, bits) in DOTS.iter().enumerate() {
+                    for (dx, bit) in bits.iter().enumerate() {
+                        if filled(x + dx, y + dy) {
+                            pattern |= bit;
+                        }
+                    }
+                }
+
+                output.push(match pattern {
+                    0 => ' ',
+                    pattern => char::from_u32(0x2800 + pattern).unwrap_or(' '),
+                });
+            }
+            output.push('\n');
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn full_width_braille() {
+        let glyph = [
+            "漢".into(),
+            "漢".into(),
+            "漢".into(),
+            "漢".into(),
+            "漢".into(),
+        ];
+        let mut printer = BigText::new("X", Some(HashMap::from([('X', glyph)])));
+        printer.set_spacing(0);
+
+        // Both columns of every row are dots
+        assert_eq!("⣿\n⠉\n", printer.to_braille());
+    }
+
+    #[test]
+    fn blank_braille() {
+        let printer = BigText::new(" ", None);
+        let braille = printer.to_braille();
+
+        assert_eq!(2, braille.lines().count());
+        assert!(braille.lines().all(|row| row.trim().is_empty()));
+    }
+}
@@ -0,0 +1,369 @@
+//! A module containing a [Canvas] to lay out multiple [BigText]s on one screen.
+
+use std::fmt::Display;
+
+use unicode_width::UnicodeWidthChar;
+
+use crate::{
+    color::{self, ColorFormat, ColorStyle},
+    effects::Grid,
+    BigText,
+};
+
+/// The cell right of a full-width character, covered by the character.
+pub(crate) const WIDE_TAIL: char = '\0';
+
+/// A fixed size grid of cells that [BigText]s are placed on.
+///
+/// Texts are drawn at a column and row of the canvas and combined with the cells
+/// below them using the [BlendMode]. Anything outside of the canvas is clipped.
+/// [Colors](BigText::set_color) of the texts aren't drawn, the whole canvas is
+/// colored with its own [color](Canvas::set_color) instead.
+///
+/// Every cell is a column of the terminal, so full-width characters take up two
+/// cells, the second one being `'\0'`. Halves of full-width characters that are
+/// clipped or drawn over are printed blank.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{canvas::Canvas, BigText};
+///
+/// let mut canvas = Canvas::new(40, 11);
+/// canvas
+///     .put_text(0, 0, &BigText::new("SCORE", None))
+///     .put_text(0, 6, &BigText::new("42", None));
+///
+/// assert_eq!(11, canvas.to_string().lines().count());
+/// println!("{canvas}");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Canvas {
+    /// The amount of columns of the canvas.
+    width: usize,
+    /// The amount of rows of the canvas.
+    height: usize,
+    /// The cells of the canvas, blank cells are spaces.
+    cells: Grid,
+    /// How texts are combined with the cells below them.
+    blend_mode: BlendMode,
+    /// The colors used when printing.
+    color: Option<ColorStyle>,
+}
+
+/// How the cells of a text are combined with the cells of a [Canvas] below them.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::canvas::BlendMode;
+///
+/// assert_eq!('*', BlendMode::Transparent.blend('*', ' '));
+/// assert_eq!(' ', BlendMode::Opaque.blend('*', ' '));
+/// assert_eq!(' ', BlendMode::Xor.blend('*', '#'));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BlendMode {
+    /// Only the filled cells of the text are drawn, blank cells don't overwrite.
+    #[default]
+    Transparent,
+    /// Every cell of the text is drawn, including blank cells.
+    Opaque,
+    /// Filled cells are drawn over blank cells and cleared where they overlap
+    /// filled cells.
+    Xor,
+}
+
+impl BlendMode {
+    /// Combines the cell `below` with the cell of a text drawn over it.
+    pub fn blend(&self, below: char, above: char) -> char {
+        match (self, below.is_whitespace(), above.is_whitespace()) {
+            (BlendMode::Opaque, _, _) => above,
+            (_, _, true) => below,
+            (BlendMode::Transparent, _, false) | (BlendMode::Xor, true, false) => above,
+            (BlendMode::Xor, false, false) => ' ',
+        }
+    }
+}
+
+impl Canvas {
+    /// Constructor Function for [Canvas], creating a blank canvas.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::canvas::Canvas;
+    ///
+    /// let canvas = Canvas::new(3, 2);
+    /// assert_eq!("   \n   \n", canvas.to_string());
+    /// ```
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![vec![' '; width]; height],
+            blend_mode: BlendMode::Transparent,
+            color: None,
+        }
+    }
+
+    /// Gets the amount of columns of the canvas.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::canvas::Canvas;
+    ///
+    /// assert_eq!(80, Canvas::new(80, 24).width());
+    /// ```
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Gets the amount of rows of the canvas.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::canvas::Canvas;
+    ///
+    /// assert_eq!(24, Canvas::new(80, 24).height());
+    /// ```
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Sets how texts are combined with the cells below them.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{canvas::{BlendMode, Canvas}, BigText};
+    ///
+    /// let mut canvas = Canvas::new(6, 5);
+    /// let one = BigText::new("1", None);
+    /// canvas.set_blend_mode(BlendMode::Xor).put_text(0, 0, &one).put_text(0, 0, &one);
+    /// assert_eq!(Canvas::new(6, 5).to_string(), canvas.to_string());
+    /// ```
+    pub fn set_blend_mode(&mut self, blend_mode: BlendMode) -> &mut Self {
+        self.blend_mode = blend_mode;
+        self
+    }
+
+    /// Gets how texts are combined with the cells below them.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::canvas::{BlendMode, Canvas};
+    ///
+    /// assert_eq!(BlendMode::Transparent, Canvas::new(1, 1).blend_mode());
+    /// ```
+    pub fn blend_mode(&self) -> BlendMode {
+        self.blend_mode
+    }
+
+    /// Draws `text` with its top left corner at column `x` and row `y` using the
+    /// [blend mode](Canvas::set_blend_mode), clipping the parts outside of the canvas.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{canvas::Canvas, BigText};
+    ///
+    /// let mut canvas = Canvas::new(8, 6);
+    /// canvas.put_text(2, 1, &BigText::new("I", None));
+    ///
+    /// let rows: Vec<String> = canvas.to_string().lines().map(String::from).collect();
+    /// assert_eq!("        ", rows[0]);
+    /// assert_eq!("  ***** ", rows[1]);
+    /// ```
+    pub fn put_text(&mut self, x: usize, y: usize, text: &BigText) -> &mut Self {
+        let rows = text.render_rows(text.text());
+
+        for (row, line) in self.cells.iter_mut().skip(y).zip(rows) {
+            for (cell, c) in row.iter_mut().skip(x).zip(row_cells(&line)) {
+                *cell = self.blend_mode.blend(*cell, c);
+            }
+        }
+
+        self
+    }
+
+    /// Sets the colors used when printing the canvas, spread over all of its
+    /// columns.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{canvas::Canvas, color::{ColorStyle, Rgb}};
+    ///
+    /// let mut canvas = Canvas::new(2, 1);
+    /// canvas.set(0, 0, '*').set_color(Some(ColorStyle::Solid(Rgb::new(1, 2, 3))));
+    /// assert_eq!("\x1b[38;2;1;2;3m* \x1b[0m\n", canvas.to_string());
+    /// ```
+    pub fn set_color(&mut self, color: Option<ColorStyle>) -> &mut Self {
+        self.color = color;
+        self
+    }
+
+    /// Gets the colors used when printing the canvas.
+    pub fn color(&self) -> Option<&ColorStyle> {
+        self.color.as_ref()
+    }
+
+    /// Gets the cell at column `x` and row `y`, [None] outside of the canvas.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::canvas::Canvas;
+    ///
+    /// let mut canvas = Canvas::new(2, 2);
+    /// canvas.set(1, 0, '#');
+    /// assert_eq!(Some('#'), canvas.get(1, 0));
+    /// assert_eq!(Some(' '), canvas.get(0, 0));
+    /// assert_eq!(None, canvas.get(2, 0));
+    /// ```
+    pub fn get(&self, x: usize, y: usize) -> Option<char> {
+        self.cells.get(y)?.get(x).copied()
+    }
+
+    /// Sets the cell at column `x` and row `y` using the
+    /// [blend mode](Canvas::set_blend_mode), cells outside of the canvas are ignored.
+    pub fn set(&mut self, x: usize, y: usize, c: char) -> &mut Self {
+        if let Some(cell) = self.cells.get_mut(y).and_then(|row| row.get_mut(x)) {
+            *cell = self.blend_mode.blend(*cell, c);
+        }
+        self
+    }
+
+    /// Clears every cell of the canvas.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{canvas::Canvas, BigText};
+    ///
+    /// let mut canvas = Canvas::new(6, 5);
+    /// canvas.put_text(0, 0, &BigText::new("1", None)).clear();
+    /// assert_eq!(Canvas::new(6, 5), canvas);
+    /// ```
+    pub fn clear(&mut self) -> &mut Self {
+        for row in &mut self.cells {
+            row.fill(' ');
+        }
+        self
+    }
+}
+
+impl Display for Canvas {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut output = String::with_capacity((self.width + 1) * self.height);
+
+        for row in &self.cells {
+            let row = join_cells(row);
+            match &self.color {
+                None => output.push_str(&row),
+                Some(style) => {
+                    output.push_str(&color::colorize_row(
+                        style,
+                        ColorFormat::Ansi,
+                        &row,
+                        0,
+                        self.width,
+                    ));
+                }
+            }
+            output.push('\n');
+        }
+
+        f.write_str(&output)
+    }
+}
+
+/// Splits `row` into one cell per column, full-width characters being followed by a
+/// [WIDE_TAIL] cell. Zero-width characters are left out.
+pub(crate) fn row_cells(row: &str) -> Vec<char> {
+    let mut cells = Vec::with_capacity(row.len());
+    for c in row.chars() {
+        match c.width() {
+            Some(0) => {}
+            Some(2) => cells.extend([c, WIDE_TAIL]),
+            _ => cells.push(c),
+        }
+    }
+
+    cells
+}
+
+/// Joins the cells of a row made by [row_cells()], printing the halves of
+/// full-width characters without the other half as blanks, so every cell stays a
+/// column.
+pub(crate) fn join_cells(cells: &[char]) -> String {
+    let is_wide = |index: usize| cells.get(index).and_then(|c| c.width()) == Some(2);
+
+    let mut row = String::with_capacity(cells.len());
+    for (index, c) in cells.iter().enumerate() {
+        let whole = match *c {
+            WIDE_TAIL => index > 0 && is_wide(index - 1),
+            _ if is_wide(index) => cells.get(index + 1) == Some(&WIDE_TAIL),
+            _ => true,
+        };
+        match (whole, *c) {
+            (true, WIDE_TAIL) => {}
+            (true, c) => row.push(c),
+            (false, _) => row.push(' '),
+        }
+    }
+
+    row
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn put_text_clips() {
+        let mut canvas = Canvas::new(4, 3);
+        canvas.put_text(2, 1, &BigText::new("HI", None));
+
+        assert_eq!("    \n  * \n  * \n", canvas.to_string());
+    }
+
+    #[test]
+    fn full_width_cells() {
+        assert_eq!(vec!['漢', WIDE_TAIL, '*'], row_cells("漢*"));
+        assert_eq!("漢*", join_cells(&row_cells("漢*")));
+        assert_eq!(" *", join_cells(&row_cells("漢*")[1..]));
+
+        let mut canvas = Canvas::new(5, 1);
+        canvas
+            .set(0, 0, '#')
+            .set(1, 0, '漢')
+            .set(2, 0, WIDE_TAIL)
+            .set(3, 0, '漢');
+        assert_eq!("#漢  \n", canvas.to_string());
+    }
+
+    #[test]
+    fn put_text_transparent() {
+        let mut canvas = Canvas::new(6, 5);
+        canvas
+            .put_text(0, 0, &BigText::new("1", None))
+            .put_text(0, 0, &BigText::new(" ", None));
+
+        let mut expected = Canvas::new(6, 5);
+        expected.put_text(0, 0, &BigText::new("1", None));
+        assert_eq!(expected, canvas);
+    }
+
+    #[test]
+    fn put_text_opaque() {
+        let mut canvas = Canvas::new(6, 5);
+        canvas
+            .put_text(0, 0, &BigText::new("1", None))
+            .set_blend_mode(BlendMode::Opaque)
+            .put_text(0, 0, &BigText::new(" ", None));
+
+        assert_eq!(Canvas::new(6, 5).to_string(), canvas.to_string());
+    }
+
+    #[test]
+    fn blend_xor() {
+        assert_eq!('*', BlendMode::Xor.blend(' ', '*'));
+        assert_eq!('#', BlendMode::Xor.blend('#', ' '));
+        assert_eq!(' ', BlendMode::Xor.blend('#', '*'));
+        assert_eq!(' ', BlendMode::Xor.blend(' ', ' '));
+    }
+}
@@ -1,16 +1,531 @@
 //! A module containing functions that generates some useful [CharacterMap]s used by
 //! the [BigText](crate::BigText) struct.
 
-use serde_json::Result;
-use std::collections::HashMap;
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet, HashMap},
+    sync::{Arc, OnceLock},
+};
 
-static LETTERS: &str = include_str!("letters.json");
-static DIGITS: &str = include_str!("digits.json");
-static PUNCTUATION: &str = include_str!("punctuation.json");
-static WHITESPACE: &str = include_str!("whitespace.json");
+// The glyphs of the built-in maps, generated from the JSON files by the build script.
+include!(concat!(env!("OUT_DIR"), "/character_maps.rs"));
+
+/// The built maps, so the glyphs are only copied into a map once.
+static LETTERS_MAP: OnceLock<CharacterMap> = OnceLock::new();
+static DIGITS_MAP: OnceLock<CharacterMap> = OnceLock::new();
+static PUNCTUATION_MAP: OnceLock<CharacterMap> = OnceLock::new();
+static WHITESPACE_MAP: OnceLock<CharacterMap> = OnceLock::new();
+static LATIN_MAP: OnceLock<CharacterMap> = OnceLock::new();
+static ARABIC_INDIC_DIGITS_MAP: OnceLock<CharacterMap> = OnceLock::new();
+static SYMBOLS_MAP: OnceLock<CharacterMap> = OnceLock::new();
+static BLOCKS_MAP: OnceLock<CharacterMap> = OnceLock::new();
+static SLANT_MAP: OnceLock<CharacterMap> = OnceLock::new();
+#[cfg(feature = "greek")]
+static GREEK_MAP: OnceLock<CharacterMap> = OnceLock::new();
+#[cfg(feature = "kana")]
+static HIRAGANA_MAP: OnceLock<CharacterMap> = OnceLock::new();
+#[cfg(feature = "kana")]
+static KATAKANA_MAP: OnceLock<CharacterMap> = OnceLock::new();
+static PRINTABLES_MAP: OnceLock<Arc<CharacterMap>> = OnceLock::new();
+
+/// The rows of a single character.
+///
+/// The rows of the built-in maps borrow static data, so creating them doesn't
+/// allocate every row, while fonts loaded at runtime own their rows.
+pub type Glyph = [Cow<'static, str>; 5];
 
 /// The [BigText](crate::BigText) type used by BigText struct.
-pub type CharacterMap = HashMap<char, [String; 5]>;
+pub type CharacterMap = HashMap<char, Glyph>;
+
+/// Optional metadata of a [Glyph] that doesn't fit in a uniform 5 row box.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::character_maps::GlyphMetrics;
+///
+/// // A glyph with a descender, its body ends on the fourth row
+/// let metrics = GlyphMetrics { baseline: Some(3), ..Default::default() };
+/// assert_eq!(None, metrics.advance);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct GlyphMetrics {
+    /// The row the body of the glyph ends on, the rows below it are descenders like
+    /// the tail of `g`. The glyph is moved down so this row lines up with the bottom
+    /// row of the other glyphs. [None] for the bottom row.
+    pub baseline: Option<usize>,
+    /// The amount of columns the glyph takes up, excluding spacing, for proportional
+    /// fonts. Rows are padded or cut to it. [None] for the width of the widest row.
+    pub advance: Option<usize>,
+}
+
+/// The [GlyphMetrics] of the characters of a font, used by
+/// [BigText::set_glyph_metrics](crate::BigText::set_glyph_metrics).
+pub type MetricsMap = HashMap<char, GlyphMetrics>;
+
+/// A [CharacterMap] sorted by character, so iterating, serializing and comparing
+/// fonts is deterministic, for font tooling and snapshot tests.
+///
+/// It is a [GlyphProvider], so a [BigText](crate::BigText) can render against it
+/// directly.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{character_maps::{self, OrderedCharacterMap}, BigText};
+/// use std::sync::Arc;
+///
+/// let map: OrderedCharacterMap = character_maps::ordered(&character_maps::digits());
+/// assert_eq!(Some(&'0'), map.keys().next());
+///
+/// let mut printer = BigText::new("42", None);
+/// printer.set_glyph_provider(Some(Arc::new(map)));
+/// assert_eq!(BigText::new("42", None).to_string(), printer.to_string());
+/// ```
+pub type OrderedCharacterMap = BTreeMap<char, Glyph>;
+
+/// Glyphs of sequences of characters, such as emoji ZWJ sequences and ligatures,
+/// used by [BigText::set_sequence_map](crate::BigText::set_sequence_map).
+pub type SequenceMap = HashMap<String, Glyph>;
+
+/// A source of [Glyph]s that [BigText](crate::BigText) renders against.
+///
+/// This allows fonts to be backed by generated glyphs, databases or fallback chains
+/// without building a [CharacterMap]. The glyphs are returned as [Glyph]s rather
+/// than a slice of [String]s, so the built-in maps can lend their static rows.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::character_maps::{Glyph, GlyphProvider};
+/// use print_big_text_rs::BigText;
+/// use std::sync::Arc;
+///
+/// /// Renders every character as a filled block.
+/// #[derive(Debug)]
+/// struct Blocks(Glyph);
+///
+/// impl GlyphProvider for Blocks {
+///     fn glyph(&self, _c: char) -> Option<&Glyph> {
+///         Some(&self.0)
+///     }
+/// }
+///
+/// let mut printer = BigText::new("AB", None);
+/// printer.set_glyph_provider(Some(Arc::new(Blocks(["##".into(), "##".into(), "##".into(), "##".into(), "##".into()]))));
+/// assert_eq!("## ## \n## ## \n## ## \n## ## \n## ## \n", printer.to_string());
+/// ```
+pub trait GlyphProvider: std::fmt::Debug + Send + Sync {
+    /// Gets the [Glyph] of a character, [None] if it isn't supported.
+    fn glyph(&self, c: char) -> Option<&Glyph>;
+
+    /// Gets the characters the provider supports, [None] (the default) if it can't
+    /// list them, such as a provider generating its glyphs.
+    fn characters(&self) -> Option<Vec<char>> {
+        None
+    }
+}
+
+impl GlyphProvider for CharacterMap {
+    fn glyph(&self, c: char) -> Option<&Glyph> {
+        self.get(&c)
+    }
+
+    fn characters(&self) -> Option<Vec<char>> {
+        Some(self.keys().copied().collect())
+    }
+}
+
+impl GlyphProvider for OrderedCharacterMap {
+    fn glyph(&self, c: char) -> Option<&Glyph> {
+        self.get(&c)
+    }
+
+    fn characters(&self) -> Option<Vec<char>> {
+        Some(self.keys().copied().collect())
+    }
+}
+
+impl<P: GlyphProvider + ?Sized> GlyphProvider for Arc<P> {
+    fn glyph(&self, c: char) -> Option<&Glyph> {
+        P::glyph(self, c)
+    }
+
+    fn characters(&self) -> Option<Vec<char>> {
+        P::characters(self)
+    }
+}
+
+/// A [GlyphProvider] looking up characters in a list of providers, using the glyph
+/// of the first one supporting the character.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::character_maps::{self, Fallback, GlyphProvider};
+///
+/// let fallback = Fallback::new(vec![
+///     Box::new(character_maps::digits()),
+///     Box::new(character_maps::ascii_letters()),
+/// ]);
+/// assert_eq!(character_maps::digits().get(&'1'), fallback.glyph('1'));
+/// assert_eq!(character_maps::ascii_letters().get(&'A'), fallback.glyph('A'));
+/// assert_eq!(None, fallback.glyph('@'));
+///
+/// let count = character_maps::digits().len() + character_maps::ascii_letters().len();
+/// assert_eq!(Some(count), fallback.characters().map(|chars| chars.len()));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Fallback {
+    /// The providers in the order they are looked up in, shared between clones.
+    providers: Vec<Arc<dyn GlyphProvider>>,
+}
+
+impl Fallback {
+    /// Constructor Function for [Fallback].
+    pub fn new(providers: Vec<Box<dyn GlyphProvider>>) -> Self {
+        Self {
+            providers: providers.into_iter().map(Arc::from).collect(),
+        }
+    }
+
+    /// Adds a provider looked up after all the current ones.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::character_maps::{self, Fallback, GlyphProvider};
+    ///
+    /// let mut fallback = Fallback::default();
+    /// fallback.push(Box::new(character_maps::digits()));
+    /// assert!(fallback.glyph('1').is_some());
+    /// ```
+    pub fn push(&mut self, provider: Box<dyn GlyphProvider>) -> &mut Self {
+        self.providers.push(Arc::from(provider));
+        self
+    }
+}
+
+impl GlyphProvider for Fallback {
+    fn glyph(&self, c: char) -> Option<&Glyph> {
+        self.providers.iter().find_map(|provider| provider.glyph(c))
+    }
+
+    /// Gets the characters of all the providers without duplicates, [None] if any of
+    /// them can't list its characters.
+    fn characters(&self) -> Option<Vec<char>> {
+        let mut characters = BTreeSet::new();
+        for provider in &self.providers {
+            characters.extend(provider.characters()?);
+        }
+        Some(characters.into_iter().collect())
+    }
+}
+
+/// The amount of [SOURCES] combined by [printables], the ones after them are only
+/// included in the extended printables.
+const PRINTABLE_SOURCES: usize = 5;
+
+/// The [SOURCES] restyling the printables, left out of the extended printables as
+/// they would replace the glyphs of the printables.
+#[cfg(feature = "extended")]
+const STYLE_SOURCES: [&str; 2] = ["blocks", "slant"];
+
+/// The index of the hiragana in [SOURCES], followed by the katakana. It shifts by
+/// one when the Greek map is compiled in before them.
+#[cfg(feature = "kana")]
+const KANA_SOURCE: usize = 9 + cfg!(feature = "greek") as usize;
+
+/// All the character maps embedded into the crate, the ones combined by [printables]
+/// first. Optional maps are only included when their feature is enabled.
+static SOURCES: &[MapSource] = &[
+    MapSource {
+        name: "letters",
+        glyphs: LETTERS_GLYPHS,
+        cache: &LETTERS_MAP,
+    },
+    MapSource {
+        name: "digits",
+        glyphs: DIGITS_GLYPHS,
+        cache: &DIGITS_MAP,
+    },
+    MapSource {
+        name: "punctuation",
+        glyphs: PUNCTUATION_GLYPHS,
+        cache: &PUNCTUATION_MAP,
+    },
+    MapSource {
+        name: "whitespace",
+        glyphs: WHITESPACE_GLYPHS,
+        cache: &WHITESPACE_MAP,
+    },
+    MapSource {
+        name: "latin",
+        glyphs: LATIN_GLYPHS,
+        cache: &LATIN_MAP,
+    },
+    MapSource {
+        name: "arabic_indic_digits",
+        glyphs: ARABIC_INDIC_DIGITS_GLYPHS,
+        cache: &ARABIC_INDIC_DIGITS_MAP,
+    },
+    MapSource {
+        name: "symbols",
+        glyphs: SYMBOLS_GLYPHS,
+        cache: &SYMBOLS_MAP,
+    },
+    MapSource {
+        name: "blocks",
+        glyphs: BLOCKS_GLYPHS,
+        cache: &BLOCKS_MAP,
+    },
+    MapSource {
+        name: "slant",
+        glyphs: SLANT_GLYPHS,
+        cache: &SLANT_MAP,
+    },
+    #[cfg(feature = "greek")]
+    MapSource {
+        name: "greek",
+        glyphs: GREEK_GLYPHS,
+        cache: &GREEK_MAP,
+    },
+    #[cfg(feature = "kana")]
+    MapSource {
+        name: "hiragana",
+        glyphs: HIRAGANA_GLYPHS,
+        cache: &HIRAGANA_MAP,
+    },
+    #[cfg(feature = "kana")]
+    MapSource {
+        name: "katakana",
+        glyphs: KATAKANA_GLYPHS,
+        cache: &KATAKANA_MAP,
+    },
+];
+
+/// A [CharacterMap] source embedded into the crate.
+///
+/// This allows tooling to introspect which maps are compiled in without hardcoding
+/// the functions that return them.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::character_maps;
+///
+/// for source in character_maps::sources() {
+///     println!(
+///         "{}: {} characters ({} bytes)",
+///         source.name(),
+///         source.character_count(),
+///         source.bytes()
+///     );
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MapSource {
+    /// The name of the map.
+    name: &'static str,
+    /// The glyphs of the map generated at compile time.
+    glyphs: &'static [(char, [&'static str; 5])],
+    /// The map built from the glyphs on first use.
+    cache: &'static OnceLock<CharacterMap>,
+}
+
+impl MapSource {
+    /// Gets the name of the map.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::character_maps;
+    ///
+    /// assert_eq!("letters", character_maps::sources()[0].name());
+    /// ```
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Gets the size of the embedded glyphs in bytes.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::character_maps;
+    ///
+    /// assert!(character_maps::sources()[0].bytes() > 0);
+    /// ```
+    pub fn bytes(&self) -> usize {
+        self.glyphs
+            .iter()
+            .flat_map(|(_, rows)| rows)
+            .map(|row| row.len())
+            .sum()
+    }
+
+    /// Gets the amount of characters in the map.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::character_maps;
+    ///
+    /// let digits = character_maps::sources()[1];
+    /// assert_eq!(10, digits.character_count());
+    /// ```
+    pub fn character_count(&self) -> usize {
+        self.cached().len()
+    }
+
+    /// Creates the [CharacterMap] of the source.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::character_maps;
+    ///
+    /// let digits = character_maps::sources()[1];
+    /// assert_eq!(character_maps::digits(), digits.map());
+    /// ```
+    pub fn map(&self) -> CharacterMap {
+        self.cached().clone()
+    }
+
+    /// Gets the map of the source, building it from the glyphs on first use.
+    fn cached(&self) -> &'static CharacterMap {
+        self.cache.get_or_init(|| {
+            self.glyphs
+                .iter()
+                .map(|(c, rows)| (*c, rows.map(Cow::Borrowed)))
+                .collect()
+        })
+    }
+}
+
+/// Returns all the [MapSource]s embedded into the crate.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::character_maps;
+///
+/// let names: Vec<&str> = character_maps::sources().iter().map(|s| s.name()).collect();
+/// assert_eq!(
+///     vec!["letters", "digits", "punctuation", "whitespace", "latin"],
+///     names[..5]
+/// );
+/// ```
+pub fn sources() -> &'static [MapSource] {
+    SOURCES
+}
+
+/// Returns the built-in [CharacterMap] with the given name.
+///
+/// Besides the names of the [sources], `"printables"` can be used to get the
+/// combination of the Latin maps, and `"extended_printables"` the combination of all
+/// of them with the `extended` feature. [None] is returned if no map has the given
+/// name.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::character_maps;
+///
+/// assert_eq!(Some(character_maps::digits()), character_maps::from_name("digits"));
+/// assert_eq!(Some(character_maps::printables()), character_maps::from_name("printables"));
+/// assert_eq!(None, character_maps::from_name("comic-sans"));
+/// ```
+pub fn from_name(name: &str) -> Option<CharacterMap> {
+    if name == "printables" {
+        return Some(printables());
+    }
+    #[cfg(feature = "extended")]
+    if name == "extended_printables" {
+        return Some(extended_printables());
+    }
+
+    sources()
+        .iter()
+        .find(|source| source.name() == name)
+        .map(MapSource::map)
+}
+
+/// Returns a [CharacterMap] only containing the glyphs of `map` needed to print
+/// `chars`, for embedding minimal fonts in size-sensitive binaries and WASM payloads.
+///
+/// Characters missing from `map` are left out.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::character_maps;
+///
+/// let map = character_maps::subset(&character_maps::printables(), "0123456789:");
+/// assert_eq!(10, map.len());
+/// assert_eq!(character_maps::digits(), map);
+/// ```
+pub fn subset(map: &CharacterMap, chars: &str) -> CharacterMap {
+    chars
+        .chars()
+        .filter_map(|c| Some((c, map.get(&c)?.clone())))
+        .collect()
+}
+
+/// Returns the glyphs of `map` as an [OrderedCharacterMap], sorted by character.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::character_maps::{self, CharacterMap};
+///
+/// let map = character_maps::ordered(&character_maps::digits());
+/// assert_eq!("0123456789", map.keys().collect::<String>());
+/// assert_eq!(character_maps::digits(), map.into_iter().collect::<CharacterMap>());
+/// ```
+pub fn ordered(map: &CharacterMap) -> OrderedCharacterMap {
+    map.iter().map(|(c, glyph)| (*c, glyph.clone())).collect()
+}
+
+/// The characters supported by only one of two [CharacterMap]s, see
+/// [coverage_diff()].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CoverageDiff {
+    /// The characters only the first map supports, sorted.
+    only_first: Vec<char>,
+    /// The characters only the second map supports, sorted.
+    only_second: Vec<char>,
+}
+
+impl CoverageDiff {
+    /// Gets the characters only the first map supports, sorted.
+    pub fn only_first(&self) -> &[char] {
+        &self.only_first
+    }
+
+    /// Gets the characters only the second map supports, sorted.
+    pub fn only_second(&self) -> &[char] {
+        &self.only_second
+    }
+
+    /// Checks if both maps support the same characters.
+    pub fn is_empty(&self) -> bool {
+        self.only_first.is_empty() && self.only_second.is_empty()
+    }
+}
+
+/// Compares the characters supported by two maps, for font authors reaching parity
+/// with the built-in maps. The glyphs themselves aren't compared.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::character_maps;
+///
+/// let diff = character_maps::coverage_diff(&character_maps::digits(), &character_maps::printables());
+/// assert!(diff.only_first().is_empty());
+/// assert!(diff.only_second().contains(&'A'));
+///
+/// let diff = character_maps::coverage_diff(&character_maps::digits(), &character_maps::digits());
+/// assert!(diff.is_empty());
+/// ```
+pub fn coverage_diff(a: &CharacterMap, b: &CharacterMap) -> CoverageDiff {
+    let only = |a: &CharacterMap, b: &CharacterMap| {
+        let mut chars: Vec<char> = a.keys().filter(|c| !b.contains_key(c)).copied().collect();
+        chars.sort_unstable();
+        chars
+    };
+
+    CoverageDiff {
+        only_first: only(a, b),
+        only_second: only(b, a),
+    }
+}
 
 /// Returns a [CharacterMap] only containing asii letters.
 ///
@@ -43,7 +558,7 @@ pub type CharacterMap = HashMap<char, [String; 5]>;
 /// - Y
 /// - Z
 pub fn ascii_letters() -> CharacterMap {
-    serde_json::from_str(LETTERS).unwrap()
+    SOURCES[0].map()
 }
 
 /// Returns a [CharacterMap] only containing digits.
@@ -61,7 +576,7 @@ pub fn ascii_letters() -> CharacterMap {
 /// - 8
 /// - 9
 pub fn digits() -> CharacterMap {
-    from_json(DIGITS).unwrap()
+    SOURCES[1].map()
 }
 
 /// Returns a [CharacterMap] only containing punctuations.
@@ -86,7 +601,7 @@ pub fn digits() -> CharacterMap {
 /// - .
 /// - ?
 pub fn punctuation() -> CharacterMap {
-    from_json(PUNCTUATION).unwrap()
+    SOURCES[2].map()
 }
 
 /// Returns a [CharacterMap] only containing whitepaces.
@@ -95,70 +610,321 @@ pub fn punctuation() -> CharacterMap {
 ///
 /// - " " (literal white space)
 pub fn whitespace() -> CharacterMap {
-    from_json(WHITESPACE).unwrap()
+    SOURCES[3].map()
+}
+
+/// Returns a [CharacterMap] only containing accented Latin letters, such as the
+/// ones used in Western European languages.
+///
+/// The glyphs are the ones of the [ascii_letters] with the mark in the top row, so
+/// lowercase letters share the glyphs of uppercase letters.
+///
+/// # Supported Characters
+///
+/// - À Á Â Ã Ä Å and lowercase
+/// - È É Ê Ë and lowercase
+/// - Ì Í Î Ï Ĩ and lowercase
+/// - Ñ Ń and lowercase
+/// - Ò Ó Ô Õ Ö and lowercase
+/// - Ù Ú Û Ü Ũ Ů and lowercase
+/// - Ý Ŷ Ÿ and lowercase
+/// - Æ Ç Ø ß ẞ and lowercase
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{character_maps, BigText};
+///
+/// let printer = BigText::new("é", Some(character_maps::latin()));
+/// assert!(printer.to_string().starts_with("   *  \n***** \n"));
+/// ```
+pub fn latin() -> CharacterMap {
+    SOURCES[4].map()
+}
+
+/// Returns a [CharacterMap] only containing Arabic-Indic digits, for localized
+/// numeric displays.
+///
+/// # Supported Characters
+///
+/// - ٠ (U+0660)
+/// - ١ (U+0661)
+/// - ٢ (U+0662)
+/// - ٣ (U+0663)
+/// - ٤ (U+0664)
+/// - ٥ (U+0665)
+/// - ٦ (U+0666)
+/// - ٧ (U+0667)
+/// - ٨ (U+0668)
+/// - ٩ (U+0669)
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{character_maps, BigText};
+///
+/// let printer = BigText::new("١٢", Some(character_maps::arabic_indic_digits()));
+/// assert!(printer.to_string().starts_with("  *   *   * \n"));
+/// ```
+pub fn arabic_indic_digits() -> CharacterMap {
+    SOURCES[5].map()
+}
+
+/// Returns a [CharacterMap] containing box-drawing characters and arrows, for
+/// diagrams and separators.
+///
+/// The lines of the box-drawing characters reach the edges of their glyphs, so they
+/// connect when printed without [spacing](crate::BigText::set_spacing).
+///
+/// # Supported Characters
+///
+/// - ─ │ ┌ ┐ └ ┘ ├ ┤ ┬ ┴ ┼
+/// - ═ ║ ╔ ╗ ╚ ╝
+/// - → ← ↑ ↓ ↔ ↕
+/// - •
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{character_maps, BigText};
+///
+/// let mut printer = BigText::new("──→", Some(character_maps::symbols()));
+/// printer.set_spacing(0);
+/// assert_eq!("***************", printer.to_string().lines().nth(2).unwrap());
+/// ```
+pub fn symbols() -> CharacterMap {
+    SOURCES[6].map()
+}
+
+/// Returns a [CharacterMap] of the [printables] drawn with full blocks (`█`)
+/// instead of asterisks.
+///
+/// # Supported Characters
+///
+/// The uppercase letters, digits, punctuation and whitespace of the [printables].
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{character_maps, BigText};
+///
+/// let printer = BigText::new("I", Some(character_maps::blocks()));
+/// assert_eq!("█████ ", printer.to_string().lines().next().unwrap());
+/// ```
+pub fn blocks() -> CharacterMap {
+    SOURCES[7].map()
+}
+
+/// Returns a [CharacterMap] of the [printables] slanted to the right, like italics.
+///
+/// # Supported Characters
+///
+/// The uppercase letters, digits, punctuation and whitespace of the [printables].
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{character_maps, BigText};
+///
+/// let output = BigText::new("I", Some(character_maps::slant())).to_string();
+/// let rows: Vec<&str> = output.lines().collect();
+/// assert_eq!("  ***** ", rows[0]);
+/// assert_eq!("*****   ", rows[4]);
+/// ```
+pub fn slant() -> CharacterMap {
+    SOURCES[8].map()
+}
+
+/// Returns a [CharacterMap] containing the Greek alphabet, in uppercase and
+/// lowercase including the final sigma `ς`, for math-flavored banners and Greek
+/// text.
+///
+/// # Supported Characters
+///
+/// - Α to Ω (U+0391 to U+03A9)
+/// - α to ω (U+03B1 to U+03C9)
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{character_maps, BigText};
+///
+/// let printer = BigText::new("ΔΣ", Some(character_maps::greek()));
+/// assert!(printer.to_string().ends_with("***** ***** \n"));
+/// ```
+#[cfg(feature = "greek")]
+pub fn greek() -> CharacterMap {
+    SOURCES[9].map()
 }
 
-/// Returns a [CharacterMap] containting all the characters of the previous maps.
+/// Returns a [CharacterMap] containing the hiragana, including the small kana and
+/// the long-vowel mark `ー`.
+///
+/// The glyphs are 10 columns wide, twice as wide as the other maps, like the
+/// full-width characters they are printed for.
+///
+/// # Supported Characters
+///
+/// - あ to ん, without the kana with (han)dakuten
+/// - ぁ, ぃ, ぅ, ぇ, ぉ, っ, ゃ, ゅ, ょ and ゎ
+/// - ー
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{character_maps, BigText};
+///
+/// let printer = BigText::new("ひらがな", Some(character_maps::hiragana()));
+/// assert_eq!(44, printer.to_string().lines().next().unwrap().chars().count());
+/// ```
+#[cfg(feature = "kana")]
+pub fn hiragana() -> CharacterMap {
+    SOURCES[KANA_SOURCE].map()
+}
+
+/// Returns a [CharacterMap] containing the katakana, including the small kana and
+/// the long-vowel mark `ー`.
+///
+/// The glyphs are 10 columns wide like the [hiragana].
+///
+/// # Supported Characters
+///
+/// - ア to ン, without the kana with (han)dakuten
+/// - ァ, ィ, ゥ, ェ, ォ, ッ, ャ, ュ, ョ and ヮ
+/// - ー
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{character_maps, BigText};
+///
+/// let printer = BigText::new("カタカナ", Some(character_maps::katakana()));
+/// assert_eq!(5, printer.to_string().lines().count());
+/// ```
+#[cfg(feature = "kana")]
+pub fn katakana() -> CharacterMap {
+    SOURCES[KANA_SOURCE + 1].map()
+}
+
+/// Returns a [CharacterMap] containting all the characters of the Latin maps.
 ///
 /// # Supported Characters
 ///
 /// This map is a combination of the maps returned by [ascii_letters], [digits],
-/// [punctuation] and [whitespace]. For more information about the support characters.
-/// See their respective documentation.
+/// [punctuation], [whitespace] and [latin]. For more information about the support
+/// characters. See their respective documentation.
 pub fn printables() -> CharacterMap {
-    let mut printables: CharacterMap = HashMap::new();
+    CharacterMap::clone(&shared_printables())
+}
+
+/// Returns the [printables] map shared by every caller, without copying it.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::character_maps;
+/// use std::sync::Arc;
+///
+/// let map = character_maps::shared_printables();
+/// assert!(Arc::ptr_eq(&map, &character_maps::shared_printables()));
+/// assert_eq!(character_maps::printables(), *map);
+/// ```
+pub fn shared_printables() -> Arc<CharacterMap> {
+    PRINTABLES_MAP
+        .get_or_init(|| {
+            let mut printables: CharacterMap = HashMap::new();
 
-    printables.extend(ascii_letters());
-    printables.extend(digits());
-    printables.extend(punctuation());
-    printables.extend(whitespace());
+            for source in &sources()[..PRINTABLE_SOURCES] {
+                printables.extend(source.map());
+            }
 
-    printables
+            Arc::new(printables)
+        })
+        .clone()
 }
 
-/// Creates a [CharacterMap] from a JSON string.
-fn from_json(map_data: &str) -> Result<CharacterMap> {
-    serde_json::from_str(map_data)
+/// Returns a [CharacterMap] containing the [printables] and every other built-in map,
+/// such as the [arabic_indic_digits] and [symbols]. The [blocks] and [slant] styles
+/// aren't included.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::character_maps;
+///
+/// let map = character_maps::extended_printables();
+/// assert!(map.contains_key(&'A'));
+/// assert!(map.contains_key(&'٣'));
+/// ```
+#[cfg(feature = "extended")]
+pub fn extended_printables() -> CharacterMap {
+    let mut extended = printables();
+    for source in &sources()[PRINTABLE_SOURCES..] {
+        if !STYLE_SOURCES.contains(&source.name()) {
+            extended.extend(source.map());
+        }
+    }
+
+    extended
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    /// Creates a [CharacterMap] from a JSON string.
+    fn from_json(map_data: &str) -> serde_json::Result<CharacterMap> {
+        serde_json::from_str(map_data)
+    }
+
     #[test]
-    fn test_from_json() {
-        let map = HashMap::from([
+    fn cached_maps() {
+        assert_eq!(printables(), printables());
+        assert!(std::ptr::eq(SOURCES[1].cached(), SOURCES[1].cached()));
+        assert!(matches!(digits()[&'0'][0], Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn subset_borrows() {
+        let map = subset(&printables(), "AAB");
+        assert_eq!(2, map.len());
+        assert!(matches!(map[&'A'][0], Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn fallback_order() {
+        let first = HashMap::from([(
+            'A',
+            ["1".into(), "".into(), "".into(), "".into(), "".into()],
+        )]);
+        let second = HashMap::from([
             (
                 'A',
-                [
-                    "".to_string(),
-                    "".to_string(),
-                    "".to_string(),
-                    "".to_string(),
-                    "".to_string(),
-                ],
+                ["2".into(), "".into(), "".into(), "".into(), "".into()],
             ),
             (
-                '1',
-                [
-                    "".to_string(),
-                    "".to_string(),
-                    "".to_string(),
-                    "".to_string(),
-                    "".to_string(),
-                ],
-            ),
-            (
-                '"',
-                [
-                    "".to_string(),
-                    "".to_string(),
-                    "".to_string(),
-                    "".to_string(),
-                    "".to_string(),
-                ],
+                'B',
+                ["2".into(), "".into(), "".into(), "".into(), "".into()],
             ),
         ]);
+        let fallback = Fallback::new(vec![Box::new(first), Box::new(Arc::new(second))]);
+
+        assert_eq!("1", fallback.glyph('A').unwrap()[0]);
+        assert_eq!("2", fallback.glyph('B').unwrap()[0]);
+        assert!(fallback.glyph('C').is_none());
+    }
+
+    #[test]
+    fn generated_maps() {
+        let json = [
+            include_str!("letters.json"),
+            include_str!("digits.json"),
+            include_str!("punctuation.json"),
+            include_str!("whitespace.json"),
+        ];
+
+        for (source, data) in SOURCES.iter().zip(json) {
+            assert_eq!(from_json(data).unwrap(), source.map(), "{}", source.name());
+        }
+    }
+
+    #[test]
+    fn test_from_json() {
+        let map = HashMap::from([
+            ('A', ["".into(), "".into(), "".into(), "".into(), "".into()]),
+            ('1', ["".into(), "".into(), "".into(), "".into(), "".into()]),
+            ('"', ["".into(), "".into(), "".into(), "".into(), "".into()]),
+        ]);
 
         let json_data = "
         {
@@ -0,0 +1,647 @@
+//! A module containing the color types used when rendering a [BigText](crate::BigText).
+
+use std::{error::Error, fmt::Display, str::FromStr};
+use unicode_width::UnicodeWidthChar;
+
+/// A color in the RGB color space.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::color::Rgb;
+///
+/// let orange = Rgb::new(255, 165, 0);
+/// assert_eq!("#ffa500", orange.to_hex());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rgb {
+    /// The red component.
+    pub r: u8,
+    /// The green component.
+    pub g: u8,
+    /// The blue component.
+    pub b: u8,
+}
+
+impl Rgb {
+    /// Black (`#000000`).
+    pub const BLACK: Rgb = Rgb::new(0, 0, 0);
+    /// White (`#ffffff`).
+    pub const WHITE: Rgb = Rgb::new(255, 255, 255);
+
+    /// Constructor Function for [Rgb].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::color::Rgb;
+    ///
+    /// let red = Rgb::new(255, 0, 0);
+    /// assert_eq!(255, red.r);
+    /// ```
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Formats the color as a hex string (`#rrggbb`) as used by CSS and SVG.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::color::Rgb;
+    ///
+    /// assert_eq!("#000000", Rgb::BLACK.to_hex());
+    /// ```
+    pub fn to_hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+
+    /// Converts the color into hue (`0..360`), saturation (`0..=1`) and lightness
+    /// (`0..=1`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::color::Rgb;
+    ///
+    /// assert_eq!((120.0, 1.0, 0.5), Rgb::new(0, 255, 0).to_hsl());
+    /// ```
+    pub fn to_hsl(&self) -> (f32, f32, f32) {
+        let (hue, max, min) = self.hue_max_min();
+        let lightness = (max + min) / 2.0;
+        let saturation = if max == min {
+            0.0
+        } else {
+            (max - min) / (1.0 - (2.0 * lightness - 1.0).abs())
+        };
+
+        (hue, saturation, lightness)
+    }
+
+    /// Creates a color from hue (in degrees), saturation (`0..=1`) and lightness
+    /// (`0..=1`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::color::Rgb;
+    ///
+    /// assert_eq!(Rgb::new(0, 0, 255), Rgb::from_hsl(240.0, 1.0, 0.5));
+    /// ```
+    pub fn from_hsl(hue: f32, saturation: f32, lightness: f32) -> Self {
+        let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+        Self::from_hue_chroma(hue, chroma, lightness - chroma / 2.0)
+    }
+
+    /// Converts the color into hue (`0..360`), saturation (`0..=1`) and value
+    /// (`0..=1`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::color::Rgb;
+    ///
+    /// assert_eq!((0.0, 1.0, 1.0), Rgb::new(255, 0, 0).to_hsv());
+    /// ```
+    pub fn to_hsv(&self) -> (f32, f32, f32) {
+        let (hue, max, min) = self.hue_max_min();
+        let saturation = if max == 0.0 { 0.0 } else { (max - min) / max };
+
+        (hue, saturation, max)
+    }
+
+    /// Creates a color from hue (in degrees), saturation (`0..=1`) and value
+    /// (`0..=1`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::color::Rgb;
+    ///
+    /// assert_eq!(Rgb::new(255, 255, 0), Rgb::from_hsv(60.0, 1.0, 1.0));
+    /// ```
+    pub fn from_hsv(hue: f32, saturation: f32, value: f32) -> Self {
+        let chroma = value * saturation;
+        Self::from_hue_chroma(hue, chroma, value - chroma)
+    }
+
+    /// Gets the hue and the largest and smallest normalized components.
+    fn hue_max_min(&self) -> (f32, f32, f32) {
+        let (r, g, b) = (
+            self.r as f32 / 255.0,
+            self.g as f32 / 255.0,
+            self.b as f32 / 255.0,
+        );
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+
+        let hue = if delta == 0.0 {
+            0.0
+        } else if max == r {
+            60.0 * ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+
+        (hue, max, min)
+    }
+
+    /// Creates a color from a hue, chroma and the amount added to every component.
+    fn from_hue_chroma(hue: f32, chroma: f32, offset: f32) -> Self {
+        let hue = hue.rem_euclid(360.0) / 60.0;
+        let x = chroma * (1.0 - (hue % 2.0 - 1.0).abs());
+        let (r, g, b) = match hue as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+
+        Self::new(
+            to_component(r + offset),
+            to_component(g + offset),
+            to_component(b + offset),
+        )
+    }
+}
+
+/// The error returned when parsing an invalid hex color.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseColorError(String);
+
+impl Display for ParseColorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid color \"{}\", expected #rrggbb", self.0)
+    }
+}
+
+impl Error for ParseColorError {}
+
+impl FromStr for Rgb {
+    type Err = ParseColorError;
+
+    /// Parses a hex color in the `#rrggbb` or `rrggbb` form.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::color::Rgb;
+    ///
+    /// assert_eq!(Ok(Rgb::new(255, 165, 0)), "#ffa500".parse());
+    /// assert!("orange".parse::<Rgb>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex = s.strip_prefix('#').unwrap_or(s);
+        if hex.len() != 6 || !hex.is_ascii() {
+            return Err(ParseColorError(String::from(s)));
+        }
+
+        let component = |range| {
+            u8::from_str_radix(&hex[range], 16).map_err(|_| ParseColorError(String::from(s)))
+        };
+        Ok(Self::new(
+            component(0..2)?,
+            component(2..4)?,
+            component(4..6)?,
+        ))
+    }
+}
+
+/// Converts a normalized color component into a [u8].
+fn to_component(value: f32) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// The color space a [Gradient] is interpolated in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorSpace {
+    /// Interpolates each red, green and blue component.
+    #[default]
+    Rgb,
+    /// Interpolates hue, saturation and lightness.
+    Hsl,
+    /// Interpolates hue, saturation and value.
+    Hsv,
+}
+
+/// The way the hue travels around the color wheel when interpolating in
+/// [ColorSpace::Hsl] or [ColorSpace::Hsv].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HueDirection {
+    /// Takes the shortest way around the color wheel.
+    #[default]
+    Shorter,
+    /// Takes the longest way around the color wheel.
+    Longer,
+    /// Always increases the hue.
+    Increasing,
+    /// Always decreases the hue.
+    Decreasing,
+}
+
+/// A gradient between evenly spaced color stops.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::color::{ColorSpace, Gradient, Rgb};
+///
+/// let mut gradient = Gradient::new(vec![Rgb::new(255, 0, 0), Rgb::new(0, 0, 255)]);
+/// assert_eq!(Rgb::new(128, 0, 128), gradient.at(0.5));
+///
+/// gradient.space = ColorSpace::Hsl;
+/// assert_eq!(Rgb::new(255, 0, 255), gradient.at(0.5));
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Gradient {
+    /// The colors of the gradient, evenly spaced from start to end.
+    pub stops: Vec<Rgb>,
+    /// The color space used for interpolation.
+    pub space: ColorSpace,
+    /// The direction of the hue when interpolating in a cylindrical color space.
+    pub hue_direction: HueDirection,
+}
+
+impl Gradient {
+    /// Constructor Function for [Gradient] interpolating in [ColorSpace::Rgb].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::color::{Gradient, Rgb};
+    ///
+    /// let gradient = Gradient::new(vec![Rgb::BLACK, Rgb::WHITE]);
+    /// assert_eq!(Rgb::WHITE, gradient.at(1.0));
+    /// ```
+    pub fn new(stops: Vec<Rgb>) -> Self {
+        Self {
+            stops,
+            space: ColorSpace::Rgb,
+            hue_direction: HueDirection::Shorter,
+        }
+    }
+
+    /// Creates a gradient going through every hue from red to magenta.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::color::{Gradient, Rgb};
+    ///
+    /// let rainbow = Gradient::rainbow();
+    /// assert_eq!(Rgb::new(255, 0, 0), rainbow.at(0.0));
+    /// assert_eq!(Rgb::new(0, 255, 255), rainbow.at(0.6));
+    /// ```
+    pub fn rainbow() -> Self {
+        Self {
+            stops: vec![Rgb::new(255, 0, 0), Rgb::new(255, 0, 255)],
+            space: ColorSpace::Hsv,
+            hue_direction: HueDirection::Increasing,
+        }
+    }
+
+    /// Gets the color at `t`, where `0.0` is the first stop and `1.0` is the last.
+    ///
+    /// Values outside of `0.0..=1.0` are clamped. A gradient without any stops is
+    /// always [Rgb::BLACK].
+    pub fn at(&self, t: f32) -> Rgb {
+        match self.stops.len() {
+            0 => return Rgb::BLACK,
+            1 => return self.stops[0],
+            _ => (),
+        }
+
+        let position = t.clamp(0.0, 1.0) * (self.stops.len() - 1) as f32;
+        let index = (position as usize).min(self.stops.len() - 2);
+        let (start, end) = (self.stops[index], self.stops[index + 1]);
+        let t = position - index as f32;
+
+        match self.space {
+            ColorSpace::Rgb => Rgb::new(
+                lerp_component(start.r, end.r, t),
+                lerp_component(start.g, end.g, t),
+                lerp_component(start.b, end.b, t),
+            ),
+            ColorSpace::Hsl => {
+                let (h, s, l) = self.lerp_cylindrical(start.to_hsl(), end.to_hsl(), t);
+                Rgb::from_hsl(h, s, l)
+            }
+            ColorSpace::Hsv => {
+                let (h, s, v) = self.lerp_cylindrical(start.to_hsv(), end.to_hsv(), t);
+                Rgb::from_hsv(h, s, v)
+            }
+        }
+    }
+
+    /// Interpolates two colors in a cylindrical color space following the hue direction.
+    fn lerp_cylindrical(
+        &self,
+        start: (f32, f32, f32),
+        end: (f32, f32, f32),
+        t: f32,
+    ) -> (f32, f32, f32) {
+        // Difference going up the color wheel
+        let increasing = (end.0 - start.0).rem_euclid(360.0);
+        let delta = match self.hue_direction {
+            HueDirection::Increasing => increasing,
+            HueDirection::Decreasing => increasing - 360.0,
+            HueDirection::Shorter if increasing <= 180.0 => increasing,
+            HueDirection::Shorter => increasing - 360.0,
+            HueDirection::Longer if increasing > 180.0 => increasing,
+            HueDirection::Longer => increasing - 360.0,
+        };
+
+        (
+            (start.0 + delta * t).rem_euclid(360.0),
+            start.1 + (end.1 - start.1) * t,
+            start.2 + (end.2 - start.2) * t,
+        )
+    }
+}
+
+/// Linearly interpolates a single color component.
+fn lerp_component(start: u8, end: u8, t: f32) -> u8 {
+    (start as f32 + (end as f32 - start as f32) * t).round() as u8
+}
+
+/// How the ascii-art of a [BigText](crate::BigText) is colored.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{color::{ColorStyle, Gradient, Rgb}, BigText};
+///
+/// let mut printer = BigText::new("HI", None);
+/// printer.set_color(Some(ColorStyle::Gradient(Gradient::rainbow())));
+/// println!("{}", printer);
+///
+/// printer.set_color(Some(ColorStyle::Solid(Rgb::new(255, 0, 0))));
+/// println!("{}", printer);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColorStyle {
+    /// Every cell has the same color.
+    Solid(Rgb),
+    /// The color changes from left to right following the gradient.
+    Gradient(Gradient),
+}
+
+impl ColorStyle {
+    /// Gets the color of column `x` out of `width` columns.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::color::{ColorStyle, Gradient, Rgb};
+    ///
+    /// let style = ColorStyle::Gradient(Gradient::new(vec![Rgb::BLACK, Rgb::WHITE]));
+    /// assert_eq!(Rgb::BLACK, style.color_at(0, 11));
+    /// assert_eq!(Rgb::WHITE, style.color_at(10, 11));
+    /// ```
+    pub fn color_at(&self, x: usize, width: usize) -> Rgb {
+        match self {
+            Self::Solid(color) => *color,
+            Self::Gradient(gradient) => {
+                let t = if width > 1 {
+                    x as f32 / (width - 1) as f32
+                } else {
+                    0.0
+                };
+                gradient.at(t)
+            }
+        }
+    }
+}
+
+/// The escape codes used to print colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorFormat {
+    /// ANSI true color escape sequences, understood by most terminals.
+    #[default]
+    Ansi,
+    /// mIRC color codes, understood by IRC clients. Colors are reduced to the
+    /// nearest of the 16 standard mIRC colors.
+    Irc,
+}
+
+/// When colors should be written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ColorChoice {
+    /// Colors are always written.
+    Always,
+    /// Colors are never written.
+    Never,
+    /// Colors are written to terminals, following the `NO_COLOR`, `CLICOLOR_FORCE`
+    /// and `CLICOLOR` environment variables.
+    #[default]
+    Auto,
+}
+
+impl ColorChoice {
+    /// Decides whether colors should be written to a stream.
+    ///
+    /// With [ColorChoice::Auto], a non-empty `NO_COLOR` disables colors, a non-empty
+    /// `CLICOLOR_FORCE` other than `0` enables them and `CLICOLOR=0` disables them.
+    /// Otherwise colors are only written if the stream is a terminal.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::color::ColorChoice;
+    ///
+    /// assert!(ColorChoice::Always.should_colorize(false));
+    /// assert!(!ColorChoice::Never.should_colorize(true));
+    /// ```
+    pub fn should_colorize(self, is_terminal: bool) -> bool {
+        let variable = |name| std::env::var_os(name).filter(|value| !value.is_empty());
+
+        match self {
+            Self::Always => true,
+            Self::Never => false,
+            Self::Auto if variable("NO_COLOR").is_some() => false,
+            Self::Auto if variable("CLICOLOR_FORCE").is_some_and(|value| value != "0") => true,
+            Self::Auto if variable("CLICOLOR").is_some_and(|value| value == "0") => false,
+            Self::Auto => is_terminal,
+        }
+    }
+}
+
+/// The 16 standard mIRC colors, indexed by their color code.
+const IRC_PALETTE: [Rgb; 16] = [
+    Rgb::new(255, 255, 255),
+    Rgb::new(0, 0, 0),
+    Rgb::new(0, 0, 127),
+    Rgb::new(0, 147, 0),
+    Rgb::new(255, 0, 0),
+    Rgb::new(127, 0, 0),
+    Rgb::new(156, 0, 156),
+    Rgb::new(252, 127, 0),
+    Rgb::new(255, 255, 0),
+    Rgb::new(0, 252, 0),
+    Rgb::new(0, 147, 147),
+    Rgb::new(0, 255, 255),
+    Rgb::new(0, 0, 252),
+    Rgb::new(255, 0, 255),
+    Rgb::new(127, 127, 127),
+    Rgb::new(210, 210, 210),
+];
+
+impl Rgb {
+    /// Gets the code of the nearest standard mIRC color.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::color::Rgb;
+    ///
+    /// assert_eq!(4, Rgb::new(250, 10, 10).to_irc());
+    /// assert_eq!(1, Rgb::BLACK.to_irc());
+    /// ```
+    pub fn to_irc(&self) -> u8 {
+        let distance = |other: &Rgb| {
+            let dr = self.r as i32 - other.r as i32;
+            let dg = self.g as i32 - other.g as i32;
+            let db = self.b as i32 - other.b as i32;
+            dr * dr + dg * dg + db * db
+        };
+
+        IRC_PALETTE
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, color)| distance(color))
+            .map_or(1, |(code, _)| code as u8)
+    }
+}
+
+impl FromStr for ColorStyle {
+    type Err = ParseColorError;
+
+    /// Parses a color style.
+    ///
+    /// `"rainbow"` is parsed as [Gradient::rainbow()], comma separated hex colors as
+    /// a [Gradient] and a single hex color as a solid color.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::color::{ColorStyle, Gradient, Rgb};
+    ///
+    /// assert_eq!(Ok(ColorStyle::Solid(Rgb::WHITE)), "#ffffff".parse());
+    /// assert_eq!(
+    ///     Ok(ColorStyle::Gradient(Gradient::new(vec![Rgb::BLACK, Rgb::WHITE]))),
+    ///     "#000000,#ffffff".parse()
+    /// );
+    /// assert_eq!(Ok(ColorStyle::Gradient(Gradient::rainbow())), "rainbow".parse());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("rainbow") {
+            return Ok(Self::Gradient(Gradient::rainbow()));
+        }
+
+        let stops = s
+            .split(',')
+            .map(|stop| stop.trim().parse())
+            .collect::<Result<Vec<Rgb>, ParseColorError>>()?;
+        match stops.as_slice() {
+            [color] => Ok(Self::Solid(*color)),
+            _ => Ok(Self::Gradient(Gradient::new(stops))),
+        }
+    }
+}
+
+/// Colors the filled cells of a row using the escape codes of `format`.
+///
+/// The colors of `style` are spread over the terminal columns from `start` to
+/// `width`, full-width characters taking up two of them.
+pub(crate) fn colorize_row(
+    style: &ColorStyle,
+    format: ColorFormat,
+    row: &str,
+    start: usize,
+    width: usize,
+) -> String {
+    let mut colored = String::with_capacity(row.len() * 4);
+    let mut current = None;
+    // The column of the cell, full-width characters taking up two
+    let mut x: usize = 0;
+
+    for cell in row.chars() {
+        if !cell.is_whitespace() {
+            let color = style.color_at(x.saturating_sub(start), width.saturating_sub(start));
+            if current != Some(color) {
+                match format {
+                    ColorFormat::Ansi => {
+                        colored.push_str(&format!("\x1b[38;2;{};{};{}m", color.r, color.g, color.b))
+                    }
+                    ColorFormat::Irc => colored.push_str(&format!("\x03{:02}", color.to_irc())),
+                }
+                current = Some(color);
+            }
+        }
+        colored.push(cell);
+        x += cell.width().unwrap_or(0);
+    }
+
+    if current.is_some() {
+        match format {
+            ColorFormat::Ansi => colored.push_str("\x1b[0m"),
+            ColorFormat::Irc => colored.push('\x0f'),
+        }
+    }
+    colored
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hsl_round_trip() {
+        let color = Rgb::new(12, 200, 99);
+        let (h, s, l) = color.to_hsl();
+        assert_eq!(color, Rgb::from_hsl(h, s, l));
+        let (h, s, v) = color.to_hsv();
+        assert_eq!(color, Rgb::from_hsv(h, s, v));
+    }
+
+    #[test]
+    fn hue_directions() {
+        let mut gradient = Gradient::new(vec![Rgb::new(255, 0, 0), Rgb::new(0, 0, 255)]);
+        gradient.space = ColorSpace::Hsv;
+
+        // Red (0) to blue (240) is shorter going through magenta (300)
+        assert_eq!(Rgb::new(255, 0, 255), gradient.at(0.5));
+        gradient.hue_direction = HueDirection::Increasing;
+        assert_eq!(Rgb::new(0, 255, 0), gradient.at(0.5));
+        gradient.hue_direction = HueDirection::Longer;
+        assert_eq!(Rgb::new(0, 255, 0), gradient.at(0.5));
+        gradient.hue_direction = HueDirection::Decreasing;
+        assert_eq!(Rgb::new(255, 0, 255), gradient.at(0.5));
+    }
+
+    #[test]
+    fn colorize_row_skips_blanks() {
+        let style = ColorStyle::Solid(Rgb::new(1, 2, 3));
+        assert_eq!(
+            " \x1b[38;2;1;2;3m** \x1b[0m",
+            colorize_row(&style, ColorFormat::Ansi, " ** ", 0, 4)
+        );
+        assert_eq!(
+            "    ",
+            colorize_row(&style, ColorFormat::Ansi, "    ", 0, 4)
+        );
+    }
+
+    #[test]
+    fn colorize_row_full_width() {
+        let style = ColorStyle::Gradient(Gradient::new(vec![Rgb::BLACK, Rgb::WHITE]));
+        let code = |x: usize| {
+            let color = style.color_at(x, 4);
+            format!("\x1b[38;2;{};{};{}m", color.r, color.g, color.b)
+        };
+
+        // The cells after the full-width one are in the columns 2 and 3
+        assert_eq!(
+            format!("{}漢{}*{}*\x1b[0m", code(0), code(2), code(3)),
+            colorize_row(&style, ColorFormat::Ansi, "漢**", 0, 4)
+        );
+    }
+
+    #[test]
+    fn colorize_row_irc() {
+        let style = ColorStyle::Solid(Rgb::new(0, 0, 255));
+        assert_eq!(
+            " \x0312** \x0f",
+            colorize_row(&style, ColorFormat::Irc, " ** ", 0, 4)
+        );
+    }
+}
@@ -0,0 +1,193 @@
+//! A module containing a helper to wrap a [BigText] in the comment syntax of a
+//! programming [Language], for banners at the top of source files.
+
+use crate::{display_width, BigText};
+use std::{error::Error, fmt::Display};
+
+/// The character the rules above and below a comment banner are drawn with.
+const RULE: char = '=';
+
+/// A programming language whose comment syntax a banner is wrapped in.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::comment::Language;
+///
+/// assert_eq!("#", Language::Python.line_marker());
+/// assert_eq!(" *", Language::C.line_marker());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Language {
+    /// Line comments starting with `//`.
+    Rust,
+    /// Line comments starting with `#`.
+    Python,
+    /// A block comment between `/*` and `*/`, with every row starting with ` *`.
+    C,
+    /// Line comments starting with `#`, for shell scripts.
+    Shell,
+}
+
+impl Language {
+    /// Gets the characters every row of the banner starts with.
+    pub fn line_marker(&self) -> &'static str {
+        match self {
+            Language::Rust => "//",
+            Language::Python | Language::Shell => "#",
+            Language::C => " *",
+        }
+    }
+
+    /// Gets the characters ending a block comment of the language, which can't
+    /// appear inside the banner.
+    fn terminator(&self) -> Option<&'static str> {
+        match self {
+            Language::C => Some("*/"),
+            _ => None,
+        }
+    }
+
+    /// Gets the first and last line of the banner, the rules between the comment
+    /// delimiters of the language.
+    fn rules(&self, rule: &str) -> (String, String) {
+        match self {
+            Language::C => (
+                format!("/* {rule}").trim_end().to_string(),
+                format!(" * {rule}").trim_end().to_string() + " */",
+            ),
+            _ => {
+                let line = format!("{} {rule}", self.line_marker())
+                    .trim_end()
+                    .to_string();
+                (line.clone(), line)
+            }
+        }
+    }
+}
+
+/// The errors that can occur when wrapping a banner in a comment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommentError {
+    /// A row of the ascii-art contains the characters ending the block comment,
+    /// such as `*/` in C, which would end the comment early.
+    Terminator {
+        /// The index of the row.
+        row: usize,
+        /// The characters ending the comment.
+        terminator: &'static str,
+    },
+}
+
+impl Display for CommentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Terminator { row, terminator } => write!(
+                f,
+                "row {row} of the banner contains \"{terminator}\", which would end the comment"
+            ),
+        }
+    }
+}
+
+impl Error for CommentError {}
+
+/// Renders `text` inside a comment of the given `language`, between two rules as
+/// wide as the text.
+///
+/// Trailing blanks are removed from the rows, so the banner doesn't leave trailing
+/// whitespace in the source file. Colors, the [line prefix](BigText::set_line_prefix)
+/// and the [line suffix](BigText::set_line_suffix) aren't included, while the
+/// [line ending](BigText::set_line_ending) and the
+/// [trailing newline](BigText::set_trailing_newline) are.
+///
+/// Fails when a row contains the characters ending a block comment of the language.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{comment::{self, Language}, BigText};
+///
+/// let banner = comment::comment_banner(&BigText::new("I", None), Language::Rust).unwrap();
+/// let rows: Vec<&str> = banner.lines().collect();
+///
+/// assert_eq!(7, rows.len());
+/// assert_eq!("// =====", rows[0]);
+/// assert_eq!("// *****", rows[1]);
+/// assert_eq!("//   *", rows[2]);
+/// assert_eq!(rows[0], rows[6]);
+/// ```
+pub fn comment_banner(text: &BigText, language: Language) -> Result<String, CommentError> {
+    let rows = text.render_rows(text.text());
+    let rows: Vec<&str> = rows.iter().map(|row| row.trim_end()).collect();
+    if let Some(terminator) = language.terminator() {
+        if let Some(row) = rows.iter().position(|row| row.contains(terminator)) {
+            return Err(CommentError::Terminator { row, terminator });
+        }
+    }
+    let width = rows.iter().map(|row| display_width(row)).max().unwrap_or(0);
+
+    let (top, bottom) = language.rules(&RULE.to_string().repeat(width));
+    let mut lines = vec![top];
+    lines.extend(rows.iter().map(|row| match row.is_empty() {
+        true => language.line_marker().to_string(),
+        false => format!("{} {row}", language.line_marker()),
+    }));
+    lines.push(bottom);
+
+    let ending = text.line_ending().as_str();
+    let mut banner = lines.join(ending);
+    if text.trailing_newline() {
+        banner.push_str(ending);
+    }
+
+    Ok(banner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::LineEnding;
+    use std::collections::HashMap;
+
+    #[test]
+    fn block_comment() {
+        let banner = comment_banner(&BigText::new(".", None), Language::C).unwrap();
+        let rows: Vec<&str> = banner.lines().collect();
+
+        assert_eq!(7, rows.len());
+        assert!(rows[0].starts_with("/* ="));
+        assert!(rows[6].starts_with(" * =") && rows[6].ends_with(" */"));
+        assert_eq!(" *", rows[1]);
+        assert_eq!(" * **", rows[4]);
+    }
+
+    #[test]
+    fn comment_terminator() {
+        let map = HashMap::from([(
+            '/',
+            ["".into(), "*/".into(), "".into(), "".into(), "".into()],
+        )]);
+        let text = BigText::new("/", Some(map));
+
+        assert_eq!(
+            Err(CommentError::Terminator {
+                row: 1,
+                terminator: "*/"
+            }),
+            comment_banner(&text, Language::C)
+        );
+        assert!(comment_banner(&text, Language::Rust).is_ok());
+    }
+
+    #[test]
+    fn line_endings() {
+        let mut text = BigText::new("I", None);
+        text.set_line_ending(LineEnding::CrLf)
+            .set_trailing_newline(false)
+            .set_line_prefix("> ");
+        let banner = comment_banner(&text, Language::Shell).unwrap();
+
+        assert_eq!(6, banner.matches("\r\n").count());
+        assert!(banner.ends_with("# ====="));
+        assert!(banner.starts_with("# ====="));
+    }
+}
@@ -0,0 +1,53 @@
+//! A module for printing a [BigText] into compressed writers.
+//!
+//! The gzip helper requires the `gzip` feature and the zstd helper requires the
+//! `zstd` feature.
+
+use crate::BigText;
+use std::io::{self, Write};
+
+impl BigText {
+    /// Prints the stored string into a gzip stream written to `writer`.
+    ///
+    /// `level` is the compression level between `0` (none) and `9` (best). The
+    /// stream is finished before `writer` is returned.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// let printer = BigText::new("HI", None);
+    /// let compressed = printer.print_gzip(Vec::new(), 6).unwrap();
+    ///
+    /// // gzip magic number
+    /// assert_eq!(&[0x1f, 0x8b], &compressed[0..2]);
+    /// ```
+    #[cfg(feature = "gzip")]
+    pub fn print_gzip<W: Write>(&self, writer: W, level: u32) -> io::Result<W> {
+        let mut encoder = flate2::write::GzEncoder::new(writer, flate2::Compression::new(level));
+        self.print(Some(&mut encoder))?;
+        encoder.finish()
+    }
+
+    /// Prints the stored string into a zstd stream written to `writer`.
+    ///
+    /// `level` is the zstd compression level, `0` uses zstd's default level. The
+    /// stream is finished before `writer` is returned.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// let printer = BigText::new("HI", None);
+    /// let compressed = printer.print_zstd(Vec::new(), 0).unwrap();
+    ///
+    /// let decompressed = zstd::decode_all(compressed.as_slice()).unwrap();
+    /// assert_eq!(printer.to_string().as_bytes(), decompressed.as_slice());
+    /// ```
+    #[cfg(feature = "zstd")]
+    pub fn print_zstd<W: Write>(&self, writer: W, level: i32) -> io::Result<W> {
+        let mut encoder = zstd::Encoder::new(writer, level)?;
+        self.print(Some(&mut encoder))?;
+        encoder.finish()
+    }
+}
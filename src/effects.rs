@@ -0,0 +1,627 @@
+//! A module containing [Effect]s that decorate the rendered text of a
+//! [BigText](crate::BigText).
+//!
+//! Effects are applied one after another in the order they were
+//! [added](crate::BigText::with_effect), so they can be combined freely.
+//!
+//! # Examples
+//! ```rust
+//! use print_big_text_rs::{effects::{Flip, Shadow}, BigText};
+//!
+//! let mut printer = BigText::new("I", None);
+//! printer.with_effect(Shadow).with_effect(Flip);
+//! println!("{printer}");
+//! ```
+
+use crate::{
+    canvas::{self, WIDE_TAIL},
+    random::Random,
+};
+use unicode_width::UnicodeWidthChar;
+
+/// The rendered rows of a text, as a grid of cells.
+///
+/// Every row has the same amount of cells, blank cells are spaces. Every cell is a
+/// terminal column, the second column of a full-width character is a `'\0'` cell.
+pub type Grid = Vec<Vec<char>>;
+
+/// The character filling the cells of an [Invert]ed grid.
+pub const INVERT_FILL: char = '*';
+/// The character used for the cells of a [Shadow].
+pub const SHADOW_FILL: char = '.';
+/// The character used for the cells of an [Outline].
+pub const OUTLINE_FILL: char = '+';
+
+/// A transformation of the rendered [Grid] of a text.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::effects::{Effect, Grid};
+/// use print_big_text_rs::BigText;
+///
+/// /// Replaces every filled cell with a `#`.
+/// #[derive(Debug)]
+/// struct Hash;
+///
+/// impl Effect for Hash {
+///     fn apply(&self, grid: Grid) -> Grid {
+///         grid.into_iter()
+///             .map(|row| row.into_iter().map(|c| if c == ' ' { c } else { '#' }).collect())
+///             .collect()
+///     }
+/// }
+///
+/// let mut printer = BigText::new("I", None);
+/// printer.with_effect(Hash);
+/// assert_eq!("#####\n  #  \n  #  \n  #  \n#####\n", printer.to_string().replace(" \n", "\n"));
+/// ```
+pub trait Effect: std::fmt::Debug + Send + Sync {
+    /// Transforms the grid, returning the decorated grid.
+    fn apply(&self, grid: Grid) -> Grid;
+}
+
+/// Casts a shadow one row below and one column to the right of the text.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::effects::{Effect, Shadow};
+///
+/// let grid = Shadow.apply(vec![vec!['*', '*']]);
+/// assert_eq!(vec![vec!['*', '*', ' '], vec![' ', '.', '.']], grid);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Shadow;
+
+impl Effect for Shadow {
+    fn apply(&self, grid: Grid) -> Grid {
+        let width = width(&grid) + 1;
+        let mut shadowed = vec![vec![' '; width]; grid.len() + 1];
+
+        for (y, row) in grid.iter().enumerate() {
+            for (x, c) in row.iter().enumerate() {
+                if !is_blank(*c) {
+                    shadowed[y + 1][x + 1] = SHADOW_FILL;
+                }
+            }
+        }
+        // Drawing the text over its shadow
+        for (y, row) in grid.into_iter().enumerate() {
+            for (x, c) in row.into_iter().enumerate() {
+                if !is_blank(c) {
+                    shadowed[y][x] = c;
+                }
+            }
+        }
+
+        shadowed
+    }
+}
+
+/// Surrounds the text with an outline, growing the grid by a cell on every side.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::effects::{Effect, Outline};
+///
+/// let grid = Outline.apply(vec![vec!['*']]);
+/// assert_eq!(vec![vec!['+'; 3], vec!['+', '*', '+'], vec!['+'; 3]], grid);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Outline;
+
+impl Effect for Outline {
+    fn apply(&self, grid: Grid) -> Grid {
+        let width = width(&grid) + 2;
+        let mut outlined = vec![vec![' '; width]; grid.len() + 2];
+
+        for (y, row) in grid.iter().enumerate() {
+            for (x, c) in row.iter().enumerate() {
+                if is_blank(*c) {
+                    continue;
+                }
+                // Marking every neighbour, the text is drawn over it afterwards
+                for cell in outlined[y..y + 3]
+                    .iter_mut()
+                    .flat_map(|row| &mut row[x..x + 3])
+                {
+                    *cell = OUTLINE_FILL;
+                }
+            }
+        }
+        for (y, row) in grid.into_iter().enumerate() {
+            for (x, c) in row.into_iter().enumerate() {
+                if !is_blank(c) {
+                    outlined[y + 1][x + 1] = c;
+                }
+            }
+        }
+
+        outlined
+    }
+}
+
+/// Swaps the filled and blank cells of the text.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::effects::{Effect, Invert};
+///
+/// assert_eq!(vec![vec![' ', '*']], Invert.apply(vec![vec!['#', ' ']]));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Invert;
+
+impl Effect for Invert {
+    fn apply(&self, grid: Grid) -> Grid {
+        grid.into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .map(|c| if is_blank(c) { INVERT_FILL } else { ' ' })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// Flips the text upside down.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::effects::{Effect, Flip};
+///
+/// assert_eq!(vec![vec!['b'], vec!['a']], Flip.apply(vec![vec!['a'], vec!['b']]));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Flip;
+
+impl Effect for Flip {
+    fn apply(&self, mut grid: Grid) -> Grid {
+        grid.reverse();
+        grid
+    }
+}
+
+/// Mirrors the text from left to right.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::effects::{Effect, Mirror};
+///
+/// assert_eq!(vec![vec!['b', 'a']], Mirror.apply(vec![vec!['a', 'b']]));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Mirror;
+
+impl Effect for Mirror {
+    fn apply(&self, mut grid: Grid) -> Grid {
+        for row in &mut grid {
+            row.reverse();
+            // Putting the second column of full-width characters after them again
+            for x in 1..row.len() {
+                if row[x - 1] == WIDE_TAIL && row[x].width() == Some(2) {
+                    row.swap(x - 1, x);
+                }
+            }
+        }
+        grid
+    }
+}
+
+/// Shifts every column down along a sine curve, producing a wavy text.
+///
+/// The grid grows by twice the amplitude, columns at the top of the curve aren't
+/// shifted and columns at the bottom are shifted by twice the amplitude.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::effects::{Effect, Wave};
+///
+/// let wave = Wave { amplitude: 1, wavelength: 4 };
+/// let grid = wave.apply(vec![vec!['*'; 4]]);
+///
+/// assert_eq!(3, grid.len());
+/// assert_eq!(vec![' ', ' ', ' ', '*'], grid[0]);
+/// assert_eq!(vec!['*', ' ', '*', ' '], grid[1]);
+/// assert_eq!(vec![' ', '*', ' ', ' '], grid[2]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Wave {
+    /// The amount of rows the curve rises and falls by.
+    pub amplitude: usize,
+    /// The amount of columns of a full period of the curve.
+    pub wavelength: usize,
+}
+
+impl Wave {
+    /// Gets the amount of rows column `x` is shifted down by.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::effects::Wave;
+    ///
+    /// let wave = Wave { amplitude: 2, wavelength: 8 };
+    /// assert_eq!(2, wave.offset(0));
+    /// assert_eq!(4, wave.offset(2));
+    /// assert_eq!(0, wave.offset(6));
+    /// ```
+    pub fn offset(&self, x: usize) -> usize {
+        let phase = x as f64 / self.wavelength.max(1) as f64 * std::f64::consts::TAU;
+        (self.amplitude as f64 * (1.0 + phase.sin())).round() as usize
+    }
+}
+
+impl Default for Wave {
+    /// A gentle wave rising and falling by a row every 16 columns.
+    fn default() -> Self {
+        Self {
+            amplitude: 1,
+            wavelength: 16,
+        }
+    }
+}
+
+impl Effect for Wave {
+    fn apply(&self, grid: Grid) -> Grid {
+        let width = width(&grid);
+        let mut waved = vec![vec![' '; width]; grid.len() + self.amplitude * 2];
+
+        for (y, row) in grid.into_iter().enumerate() {
+            for (x, c) in row.into_iter().enumerate() {
+                waved[y + self.offset(x)][x] = c;
+            }
+        }
+
+        waved
+    }
+}
+
+/// Slants the text by shifting every row further than the row below it, giving any
+/// font an italic look.
+///
+/// A positive shift slants the text to the right like italics, a negative shift
+/// slants it to the left. The grid grows by the shift of the most shifted row.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::effects::{Effect, Skew};
+///
+/// let grid = Skew { shift: 1 }.apply(vec![vec!['*'], vec!['*'], vec!['*']]);
+/// assert_eq!(vec![vec![' ', ' ', '*'], vec![' ', '*', ' '], vec!['*', ' ', ' ']], grid);
+///
+/// let grid = Skew { shift: -1 }.apply(vec![vec!['*'], vec!['*']]);
+/// assert_eq!(vec![vec!['*', ' '], vec![' ', '*']], grid);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Skew {
+    /// The amount of columns every row is shifted by relative to the row below it.
+    pub shift: isize,
+}
+
+impl Default for Skew {
+    /// An italic slant of a column every row.
+    fn default() -> Self {
+        Self { shift: 1 }
+    }
+}
+
+impl Effect for Skew {
+    fn apply(&self, grid: Grid) -> Grid {
+        let height = grid.len();
+        let step = self.shift.unsigned_abs();
+        let width = width(&grid) + height.saturating_sub(1) * step;
+
+        grid.into_iter()
+            .enumerate()
+            .map(|(y, row)| {
+                let rows_below = match self.shift >= 0 {
+                    true => height - 1 - y,
+                    false => y,
+                };
+                let mut skewed = vec![' '; rows_below * step];
+                skewed.extend(row);
+                skewed.resize(width, ' ');
+                skewed
+            })
+            .collect()
+    }
+}
+
+/// Scales the text up by repeating every cell `factor` times horizontally and
+/// every row `factor` times vertically.
+///
+/// Scaling before a [Shade] gives it more neighbours to work with, which smooths
+/// out the edges of small fonts. A factor of 0 is treated as 1.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::effects::{Effect, Scale};
+///
+/// let grid = Scale { factor: 2 }.apply(vec![vec!['*', ' ']]);
+/// assert_eq!(vec![vec!['*', '*', ' ', ' ']; 2], grid);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Scale {
+    /// The amount of times every cell and row is repeated.
+    pub factor: usize,
+}
+
+impl Default for Scale {
+    /// Doubles the size of the text.
+    fn default() -> Self {
+        Self { factor: 2 }
+    }
+}
+
+impl Effect for Scale {
+    fn apply(&self, grid: Grid) -> Grid {
+        let factor = self.factor.max(1);
+
+        grid.into_iter()
+            .flat_map(|row| {
+                let mut scaled = Vec::with_capacity(row.len() * factor);
+                let mut cells = row.into_iter().peekable();
+                while let Some(c) = cells.next() {
+                    // Repeating full-width characters along with their second column
+                    match c.width() == Some(2) && cells.peek() == Some(&WIDE_TAIL) {
+                        true => {
+                            cells.next();
+                            for _ in 0..factor {
+                                scaled.extend([c, WIDE_TAIL]);
+                            }
+                        }
+                        false => scaled.extend(std::iter::repeat_n(c, factor)),
+                    }
+                }
+                std::iter::repeat_n(scaled, factor)
+            })
+            .collect()
+    }
+}
+
+/// The characters a [Shade]d cell is drawn with, from the most isolated to the
+/// most surrounded.
+const SHADES: [char; 4] = ['░', '▒', '▓', '█'];
+
+/// Smooths the text by drawing the filled cells with shade characters, lighter
+/// the fewer filled neighbours they have.
+///
+/// Filled cells surrounded by at least 6 of their 8 neighbours are drawn solid
+/// (`█`), edges get darker shades (`▓`, `▒`) and isolated cells the lightest
+/// one (`░`). This works best on large texts, such as the ones drawn with
+/// big fonts.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::effects::{Effect, Shade};
+///
+/// let grid = Shade.apply(vec![vec!['*'; 3]; 3]);
+/// assert_eq!(vec!['▒', '▓', '▒'], grid[0]);
+/// assert_eq!(vec!['▓', '█', '▓'], grid[1]);
+///
+/// assert_eq!(vec![vec!['░', ' ']], Shade.apply(vec![vec!['*', ' ']]));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Shade;
+
+impl Effect for Shade {
+    fn apply(&self, grid: Grid) -> Grid {
+        let filled = |x: usize, y: usize| {
+            grid.get(y)
+                .and_then(|row| row.get(x))
+                .is_some_and(|c| !is_blank(*c))
+        };
+
+        (0..grid.len())
+            .map(|y| {
+                (0..grid[y].len())
+                    .map(|x| {
+                        if !filled(x, y) {
+                            return grid[y][x];
+                        }
+
+                        let neighbours = (y.saturating_sub(1)..=y + 1)
+                            .flat_map(|ny| (x.saturating_sub(1)..=x + 1).map(move |nx| (nx, ny)))
+                            .filter(|(nx, ny)| (*nx, *ny) != (x, y) && filled(*nx, *ny))
+                            .count();
+                        match neighbours {
+                            0..=1 => SHADES[0],
+                            2..=3 => SHADES[1],
+                            4..=5 => SHADES[2],
+                            _ => SHADES[3],
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// The characters filled cells are corrupted into by a [Glitch].
+const GLITCH_CHARS: [char; 8] = ['#', '%', '&', '@', '$', '?', '!', '/'];
+
+/// Randomly corrupts or shifts a small part of the filled cells, for glitchy
+/// animated intros.
+///
+/// A glitched cell is either replaced by a random symbol or moved a column to the
+/// side. The same seed always glitches the same cells, so animations change the
+/// seed on every frame.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{effects::{Effect, Glitch}, BigText};
+///
+/// let glitch = Glitch { rate: 0.2, seed: 7 };
+/// let grid = vec![vec!['*'; 10]; 5];
+///
+/// assert_eq!(glitch.apply(grid.clone()), glitch.apply(grid.clone()));
+/// assert_ne!(grid, glitch.apply(grid.clone()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Glitch {
+    /// The part of the filled cells that are glitched, between 0 and 1.
+    pub rate: f64,
+    /// The seed of the random glitches.
+    pub seed: u64,
+}
+
+impl Default for Glitch {
+    /// Glitches 5% of the cells.
+    fn default() -> Self {
+        Self {
+            rate: 0.05,
+            seed: 0,
+        }
+    }
+}
+
+impl Effect for Glitch {
+    fn apply(&self, mut grid: Grid) -> Grid {
+        let mut random = Random::new(self.seed);
+
+        for row in &mut grid {
+            for x in 0..row.len() {
+                if is_blank(row[x]) || !random.chance(self.rate) {
+                    continue;
+                }
+
+                match random.below(3) {
+                    0 if x > 0 => row.swap(x, x - 1),
+                    1 if x + 1 < row.len() => row.swap(x, x + 1),
+                    _ => row[x] = GLITCH_CHARS[random.below(GLITCH_CHARS.len() as u64) as usize],
+                }
+            }
+        }
+
+        grid
+    }
+}
+
+/// Applies `effects` in order to the rendered rows, padding them into a [Grid]
+/// first.
+pub(crate) fn apply(effects: &[std::sync::Arc<dyn Effect>], rows: Vec<String>) -> Vec<String> {
+    let mut grid: Grid = rows.iter().map(|row| canvas::row_cells(row)).collect();
+    let width = width(&grid);
+    for row in &mut grid {
+        row.resize(width, ' ');
+    }
+
+    effects
+        .iter()
+        .fold(grid, |grid, effect| effect.apply(grid))
+        .iter()
+        .map(|row| canvas::join_cells(row))
+        .collect()
+}
+
+/// Gets the amount of cells in the widest row of the grid.
+fn width(grid: &Grid) -> usize {
+    grid.iter().map(Vec::len).max().unwrap_or(0)
+}
+
+/// Checks if a cell is blank.
+fn is_blank(c: char) -> bool {
+    c.is_whitespace()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn apply_pads_rows() {
+        let effects: Vec<Arc<dyn Effect>> = vec![Arc::new(Mirror)];
+        let rows = apply(&effects, vec![String::from("ab"), String::new()]);
+        assert_eq!(vec![String::from("ba"), String::from("  ")], rows);
+    }
+
+    #[test]
+    fn effects_compose() {
+        let effects: Vec<Arc<dyn Effect>> = vec![Arc::new(Shadow), Arc::new(Flip)];
+        let rows = apply(&effects, vec![String::from("*")]);
+        assert_eq!(vec![String::from(" ."), String::from("* ")], rows);
+    }
+
+    #[test]
+    fn full_width_mirror() {
+        let effects: Vec<Arc<dyn Effect>> = vec![Arc::new(Mirror)];
+        let rows = apply(&effects, vec![String::from("漢*")]);
+        assert_eq!(vec![String::from("*漢")], rows);
+    }
+
+    #[test]
+    fn full_width_shadow() {
+        let effects: Vec<Arc<dyn Effect>> = vec![Arc::new(Shadow)];
+        let rows = apply(&effects, vec![String::from("漢*")]);
+        assert_eq!(vec![String::from("漢* "), String::from(" ...")], rows);
+    }
+
+    #[test]
+    fn flat_wave() {
+        let grid = vec![vec!['*', '*'], vec!['*', ' ']];
+        let wave = Wave {
+            amplitude: 0,
+            wavelength: 0,
+        };
+        assert_eq!(grid, wave.apply(grid.clone()));
+    }
+
+    #[test]
+    fn skew_keeps_width() {
+        let effects: Vec<Arc<dyn Effect>> = vec![Arc::new(Skew { shift: 2 })];
+        let rows = apply(&effects, vec![String::from("ab"), String::from("c")]);
+        assert_eq!(vec![String::from("  ab"), String::from("c   ")], rows);
+    }
+
+    #[test]
+    fn glitch_rates() {
+        let grid = vec![vec!['*', ' ', '*']; 4];
+        let none = Glitch { rate: 0.0, seed: 1 };
+        assert_eq!(grid, none.apply(grid.clone()));
+
+        // Blank cells are never glitched into symbols
+        let all = Glitch { rate: 1.0, seed: 1 }.apply(grid.clone());
+        let blanks = |grid: &Grid| grid.iter().flatten().filter(|c| is_blank(**c)).count();
+        assert_eq!(blanks(&grid), blanks(&all));
+    }
+
+    #[test]
+    fn full_width_scale() {
+        let effects: Vec<Arc<dyn Effect>> = vec![Arc::new(Scale { factor: 2 })];
+        let rows = apply(&effects, vec![String::from("漢*")]);
+        assert_eq!(vec![String::from("漢漢**"); 2], rows);
+    }
+
+    #[test]
+    fn scale_then_shade() {
+        // Every cell of a scaled dot gets 3 filled neighbours
+        let effects: Vec<Arc<dyn Effect>> = vec![Arc::new(Scale { factor: 2 }), Arc::new(Shade)];
+        let rows = apply(&effects, vec![String::from("*")]);
+        assert_eq!(vec![String::from("▒▒"); 2], rows);
+
+        let effects: Vec<Arc<dyn Effect>> = vec![Arc::new(Scale { factor: 3 }), Arc::new(Shade)];
+        let rows = apply(&effects, vec![String::from("*")]);
+        assert_eq!(
+            vec![
+                String::from("▒▓▒"),
+                String::from("▓█▓"),
+                String::from("▒▓▒")
+            ],
+            rows
+        );
+    }
+
+    #[test]
+    fn shade_then_scale() {
+        // Shading first only repeats the shade of the isolated dot
+        let effects: Vec<Arc<dyn Effect>> = vec![Arc::new(Shade), Arc::new(Scale { factor: 2 })];
+        let rows = apply(&effects, vec![String::from("*")]);
+        assert_eq!(vec![String::from("░░"); 2], rows);
+    }
+
+    #[test]
+    fn invert_twice() {
+        let grid = vec![vec!['*', ' '], vec![' ', '*']];
+        assert_eq!(grid, Invert.apply(Invert.apply(grid.clone())));
+    }
+}
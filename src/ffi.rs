@@ -0,0 +1,154 @@
+//! A module exposing a C API, so the renderer can be embedded in C/C++ tools and
+//! other languages.
+//!
+//! The functions require the `ffi` feature and a `cdylib` built with
+//! `cargo rustc --lib --features ffi --crate-type cdylib`, the matching header is
+//! `include/print_big_text.h`, which can be regenerated with
+//! `cbindgen --config cbindgen.toml --output include/print_big_text.h`.
+//!
+//! ```c
+//! #include "print_big_text.h"
+//!
+//! BigText *printer = pbt_new("HI");
+//! char *rendered = pbt_render(printer);
+//! fputs(rendered, stdout);
+//! pbt_string_free(rendered);
+//! pbt_free(printer);
+//! ```
+
+use crate::BigText;
+use std::{
+    ffi::{c_char, c_int, CStr, CString},
+    ptr,
+};
+
+/// Reads a C string, [None] if it is null or not valid UTF-8.
+///
+/// # Safety
+///
+/// `text` must be null or point to a nul-terminated string.
+unsafe fn read_str<'a>(text: *const c_char) -> Option<&'a str> {
+    if text.is_null() {
+        return None;
+    }
+    CStr::from_ptr(text).to_str().ok()
+}
+
+/// Creates a printer using the printables map, returning null if `text` isn't
+/// valid UTF-8. A null `text` creates a printer without text.
+///
+/// The printer must be freed with [pbt_free()].
+///
+/// # Safety
+///
+/// `text` must be null or point to a nul-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn pbt_new(text: *const c_char) -> *mut BigText {
+    let text = if text.is_null() {
+        ""
+    } else {
+        match read_str(text) {
+            Some(text) => text,
+            None => return ptr::null_mut(),
+        }
+    };
+
+    Box::into_raw(Box::new(BigText::from(text)))
+}
+
+/// Sets the text of a printer, returning `0` on success and `-1` if `printer` is
+/// null or `text` is null or isn't valid UTF-8.
+///
+/// # Safety
+///
+/// `printer` must be null or a printer created by [pbt_new()] that hasn't been
+/// freed, and `text` must be null or point to a nul-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn pbt_set_text(printer: *mut BigText, text: *const c_char) -> c_int {
+    match (printer.as_mut(), read_str(text)) {
+        (Some(printer), Some(text)) => {
+            printer.set_text(text);
+            0
+        }
+        _ => -1,
+    }
+}
+
+/// Renders the text of a printer into its ascii-art form, returning null if
+/// `printer` is null.
+///
+/// The string must be freed with [pbt_string_free()].
+///
+/// # Safety
+///
+/// `printer` must be null or a printer created by [pbt_new()] that hasn't been
+/// freed.
+#[no_mangle]
+pub unsafe extern "C" fn pbt_render(printer: *const BigText) -> *mut c_char {
+    match printer.as_ref() {
+        // The rendered text never contains nul bytes unless the font does
+        Some(printer) => {
+            CString::new(printer.to_string()).map_or(ptr::null_mut(), CString::into_raw)
+        }
+        None => ptr::null_mut(),
+    }
+}
+
+/// Frees a string returned by [pbt_render()], doing nothing if it is null.
+///
+/// # Safety
+///
+/// `string` must be null or a string returned by [pbt_render()] that hasn't been
+/// freed.
+#[no_mangle]
+pub unsafe extern "C" fn pbt_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+/// Frees a printer created by [pbt_new()], doing nothing if it is null.
+///
+/// # Safety
+///
+/// `printer` must be null or a printer created by [pbt_new()] that hasn't been
+/// freed.
+#[no_mangle]
+pub unsafe extern "C" fn pbt_free(printer: *mut BigText) {
+    if !printer.is_null() {
+        drop(Box::from_raw(printer));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render() {
+        unsafe {
+            let printer = pbt_new(c"HI".as_ptr());
+            assert_eq!(0, pbt_set_text(printer, c"42".as_ptr()));
+            assert_eq!(-1, pbt_set_text(printer, ptr::null()));
+
+            let rendered = pbt_render(printer);
+            let expected = BigText::new("42", None).to_string();
+            assert_eq!(
+                expected.as_str(),
+                CStr::from_ptr(rendered).to_str().unwrap()
+            );
+
+            pbt_string_free(rendered);
+            pbt_free(printer);
+        }
+    }
+
+    #[test]
+    fn null_printer() {
+        unsafe {
+            assert!(pbt_render(ptr::null()).is_null());
+            assert_eq!(-1, pbt_set_text(ptr::null_mut(), c"HI".as_ptr()));
+            pbt_free(ptr::null_mut());
+        }
+    }
+}
@@ -0,0 +1,987 @@
+//! A module for loading [CharacterMap]s from font files.
+//!
+//! The following formats are supported, fonts can be converted between them with
+//! [serialize()]:
+//!
+//! - JSON, the format of the built-in maps, an object mapping every character to an
+//!   array of 5 rows.
+//! - TOML, a table mapping every character to an array of 5 rows.
+//! - [FIGlet](http://www.figlet.org/) `.flf` fonts. Glyphs are limited to 5 rows, rows
+//!   that are blank in every glyph are removed and shorter fonts are padded.
+//! - A plain-text template, where every glyph is a line containing only the
+//!   character followed by its 5 rows, each wrapped in `|` to keep whitespace.
+//!
+//! Besides the built-in maps, fonts are looked up by name in the [font
+//! directories](font_dirs()).
+
+use crate::{
+    character_maps,
+    character_maps::{CharacterMap, Glyph},
+    display_width, GLYPH_HEIGHT,
+};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
+    env,
+    error::Error,
+    fmt::Display,
+    fs, io,
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+};
+
+/// The environment variable listing the directories fonts are searched in.
+pub const FONT_PATH_VAR: &str = "PRINT_BIG_TEXT_FONTS";
+/// The environment variable naming the default font, see [from_env()].
+pub const FONT_VAR: &str = "PRINT_BIG_TEXT_FONT";
+
+/// The errors that can occur when loading a font.
+#[derive(Debug)]
+pub enum FontError {
+    /// The font file couldn't be read.
+    Io(io::Error),
+    /// The font isn't a valid JSON character map.
+    Json(serde_json::Error),
+    /// The font isn't a valid TOML character map.
+    Toml(toml::de::Error),
+    /// The font isn't a valid FIGlet font.
+    Figlet(String),
+    /// The font isn't a valid plain-text template.
+    Template(String),
+    /// The glyphs of the font are taller than the 5 rows a [CharacterMap] supports.
+    UnsupportedHeight(usize),
+    /// There is no built-in font or font file with the given name.
+    UnknownFont(String),
+    /// The font has the [errors](Severity::Error) found by [validate_map()].
+    Invalid(Vec<Diagnostic>),
+}
+
+impl Display for FontError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "failed to read font: {error}"),
+            Self::Json(error) => write!(f, "invalid JSON font: {error}"),
+            Self::Toml(error) => write!(f, "invalid TOML font: {error}"),
+            Self::Figlet(reason) => write!(f, "invalid FIGlet font: {reason}"),
+            Self::Template(reason) => write!(f, "invalid template font: {reason}"),
+            Self::UnsupportedHeight(height) => write!(
+                f,
+                "font is {height} rows tall, only fonts up to {GLYPH_HEIGHT} rows are supported"
+            ),
+            Self::UnknownFont(name) => write!(f, "unknown font \"{name}\""),
+            Self::Invalid(diagnostics) => {
+                write!(f, "invalid font")?;
+                for (index, diagnostic) in diagnostics.iter().enumerate() {
+                    let separator = if index == 0 { ": " } else { "; " };
+                    write!(f, "{separator}{diagnostic}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Error for FontError {}
+
+/// The file formats fonts can be loaded from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FontFormat {
+    /// A JSON object mapping characters to 5 rows.
+    Json,
+    /// A TOML table mapping characters to 5 rows.
+    Toml,
+    /// A FIGlet `.flf` font.
+    Figlet,
+    /// A plain-text `.txt` template.
+    Template,
+}
+
+impl FontFormat {
+    /// Guesses the format of a font file from its extension.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::fonts::FontFormat;
+    ///
+    /// assert_eq!(Some(FontFormat::Figlet), FontFormat::from_path("standard.flf"));
+    /// assert_eq!(Some(FontFormat::Json), FontFormat::from_path("font.json"));
+    /// assert_eq!(None, FontFormat::from_path("font.ttf"));
+    /// ```
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Option<Self> {
+        match path.as_ref().extension()?.to_str()? {
+            "json" => Some(Self::Json),
+            "toml" => Some(Self::Toml),
+            "flf" => Some(Self::Figlet),
+            "txt" => Some(Self::Template),
+            _ => None,
+        }
+    }
+}
+
+/// Parses a [CharacterMap] from data in the given format.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::fonts::{self, FontFormat};
+///
+/// let map = fonts::parse(r#"{"-": ["", "", "---", "", ""]}"#, FontFormat::Json).unwrap();
+/// assert_eq!("---", map[&'-'][2]);
+/// ```
+pub fn parse(data: &str, format: FontFormat) -> Result<CharacterMap, FontError> {
+    match format {
+        FontFormat::Json => serde_json::from_str(data).map_err(FontError::Json),
+        FontFormat::Toml => toml::from_str(data).map_err(FontError::Toml),
+        FontFormat::Figlet => from_figlet(data),
+        FontFormat::Template => from_template(data),
+    }
+}
+
+/// Serializes a [CharacterMap] into the given format, with the characters sorted.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{character_maps, fonts::{self, FontFormat}};
+///
+/// let map = character_maps::digits();
+/// let template = fonts::serialize(&map, FontFormat::Template);
+/// assert!(template.starts_with("0\n|*****|\n|*   *|\n"));
+/// assert_eq!(map, fonts::parse(&template, FontFormat::Template).unwrap());
+/// ```
+pub fn serialize(map: &CharacterMap, format: FontFormat) -> String {
+    let sorted: BTreeMap<&char, &Glyph> = map.iter().collect();
+
+    // Maps of strings can always be serialized
+    match format {
+        FontFormat::Json => serde_json::to_string_pretty(&sorted).unwrap() + "\n",
+        FontFormat::Toml => toml::to_string(&sorted).unwrap(),
+        FontFormat::Figlet => to_figlet(&sorted),
+        FontFormat::Template => to_template(&sorted),
+    }
+}
+
+/// Loads a [CharacterMap] from a font file.
+///
+/// The format is guessed from the extension, files without a known extension are
+/// treated as FIGlet fonts if they start with the FIGlet signature and as JSON
+/// otherwise.
+pub fn load<P: AsRef<Path>>(path: P) -> Result<CharacterMap, FontError> {
+    let path = path.as_ref();
+    let data = fs::read_to_string(path).map_err(FontError::Io)?;
+    parse(&data, detect_format(path, &data))
+}
+
+/// Gets the format of a font file from its extension or its contents.
+fn detect_format(path: &Path, data: &str) -> FontFormat {
+    FontFormat::from_path(path).unwrap_or(if data.starts_with("flf2a") {
+        FontFormat::Figlet
+    } else {
+        FontFormat::Json
+    })
+}
+
+/// How serious a [Diagnostic] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Severity {
+    /// The font can be used, but might not look as intended.
+    Warning,
+    /// The font can't be used or renders incorrectly.
+    Error,
+}
+
+/// A problem found when [validating](validate()) a font.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// How serious the problem is.
+    pub severity: Severity,
+    /// The character with the problem, [None] if it concerns the whole font.
+    pub character: Option<char>,
+    /// A human-readable description of the problem.
+    pub message: String,
+}
+
+impl Diagnostic {
+    /// Creates a diagnostic.
+    fn new(severity: Severity, character: Option<char>, message: String) -> Self {
+        Self {
+            severity,
+            character,
+            message,
+        }
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.severity {
+            Severity::Warning => write!(f, "warning: ")?,
+            Severity::Error => write!(f, "error: ")?,
+        }
+        if let Some(c) = self.character {
+            write!(f, "{c:?}: ")?;
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+/// Validates font data in the given format, returning the problems found sorted by
+/// character.
+///
+/// Glyphs without exactly 5 rows, glyphs with rows of different widths and data
+/// that can't be parsed are errors. Empty glyphs of visible characters and missing
+/// printable ASCII characters are warnings.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::fonts::{self, FontFormat, Severity};
+///
+/// let font = r#"{"-": ["", "", "---", "", ""], "|": ["|", "|", "|"]}"#;
+/// let diagnostics = fonts::validate(font, FontFormat::Json);
+///
+/// assert_eq!(Severity::Error, diagnostics[1].severity);
+/// assert_eq!(Some('-'), diagnostics[1].character);
+/// assert_eq!("error: '|': has 3 rows, expected 5", diagnostics[2].to_string());
+/// ```
+pub fn validate(data: &str, format: FontFormat) -> Vec<Diagnostic> {
+    let glyphs: Result<Vec<(char, Vec<String>)>, FontError> = match format {
+        FontFormat::Json => serde_json::from_str::<HashMap<char, Vec<String>>>(data)
+            .map(|glyphs| glyphs.into_iter().collect())
+            .map_err(FontError::Json),
+        FontFormat::Toml => toml::from_str::<HashMap<char, Vec<String>>>(data)
+            .map(|glyphs| glyphs.into_iter().collect())
+            .map_err(FontError::Toml),
+        FontFormat::Figlet => from_figlet(data).map(|map| {
+            map.into_iter()
+                .map(|(c, glyph)| (c, glyph.map(String::from).into()))
+                .collect()
+        }),
+        FontFormat::Template => template_glyphs(data),
+    };
+    let glyphs = match glyphs {
+        Ok(glyphs) => glyphs,
+        Err(error) => return vec![Diagnostic::new(Severity::Error, None, error.to_string())],
+    };
+
+    check_glyphs(&glyphs)
+}
+
+/// Validates a [CharacterMap] like [validate()], every glyph having 5 rows.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{character_maps, fonts::{self, Severity}};
+///
+/// let mut map = character_maps::printables();
+/// map.get_mut(&'A').unwrap()[0] = "*".into();
+///
+/// let diagnostics = fonts::validate_map(&map);
+/// assert_eq!(Severity::Warning, diagnostics[0].severity);
+/// assert_eq!(Severity::Error, diagnostics[1].severity);
+/// assert_eq!(Some('A'), diagnostics[1].character);
+/// ```
+pub fn validate_map(map: &CharacterMap) -> Vec<Diagnostic> {
+    let glyphs: Vec<(char, &[Cow<str>])> = map.iter().map(|(c, glyph)| (*c, &glyph[..])).collect();
+    check_glyphs(&glyphs)
+}
+
+/// Checks the glyphs of a font, see [validate()].
+fn check_glyphs<R: AsRef<[S]>, S: AsRef<str>>(glyphs: &[(char, R)]) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    let missing: String = (' '..='~')
+        .filter(|c| !glyphs.iter().any(|(glyph, _)| glyph == c))
+        .collect();
+    if !missing.is_empty() {
+        let message = format!(
+            "missing {} printable ASCII characters: {missing}",
+            missing.chars().count()
+        );
+        diagnostics.push(Diagnostic::new(Severity::Warning, None, message));
+    }
+
+    let mut problems = Vec::new();
+    for (c, rows) in glyphs {
+        let rows = rows.as_ref();
+        if rows.len() != GLYPH_HEIGHT {
+            let message = format!("has {} rows, expected {GLYPH_HEIGHT}", rows.len());
+            problems.push(Diagnostic::new(Severity::Error, Some(*c), message));
+        }
+
+        let widths: Vec<usize> = rows.iter().map(|row| display_width(row.as_ref())).collect();
+        if widths.windows(2).any(|pair| pair[0] != pair[1]) {
+            let message = format!("rows have different widths {widths:?}");
+            problems.push(Diagnostic::new(Severity::Error, Some(*c), message));
+        }
+
+        if !c.is_whitespace() && rows.iter().all(|row| row.as_ref().trim().is_empty()) {
+            let message = String::from("glyph is empty");
+            problems.push(Diagnostic::new(Severity::Warning, Some(*c), message));
+        }
+    }
+    problems.sort_by_key(|diagnostic| diagnostic.character);
+    diagnostics.extend(problems);
+
+    diagnostics
+}
+
+/// Validates a font file, see [validate()].
+pub fn validate_file<P: AsRef<Path>>(path: P) -> Result<Vec<Diagnostic>, FontError> {
+    let path = path.as_ref();
+    let data = fs::read_to_string(path).map_err(FontError::Io)?;
+    Ok(validate(&data, detect_format(path, &data)))
+}
+
+/// Resolves a font from a built-in map name, the name of a font in the
+/// [font directories](font_dirs()) or a path to a font file.
+///
+/// Built-in names (see [character_maps::from_name()]) take precedence over the font
+/// directories, which take precedence over paths.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{character_maps, fonts};
+///
+/// assert_eq!(character_maps::digits(), fonts::resolve("digits").unwrap());
+/// assert!(fonts::resolve("does-not-exist.flf").is_err());
+/// ```
+pub fn resolve(name: &str) -> Result<CharacterMap, FontError> {
+    resolve_in(name, &font_dirs())
+}
+
+/// Resolves a font like [resolve()], searching the given directories in order
+/// instead of the default font directories.
+pub fn resolve_in<P: AsRef<Path>>(name: &str, dirs: &[P]) -> Result<CharacterMap, FontError> {
+    if let Some(map) = character_maps::from_name(name) {
+        return Ok(map);
+    }
+    if let Some(path) = font_files(dirs).find(|path| path.file_stem() == Some(name.as_ref())) {
+        return load(path);
+    }
+
+    let path = Path::new(name);
+    if path.is_file() {
+        load(path)
+    } else {
+        Err(FontError::UnknownFont(String::from(name)))
+    }
+}
+
+/// Resolves the font named by the [`PRINT_BIG_TEXT_FONT`](FONT_VAR) environment
+/// variable, which can be any name or path accepted by [resolve()].
+///
+/// [None] is returned if the variable isn't set or is empty.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::fonts;
+///
+/// std::env::set_var("PRINT_BIG_TEXT_FONT", "digits");
+/// assert_eq!(10, fonts::from_env().unwrap().unwrap().len());
+/// ```
+pub fn from_env() -> Result<Option<CharacterMap>, FontError> {
+    match env::var(FONT_VAR) {
+        Ok(name) if !name.is_empty() => resolve(&name).map(Some),
+        _ => Ok(None),
+    }
+}
+
+/// Gets the directories fonts are searched in, in order:
+///
+/// 1. The directories in the [`PRINT_BIG_TEXT_FONTS`](FONT_PATH_VAR) environment
+///    variable, a list of paths in the platform's `PATH` format.
+/// 2. `print-big-text/fonts` in `$XDG_DATA_HOME`, or `~/.local/share` if it isn't
+///    set.
+/// 3. `/usr/share/figlet`, where FIGlet fonts are usually installed.
+///
+/// Directories that don't exist are included, they are skipped when searching.
+pub fn font_dirs() -> Vec<PathBuf> {
+    let variable = |name| env::var_os(name).filter(|value| !value.is_empty());
+
+    let mut dirs: Vec<PathBuf> = variable(FONT_PATH_VAR)
+        .map(|paths| env::split_paths(&paths).collect())
+        .unwrap_or_default();
+
+    let data = variable("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .or_else(|| variable("HOME").map(|home| Path::new(&home).join(".local/share")));
+    if let Some(data) = data {
+        dirs.push(data.join("print-big-text").join("fonts"));
+    }
+    dirs.push(PathBuf::from("/usr/share/figlet"));
+
+    dirs
+}
+
+/// Gets every font file with a known extension in the directories, sorted by path
+/// within each directory.
+fn font_files<P: AsRef<Path>>(dirs: &[P]) -> impl Iterator<Item = PathBuf> + '_ {
+    dirs.iter().flat_map(|dir| {
+        let mut files: Vec<PathBuf> = fs::read_dir(dir)
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.is_file() && FontFormat::from_path(path).is_some())
+            .collect();
+        files.sort();
+        files
+    })
+}
+
+/// Where an available font comes from.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FontSource {
+    /// The font is built into the crate.
+    BuiltIn,
+    /// The font was found in a font directory.
+    File(PathBuf),
+}
+
+impl Display for FontSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::BuiltIn => write!(f, "built-in"),
+            Self::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Information about a font that can be [resolved](resolve()) by name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FontInfo {
+    /// The name used to resolve the font.
+    name: String,
+    /// Where the font comes from.
+    source: FontSource,
+    /// The amount of characters the font supports.
+    character_count: usize,
+}
+
+impl FontInfo {
+    /// Gets the name used to resolve the font.
+    pub fn name(&self) -> &str {
+        self.name.as_ref()
+    }
+
+    /// Gets where the font comes from.
+    pub fn source(&self) -> &FontSource {
+        &self.source
+    }
+
+    /// Gets the amount of characters the font supports.
+    pub fn character_count(&self) -> usize {
+        self.character_count
+    }
+}
+
+/// Lists every font that can be resolved by name, the built-in maps first followed
+/// by the fonts in the [font directories](font_dirs()).
+///
+/// Font files that fail to load, like FIGlet fonts taller than 5 rows, are left out.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::fonts;
+///
+/// let fonts = fonts::list();
+/// assert_eq!("printables", fonts[0].name());
+/// assert!(fonts.iter().any(|font| font.name() == "digits" && font.character_count() == 10));
+/// ```
+pub fn list() -> Vec<FontInfo> {
+    list_in(&font_dirs())
+}
+
+/// Lists fonts like [list()], searching the given directories instead of the default
+/// font directories.
+pub fn list_in<P: AsRef<Path>>(dirs: &[P]) -> Vec<FontInfo> {
+    let printables = FontInfo {
+        name: String::from("printables"),
+        source: FontSource::BuiltIn,
+        character_count: character_maps::printables().len(),
+    };
+    #[cfg(feature = "extended")]
+    let printables = std::iter::once(printables).chain(std::iter::once(FontInfo {
+        name: String::from("extended_printables"),
+        source: FontSource::BuiltIn,
+        character_count: character_maps::extended_printables().len(),
+    }));
+    #[cfg(not(feature = "extended"))]
+    let printables = std::iter::once(printables);
+    let built_in = character_maps::sources().iter().map(|source| FontInfo {
+        name: String::from(source.name()),
+        source: FontSource::BuiltIn,
+        character_count: source.character_count(),
+    });
+    let files = font_files(dirs).filter_map(|path| {
+        let map = load(&path).ok()?;
+        Some(FontInfo {
+            name: path.file_stem()?.to_string_lossy().into_owned(),
+            character_count: map.len(),
+            source: FontSource::File(path),
+        })
+    });
+
+    printables.chain(built_in).chain(files).collect()
+}
+
+/// The Unicode blocks reported by [coverage()], without their control characters.
+const BLOCKS: [(&str, RangeInclusive<char>); 12] = [
+    ("Basic Latin", ' '..='~'),
+    ("Latin-1 Supplement", '\u{A0}'..='\u{FF}'),
+    ("Latin Extended-A", '\u{100}'..='\u{17F}'),
+    ("Latin Extended-B", '\u{180}'..='\u{24F}'),
+    ("Greek and Coptic", '\u{370}'..='\u{3FF}'),
+    ("Cyrillic", '\u{400}'..='\u{4FF}'),
+    ("Arabic", '\u{600}'..='\u{6FF}'),
+    ("General Punctuation", '\u{2000}'..='\u{206F}'),
+    ("Arrows", '\u{2190}'..='\u{21FF}'),
+    ("Box Drawing", '\u{2500}'..='\u{257F}'),
+    ("Hiragana", '\u{3040}'..='\u{309F}'),
+    ("Katakana", '\u{30A0}'..='\u{30FF}'),
+];
+
+/// How many characters of a Unicode block a font covers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockCoverage {
+    /// The name of the block, `Other` for the characters outside the known blocks.
+    name: &'static str,
+    /// The amount of characters of the block the font supports.
+    covered: usize,
+    /// The amount of characters in the block.
+    total: usize,
+}
+
+impl BlockCoverage {
+    /// Gets the name of the block, `Other` for the characters outside the known
+    /// blocks.
+    pub fn name(&self) -> &str {
+        self.name
+    }
+
+    /// Gets the amount of characters of the block the font supports.
+    pub fn covered(&self) -> usize {
+        self.covered
+    }
+
+    /// Gets the amount of characters in the block, the same as the covered amount
+    /// for `Other`.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+}
+
+/// Counts the characters of every Unicode block that `map` supports, leaving out the
+/// blocks it doesn't support at all.
+///
+/// Characters outside the blocks the built-in maps are made for are counted in a
+/// last `Other` block.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{character_maps, fonts};
+///
+/// let coverage = fonts::coverage(&character_maps::digits());
+/// assert_eq!(1, coverage.len());
+/// assert_eq!("Basic Latin", coverage[0].name());
+/// assert_eq!((10, 95), (coverage[0].covered(), coverage[0].total()));
+/// ```
+pub fn coverage(map: &CharacterMap) -> Vec<BlockCoverage> {
+    let mut coverage: Vec<BlockCoverage> = BLOCKS
+        .iter()
+        .map(|(name, range)| BlockCoverage {
+            name,
+            covered: range.clone().filter(|c| map.contains_key(c)).count(),
+            total: range.clone().count(),
+        })
+        .filter(|block| block.covered > 0)
+        .collect();
+
+    let other = map
+        .keys()
+        .filter(|c| !BLOCKS.iter().any(|(_, range)| range.contains(c)))
+        .count();
+    if other > 0 {
+        coverage.push(BlockCoverage {
+            name: "Other",
+            covered: other,
+            total: other,
+        });
+    }
+
+    coverage
+}
+
+/// The characters every FIGlet font defines in order after the header.
+fn figlet_required_chars() -> impl Iterator<Item = char> {
+    (' '..='~').chain(['Ä', 'Ö', 'Ü', 'ä', 'ö', 'ü', 'ß'])
+}
+
+/// Parses a FIGlet font.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::fonts;
+///
+/// let font = "flf2a$ 2 2 4 -1 1\ncomment\n$$@\n$$@@\n";
+/// let map = fonts::from_figlet(font).unwrap();
+/// assert_eq!("  ", map[&' '][0]);
+/// ```
+pub fn from_figlet(data: &str) -> Result<CharacterMap, FontError> {
+    let mut lines = data.lines();
+    let header = lines
+        .next()
+        .ok_or_else(|| FontError::Figlet(String::from("missing header")))?;
+
+    let signature = header
+        .strip_prefix("flf2a")
+        .ok_or_else(|| FontError::Figlet(String::from("missing flf2a signature")))?;
+    let hardblank = signature
+        .chars()
+        .next()
+        .ok_or_else(|| FontError::Figlet(String::from("missing hardblank")))?;
+    let parameters: Vec<&str> = signature[hardblank.len_utf8()..]
+        .split_whitespace()
+        .collect();
+    let number = |index: usize, name: &str| -> Result<usize, FontError> {
+        parameters
+            .get(index)
+            .and_then(|value| value.parse().ok())
+            .ok_or_else(|| FontError::Figlet(format!("invalid {name} in header")))
+    };
+    let height = number(0, "height")?;
+    let comment_lines = number(4, "comment line count")?;
+
+    let mut lines = lines.skip(comment_lines);
+    let read_glyph = |lines: &mut dyn Iterator<Item = &str>| -> Option<Vec<String>> {
+        let mut glyph = Vec::with_capacity(height);
+        for _ in 0..height {
+            let line = lines.next()?;
+            let endmark = line.chars().last();
+            let row = match endmark {
+                Some(endmark) => line.trim_end_matches(endmark),
+                None => line,
+            };
+            glyph.push(row.replace(hardblank, " "));
+        }
+        Some(glyph)
+    };
+
+    let mut glyphs: Vec<(char, Vec<String>)> = Vec::new();
+    for c in figlet_required_chars() {
+        match read_glyph(&mut lines) {
+            Some(glyph) => glyphs.push((c, glyph)),
+            None if glyphs.is_empty() => {
+                return Err(FontError::Figlet(String::from("missing glyphs")))
+            }
+            None => break,
+        }
+    }
+
+    // Code tagged characters
+    while let Some(tag) = lines.next() {
+        let code = tag.split_whitespace().next().unwrap_or_default();
+        let glyph = match read_glyph(&mut lines) {
+            Some(glyph) => glyph,
+            None => break,
+        };
+        if let Some(c) = parse_code(code).and_then(char::from_u32) {
+            glyphs.push((c, glyph));
+        }
+    }
+
+    // Glyphs without any columns are missing characters
+    glyphs.retain(|(_, glyph)| glyph.iter().any(|row| !row.is_empty()));
+
+    let rows = trim_blank_rows(&glyphs, height);
+    if rows.len() > GLYPH_HEIGHT {
+        return Err(FontError::UnsupportedHeight(rows.len()));
+    }
+
+    Ok(glyphs
+        .into_iter()
+        .map(|(c, glyph)| {
+            let width = glyph.iter().map(|row| display_width(row)).max();
+            let width = width.unwrap_or(0);
+            let blank = " ".repeat(width);
+            let mut padded: [String; GLYPH_HEIGHT] = Default::default();
+
+            for (target, row) in padded.iter_mut().zip(rows.clone()) {
+                let row = &glyph[row];
+                *target = format!("{row}{}", " ".repeat(width - display_width(row)));
+            }
+            for target in padded.iter_mut().skip(rows.len()) {
+                target.clone_from(&blank);
+            }
+
+            (c, padded.map(Cow::Owned))
+        })
+        .collect())
+}
+
+/// Writes a FIGlet font with the required characters first, followed by the other
+/// characters as code tagged characters.
+fn to_figlet(map: &BTreeMap<&char, &Glyph>) -> String {
+    let rows = || map.values().flat_map(|glyph| glyph.iter());
+    let hardblank = ['$', '^', '~', '\u{7f}']
+        .into_iter()
+        .find(|hardblank| !rows().any(|row| row.contains(*hardblank)))
+        .unwrap_or('\u{7f}');
+    let max_length = rows().map(|row| row.chars().count()).max().unwrap_or(0) + 2;
+
+    let mut font = format!(
+        "flf2a{hardblank} {GLYPH_HEIGHT} {GLYPH_HEIGHT} {max_length} -1 1\n\
+         Converted by print-big-text-rs\n"
+    );
+    let write_glyph = |font: &mut String, glyph: Option<&&Glyph>| {
+        for (index, row) in glyph.into_iter().flat_map(|glyph| glyph.iter()).enumerate() {
+            // The endmark can't be the last character of the row
+            let endmark = if row.ends_with('@') { '#' } else { '@' };
+            font.push_str(row);
+            font.push(endmark);
+            if index == GLYPH_HEIGHT - 1 {
+                font.push(endmark);
+            }
+            font.push('\n');
+        }
+        if glyph.is_none() {
+            font.push_str(&"@\n".repeat(GLYPH_HEIGHT - 1));
+            font.push_str("@@\n");
+        }
+    };
+
+    for c in figlet_required_chars() {
+        write_glyph(&mut font, map.get(&c));
+    }
+    for (c, glyph) in map {
+        if !figlet_required_chars().any(|required| required == **c) {
+            font.push_str(&format!("{}\n", **c as u32));
+            write_glyph(&mut font, Some(glyph));
+        }
+    }
+
+    font
+}
+
+/// Parses a plain-text template font.
+///
+/// Every glyph is a line containing only the character, followed by its 5 rows each
+/// wrapped in `|`. Empty lines between glyphs are ignored.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::fonts;
+///
+/// let map = fonts::from_template("-\n|   |\n|   |\n|---|\n|   |\n|   |\n").unwrap();
+/// assert_eq!("---", map[&'-'][2]);
+/// ```
+pub fn from_template(data: &str) -> Result<CharacterMap, FontError> {
+    template_glyphs(data)?
+        .into_iter()
+        .map(|(c, rows)| {
+            let glyph = <[String; GLYPH_HEIGHT]>::try_from(rows).map_err(|rows| {
+                FontError::Template(format!(
+                    "{c:?} has {} rows, expected {GLYPH_HEIGHT}",
+                    rows.len()
+                ))
+            })?;
+            Ok((c, glyph.map(Cow::Owned)))
+        })
+        .collect()
+}
+
+/// Parses the glyphs of a plain-text template font, with any amount of rows.
+fn template_glyphs(data: &str) -> Result<Vec<(char, Vec<String>)>, FontError> {
+    let mut glyphs: Vec<(char, Vec<String>)> = Vec::new();
+
+    for (number, line) in data.lines().enumerate() {
+        let row = line
+            .strip_prefix('|')
+            .and_then(|line| line.strip_suffix('|'));
+        let mut chars = line.chars();
+
+        match (row, chars.next(), chars.next(), glyphs.last_mut()) {
+            (Some(row), _, _, Some((_, rows))) => rows.push(String::from(row)),
+            (_, Some(c), None, _) => glyphs.push((c, Vec::new())),
+            (_, None, _, _) => (),
+            _ => {
+                return Err(FontError::Template(format!(
+                    "line {}: expected a character or a row wrapped in \"|\"",
+                    number + 1
+                )))
+            }
+        }
+    }
+
+    Ok(glyphs)
+}
+
+/// Writes a plain-text template font.
+fn to_template(map: &BTreeMap<&char, &Glyph>) -> String {
+    let mut template = String::new();
+
+    for (c, glyph) in map {
+        if !template.is_empty() {
+            template.push('\n');
+        }
+        template.push(**c);
+        template.push('\n');
+        for row in glyph.iter() {
+            template.push_str(&format!("|{row}|\n"));
+        }
+    }
+
+    template
+}
+
+/// Gets the range of rows left after removing the rows at the top and bottom that
+/// are blank in every glyph.
+fn trim_blank_rows(glyphs: &[(char, Vec<String>)], height: usize) -> std::ops::Range<usize> {
+    let is_blank = |row: usize| glyphs.iter().all(|(_, glyph)| glyph[row].trim().is_empty());
+
+    let start = (0..height).find(|row| !is_blank(*row)).unwrap_or(0);
+    let end = (start..height)
+        .rev()
+        .find(|row| !is_blank(*row))
+        .map_or(start, |row| row + 1);
+    start..end
+}
+
+/// Parses a FIGlet character code in decimal, hexadecimal (`0x`) or octal (`0`).
+fn parse_code(code: &str) -> Option<u32> {
+    if let Some(hex) = code.strip_prefix("0x").or_else(|| code.strip_prefix("0X")) {
+        u32::from_str_radix(hex, 16).ok()
+    } else if code.len() > 1 && code.starts_with('0') {
+        u32::from_str_radix(&code[1..], 8).ok()
+    } else {
+        code.parse().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn full_width_glyphs_are_valid() {
+        let glyph: Glyph = ["漢", "**", "漢", "**", "漢"].map(Cow::Borrowed);
+        let map = CharacterMap::from([('漢', glyph)]);
+        assert!(validate_map(&map)
+            .iter()
+            .all(|diagnostic| diagnostic.severity != Severity::Error));
+
+        let ragged: Glyph = ["漢", "*", "漢", "*", "漢"].map(Cow::Borrowed);
+        let map = CharacterMap::from([('漢', ragged)]);
+        assert!(validate_map(&map)
+            .iter()
+            .any(|diagnostic| diagnostic.severity == Severity::Error));
+    }
+
+    #[test]
+    fn test_coverage_other() {
+        let glyph: Glyph = [0; GLYPH_HEIGHT].map(|_| Cow::Borrowed("*"));
+        let map = CharacterMap::from([('A', glyph.clone()), ('€', glyph)]);
+
+        let coverage = coverage(&map);
+        assert_eq!(2, coverage.len());
+        assert_eq!("Other", coverage[1].name());
+        assert_eq!((1, 1), (coverage[1].covered(), coverage[1].total()));
+    }
+
+    #[test]
+    fn test_parse_code() {
+        assert_eq!(Some(196), parse_code("196"));
+        assert_eq!(Some(0x263A), parse_code("0x263A"));
+        assert_eq!(Some(8), parse_code("010"));
+        assert_eq!(None, parse_code("-1"));
+    }
+
+    #[test]
+    fn test_from_figlet() {
+        let mut font = String::from("flf2a$ 7 6 10 0 1\nTest font\n");
+        for c in figlet_required_chars() {
+            for row in 0..7 {
+                let end = if row == 6 { "@@" } else { "@" };
+                // Only rows 1..=3 have content, the rest are blank in every glyph
+                let line = if (1..=3).contains(&row) && c != ' ' {
+                    format!("{c}$$")
+                } else {
+                    String::from("$$$")
+                };
+                font.push_str(&format!("{line}{end}\n"));
+            }
+        }
+        font.push_str("0x263A smiley\n@\n:)@\n:)@\n:)@\n@\n@\n@@\n");
+
+        let map = from_figlet(&font).unwrap();
+        assert_eq!(["A  ", "A  ", "A  ", "   ", "   "], map[&'A']);
+        assert_eq!(["   "; 5], map[&' ']);
+        assert_eq!([":)", ":)", ":)", "  ", "  "], map[&'☺']);
+        assert_eq!(103, map.len());
+    }
+
+    #[test]
+    fn test_validate_built_in() {
+        let data = serde_json::to_string(&character_maps::printables()).unwrap();
+        let diagnostics = validate(&data, FontFormat::Json);
+
+        // Only the missing lowercase letters and some punctuation are reported
+        assert_eq!(1, diagnostics.len());
+        assert_eq!(Severity::Warning, diagnostics[0].severity);
+        assert_eq!(None, diagnostics[0].character);
+    }
+
+    #[test]
+    fn test_validate_invalid() {
+        let diagnostics = validate("flf2a", FontFormat::Figlet);
+        assert_eq!(1, diagnostics.len());
+        assert_eq!(Severity::Error, diagnostics[0].severity);
+        assert_eq!(None, diagnostics[0].character);
+    }
+
+    #[test]
+    fn test_validate_map() {
+        let map = character_maps::printables();
+        let data = serde_json::to_string(&map).unwrap();
+        assert_eq!(validate(&data, FontFormat::Json), validate_map(&map));
+
+        let error = FontError::Invalid(vec![Diagnostic::new(
+            Severity::Error,
+            Some('A'),
+            String::from("glyph is broken"),
+        )]);
+        assert_eq!(
+            "invalid font: error: 'A': glyph is broken",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let map = character_maps::printables();
+
+        for format in [
+            FontFormat::Json,
+            FontFormat::Toml,
+            FontFormat::Figlet,
+            FontFormat::Template,
+        ] {
+            let data = serialize(&map, format);
+            assert_eq!(map, parse(&data, format).unwrap(), "{format:?}");
+        }
+    }
+
+    #[test]
+    fn test_template_pipe() {
+        let map = from_template("|\n|  |  |\n|  |  |\n|  |  |\n|  |  |\n|  |  |\n").unwrap();
+        assert_eq!("  |  ", map[&'|'][0]);
+    }
+
+    #[test]
+    fn test_too_tall() {
+        let mut font = String::from("flf2a$ 6 6 10 0 0\n");
+        for _ in figlet_required_chars() {
+            font.push_str("#@\n#@\n#@\n#@\n#@\n#@@\n");
+        }
+        assert!(matches!(
+            from_figlet(&font),
+            Err(FontError::UnsupportedHeight(6))
+        ));
+    }
+}
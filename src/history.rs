@@ -0,0 +1,260 @@
+//! A module containing a scrollback ring of rendered banners.
+//!
+//! Streaming front-ends (such as a follow mode that renders every incoming line)
+//! keep the last few banners in a [BannerHistory] so older messages can be reviewed
+//! without losing the live view.
+
+use std::collections::VecDeque;
+
+/// A ring of the last `capacity` rendered banners with a navigation cursor.
+///
+/// The history is "live" while it shows the newest banner. Moving to an older
+/// banner with [BannerHistory::previous()] stops following new banners until
+/// [BannerHistory::next()] reaches the newest one again or
+/// [BannerHistory::go_live()] is called.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{history::BannerHistory, BigText};
+///
+/// let mut history = BannerHistory::new(10);
+/// history.push(BigText::new("1", None).to_string());
+/// history.push(BigText::new("2", None).to_string());
+///
+/// // Arrow up
+/// history.previous();
+/// assert_eq!(Some(BigText::new("1", None).to_string().as_str()), history.current());
+///
+/// // New banners don't move the view while reviewing
+/// history.push(BigText::new("3", None).to_string());
+/// assert!(!history.is_live());
+///
+/// history.go_live();
+/// assert_eq!(Some(BigText::new("3", None).to_string().as_str()), history.current());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BannerHistory {
+    /// The stored banners from oldest to newest.
+    banners: VecDeque<String>,
+    /// The largest amount of banners stored.
+    capacity: usize,
+    /// The index of the banner being viewed, [None] when following the newest.
+    cursor: Option<usize>,
+}
+
+impl BannerHistory {
+    /// Constructor Function for [BannerHistory].
+    ///
+    /// A `capacity` of `0` is treated as `1`, the newest banner is always kept.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::history::BannerHistory;
+    ///
+    /// let history = BannerHistory::new(5);
+    /// assert_eq!(5, history.capacity());
+    /// assert!(history.is_empty());
+    /// ```
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+
+        Self {
+            banners: VecDeque::with_capacity(capacity),
+            capacity,
+            cursor: None,
+        }
+    }
+
+    /// Adds a new banner, dropping the oldest one when the history is full.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::history::BannerHistory;
+    ///
+    /// let mut history = BannerHistory::new(2);
+    /// history.push(String::from("a"));
+    /// history.push(String::from("b"));
+    /// history.push(String::from("c"));
+    ///
+    /// assert_eq!(vec!["b", "c"], history.iter().collect::<Vec<&str>>());
+    /// ```
+    ///
+    /// While the oldest banner is being viewed, the banner after it is dropped
+    /// instead, so the view doesn't change under the reader.
+    pub fn push(&mut self, banner: String) {
+        if self.banners.len() == self.capacity {
+            match self.cursor {
+                Some(0) if self.banners.len() > 1 => {
+                    self.banners.remove(1);
+                }
+                // The only banner is replaced, so there is nothing left to review
+                Some(0) => {
+                    self.banners.pop_front();
+                    self.cursor = None;
+                }
+                cursor => {
+                    self.banners.pop_front();
+                    // Keeping the cursor on the same banner
+                    self.cursor = cursor.map(|cursor| cursor - 1);
+                }
+            }
+        }
+        self.banners.push_back(banner);
+    }
+
+    /// Gets the banner currently being viewed.
+    pub fn current(&self) -> Option<&str> {
+        match self.cursor {
+            Some(cursor) => self.banners.get(cursor),
+            None => self.banners.back(),
+        }
+        .map(String::as_str)
+    }
+
+    /// Moves the view to the banner before the current one and returns it.
+    ///
+    /// The view stays on the oldest banner once it is reached.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::history::BannerHistory;
+    ///
+    /// let mut history = BannerHistory::new(3);
+    /// history.push(String::from("a"));
+    /// history.push(String::from("b"));
+    ///
+    /// assert_eq!(Some("a"), history.previous());
+    /// assert_eq!(Some("a"), history.previous());
+    /// ```
+    pub fn previous(&mut self) -> Option<&str> {
+        // Without an older banner the view stays where it is
+        if self.banners.len() < 2 {
+            return self.current();
+        }
+
+        let newest = self.banners.len() - 1;
+        self.cursor = Some(self.cursor.unwrap_or(newest).saturating_sub(1));
+        self.current()
+    }
+
+    /// Moves the view to the banner after the current one and returns it.
+    ///
+    /// Reaching the newest banner makes the history live again.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::history::BannerHistory;
+    ///
+    /// let mut history = BannerHistory::new(3);
+    /// history.push(String::from("a"));
+    /// history.push(String::from("b"));
+    /// history.previous();
+    ///
+    /// assert_eq!(Some("b"), history.next());
+    /// assert!(history.is_live());
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<&str> {
+        if let Some(cursor) = self.cursor {
+            self.cursor = if cursor + 1 >= self.banners.len() - 1 {
+                None
+            } else {
+                Some(cursor + 1)
+            };
+        }
+        self.current()
+    }
+
+    /// Makes the view follow the newest banner again.
+    pub fn go_live(&mut self) {
+        self.cursor = None;
+    }
+
+    /// Checks if the view is following the newest banner.
+    pub fn is_live(&self) -> bool {
+        self.cursor.is_none()
+    }
+
+    /// Gets the largest amount of banners stored.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Gets the amount of banners stored.
+    pub fn len(&self) -> usize {
+        self.banners.len()
+    }
+
+    /// Checks if there are no banners stored.
+    pub fn is_empty(&self) -> bool {
+        self.banners.is_empty()
+    }
+
+    /// Iterates over the stored banners from oldest to newest.
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.banners.iter().map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_follows_dropped_banners() {
+        let mut history = BannerHistory::new(3);
+        for banner in ["a", "b", "c"] {
+            history.push(String::from(banner));
+        }
+
+        assert_eq!(Some("b"), history.previous());
+        history.push(String::from("d"));
+        assert_eq!(Some("b"), history.current());
+        history.push(String::from("e"));
+        history.push(String::from("f"));
+        assert_eq!(Some("b"), history.current());
+        assert_eq!(vec!["b", "e", "f"], history.iter().collect::<Vec<&str>>());
+    }
+
+    #[test]
+    fn single_banner_stays_live() {
+        let mut history = BannerHistory::new(3);
+        assert_eq!(None, history.previous());
+
+        history.push(String::from("a"));
+        assert_eq!(Some("a"), history.previous());
+        assert!(history.is_live());
+        history.push(String::from("b"));
+        assert_eq!(Some("b"), history.current());
+    }
+
+    #[test]
+    fn oldest_viewed_banner_is_kept() {
+        let mut history = BannerHistory::new(3);
+        for banner in ["a", "b", "c"] {
+            history.push(String::from(banner));
+        }
+
+        history.previous();
+        assert_eq!(Some("a"), history.previous());
+        history.push(String::from("d"));
+        assert_eq!(Some("a"), history.current());
+        assert_eq!(vec!["a", "c", "d"], history.iter().collect::<Vec<&str>>());
+
+        let mut history = BannerHistory::new(1);
+        history.push(String::from("a"));
+        history.previous();
+        history.push(String::from("b"));
+        assert!(history.is_live());
+        assert_eq!(Some("b"), history.current());
+    }
+
+    #[test]
+    fn empty_history() {
+        let mut history = BannerHistory::new(0);
+        assert_eq!(1, history.capacity());
+        assert_eq!(None, history.previous());
+        assert_eq!(None, history.next());
+        assert!(history.is_live());
+    }
+}
@@ -0,0 +1,124 @@
+//! A module for displaying a [BigText] as an image using the
+//! [kitty graphics protocol](https://sw.kovidgoyal.net/kitty/graphics-protocol/).
+//!
+//! The protocol is supported by kitty, WezTerm and a few other terminals. This
+//! module requires the `kitty` feature.
+
+use crate::{raster::RasterOptions, BigText};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use std::{
+    env,
+    io::{self, Error, ErrorKind, IsTerminal, Write},
+};
+
+/// The largest amount of base64 encoded bytes sent in a single escape sequence.
+const CHUNK_SIZE: usize = 4096;
+
+/// Checks if the current terminal advertises support for the kitty graphics protocol.
+///
+/// The check is based on the environment variables set by the terminals that
+/// support the protocol.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::kitty;
+///
+/// if kitty::is_supported() {
+///     println!("Banners will be displayed as images");
+/// }
+/// ```
+pub fn is_supported() -> bool {
+    let term = env::var("TERM").unwrap_or_default();
+    let term_program = env::var("TERM_PROGRAM").unwrap_or_default();
+
+    env::var_os("KITTY_WINDOW_ID").is_some()
+        || term.contains("kitty")
+        || term_program == "WezTerm"
+        || term_program == "ghostty"
+}
+
+impl BigText {
+    /// Prints the stored string as an image using the kitty graphics protocol.
+    ///
+    /// The ascii-art is rasterized with the given `options` and transmitted as raw
+    /// RGBA pixels, [RasterOptions::terminal()] suits most terminals. If [None] is
+    /// provided for stream, the standard output would be used.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{raster::RasterOptions, BigText};
+    ///
+    /// let mut vec = Vec::new();
+    /// let printer = BigText::new("HI", None);
+    /// printer.print_kitty(Some(&mut vec), &RasterOptions::default()).unwrap();
+    ///
+    /// let output = String::from_utf8(vec).unwrap();
+    /// assert!(output.starts_with("\x1b_Ga=T,f=32,s=12,v=5,"));
+    /// ```
+    pub fn print_kitty(
+        &self,
+        stream: Option<&mut dyn Write>,
+        options: &RasterOptions,
+    ) -> Result<(), Error> {
+        let standard = &mut io::stdout();
+        let stream = stream.unwrap_or(standard);
+
+        let raster = self
+            .rasterize(options)
+            .ok_or_else(|| Error::new(ErrorKind::InvalidInput, "image is too large"))?;
+        let encoded = STANDARD.encode(raster.pixels());
+        let chunks: Vec<&[u8]> = encoded.as_bytes().chunks(CHUNK_SIZE).collect();
+
+        for (index, chunk) in chunks.iter().enumerate() {
+            let more = if index + 1 < chunks.len() { 1 } else { 0 };
+
+            // Only the first chunk carries the image metadata
+            if index == 0 {
+                write!(
+                    stream,
+                    "\x1b_Ga=T,f=32,s={},v={},m={};",
+                    raster.width(),
+                    raster.height(),
+                    more
+                )?;
+            } else {
+                write!(stream, "\x1b_Gm={more};")?;
+            }
+            stream.write_all(chunk)?;
+            write!(stream, "\x1b\\")?;
+        }
+
+        writeln!(stream)?;
+        Ok(())
+    }
+
+    /// Prints the stored string as an image if the standard output is a terminal
+    /// supporting the kitty graphics protocol, otherwise prints it as text via
+    /// [BigText::print()].
+    ///
+    /// Other streams can't be checked for a terminal, so the text is always printed
+    /// to them.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{raster::RasterOptions, BigText};
+    ///
+    /// let printer = BigText::new("HI", None);
+    /// printer.print_graphics(None, &RasterOptions::terminal()).unwrap();
+    ///
+    /// let mut vec = Vec::new();
+    /// printer.print_graphics(Some(&mut vec), &RasterOptions::terminal()).unwrap();
+    /// assert_eq!(printer.to_string().into_bytes(), vec);
+    /// ```
+    pub fn print_graphics(
+        &self,
+        stream: Option<&mut dyn Write>,
+        options: &RasterOptions,
+    ) -> Result<(), Error> {
+        if stream.is_none() && io::stdout().is_terminal() && is_supported() {
+            self.print_kitty(None, options)
+        } else {
+            self.print(stream)
+        }
+    }
+}
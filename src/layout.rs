@@ -0,0 +1,550 @@
+//! A module containing types that control how the characters of a
+//! [BigText](crate::BigText) are laid out relative to each other, and helpers that
+//! lay out multiple [BigText]s.
+
+use crate::{display_width, BigText, GLYPH_HEIGHT};
+
+/// A pattern that decides how far down each successive character is shifted.
+///
+/// Shifting characters by different amounts produces a static "bouncy" layout
+/// without needing any animation. The offset is measured in rows, so a character
+/// with an offset of `2` starts printing on the third row of the output.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::layout::OffsetPattern;
+///
+/// let pattern = OffsetPattern::Alternating(1);
+/// assert_eq!(0, pattern.offset(0));
+/// assert_eq!(1, pattern.offset(1));
+/// assert_eq!(0, pattern.offset(2));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum OffsetPattern {
+    /// Every character is printed on the same baseline.
+    #[default]
+    None,
+    /// Every second character is shifted down by the given amount of rows.
+    Alternating(usize),
+    /// Characters rise and fall in a triangle wave between `0` and `amplitude` rows.
+    Wave {
+        /// The largest offset of the wave.
+        amplitude: usize,
+    },
+    /// Offsets are taken from the given list, repeating from the start when the
+    /// text is longer than the list.
+    Custom(Vec<usize>),
+}
+
+impl OffsetPattern {
+    /// Gets the vertical offset of the character at `index`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::layout::OffsetPattern;
+    ///
+    /// let wave = OffsetPattern::Wave { amplitude: 2 };
+    /// let offsets: Vec<usize> = (0..6).map(|i| wave.offset(i)).collect();
+    /// assert_eq!(vec![0, 1, 2, 1, 0, 1], offsets);
+    /// ```
+    pub fn offset(&self, index: usize) -> usize {
+        match self {
+            Self::None => 0,
+            Self::Alternating(amount) => {
+                if index % 2 == 1 {
+                    *amount
+                } else {
+                    0
+                }
+            }
+            Self::Wave { amplitude: 0 } => 0,
+            Self::Wave { amplitude } => {
+                let period = amplitude * 2;
+                let phase = index % period;
+                if phase <= *amplitude {
+                    phase
+                } else {
+                    period - phase
+                }
+            }
+            Self::Custom(offsets) => {
+                if offsets.is_empty() {
+                    0
+                } else {
+                    offsets[index % offsets.len()]
+                }
+            }
+        }
+    }
+}
+
+/// How lines of big text are aligned horizontally.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Alignment {
+    /// Lines start at the left edge.
+    #[default]
+    Left,
+    /// Lines are centered.
+    Center,
+    /// Lines end at the right edge.
+    Right,
+}
+
+/// The order the characters of a line are printed in.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{layout::Direction, BigText};
+///
+/// let mut printer = BigText::new("AB", None);
+/// printer.set_direction(Direction::RightToLeft);
+/// assert_eq!(BigText::new("BA", None).to_string(), printer.to_string());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Direction {
+    /// The first character is printed on the left.
+    #[default]
+    LeftToRight,
+    /// The first character is printed on the right, for right-to-left scripts such
+    /// as Hebrew and Arabic. Lines are wrapped before they are reversed.
+    RightToLeft,
+}
+
+/// The characters printed at the end of every row.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{layout::LineEnding, BigText};
+///
+/// let mut printer = BigText::new("I", None);
+/// printer.set_line_ending(LineEnding::CrLf);
+/// assert_eq!(5, printer.to_string().matches("\r\n").count());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LineEnding {
+    /// A line feed, `\n`.
+    #[default]
+    Lf,
+    /// A carriage return and a line feed, `\r\n`, as used on Windows.
+    CrLf,
+}
+
+impl LineEnding {
+    /// Gets the characters of the line ending.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::layout::LineEnding;
+    ///
+    /// assert_eq!("\r\n", LineEnding::CrLf.as_str());
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LineEnding::Lf => "\n",
+            LineEnding::CrLf => "\r\n",
+        }
+    }
+}
+
+/// Gets the mirror image of direction-sensitive punctuation such as brackets, other
+/// characters are returned as they are.
+pub(crate) fn mirrored(c: char) -> char {
+    match c {
+        '(' => ')',
+        ')' => '(',
+        '[' => ']',
+        ']' => '[',
+        '{' => '}',
+        '}' => '{',
+        '<' => '>',
+        '>' => '<',
+        '«' => '»',
+        '»' => '«',
+        '‹' => '›',
+        '›' => '‹',
+        '/' => '\\',
+        '\\' => '/',
+        _ => c,
+    }
+}
+
+/// Renders `texts` side by side, separated by `gap` blank columns.
+///
+/// The first lines of the texts share a baseline, shorter texts are padded with
+/// blank rows below and every text is padded to its widest row. Colors aren't
+/// included.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{character_maps, layout, BigText};
+///
+/// let hours = BigText::new("12", None);
+/// let colon = BigText::new(":", Some(character_maps::punctuation()));
+/// let minutes = BigText::new("34", None);
+///
+/// let clock = layout::hstack(&[&hours, &colon, &minutes], 1);
+/// assert_eq!(5, clock.lines().count());
+/// assert!(clock.starts_with(hours.to_string().lines().next().unwrap()));
+/// ```
+pub fn hstack(texts: &[&BigText], gap: usize) -> String {
+    let blocks: Vec<Vec<String>> = texts.iter().map(|text| padded_rows(text)).collect();
+    let height = blocks.iter().map(Vec::len).max().unwrap_or(0);
+
+    join_blocks(&blocks, height, gap)
+}
+
+/// Rendered rows together with the row their content sits on, so renders of
+/// different heights can be lined up by [hstack_baseline()].
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{layout::Block, BigText};
+///
+/// let block = Block::from_text(&BigText::new("12", None));
+/// assert_eq!(4, block.baseline());
+/// assert_eq!(5, block.rows().len());
+///
+/// // A single row of plain text sits on its only row
+/// let label = Block::new(vec![String::from("AM")], 0);
+/// assert_eq!(0, label.baseline());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Block {
+    /// The rendered rows.
+    rows: Vec<String>,
+    /// The index of the row the content sits on, descenders go below it.
+    baseline: usize,
+}
+
+impl Block {
+    /// Constructor Function for [Block], from rows and the index of the row their
+    /// content sits on.
+    pub fn new(rows: Vec<String>, baseline: usize) -> Self {
+        Self { rows, baseline }
+    }
+
+    /// Renders `text` into a block whose baseline is the bottom row of the glyphs of
+    /// its first line, so descenders and shifted characters hang below it. Colors
+    /// aren't included.
+    pub fn from_text(text: &BigText) -> Self {
+        Self::new(text.render_rows(text.text()), GLYPH_HEIGHT - 1)
+    }
+
+    /// Gets the rendered rows.
+    pub fn rows(&self) -> &[String] {
+        &self.rows
+    }
+
+    /// Gets the index of the row the content sits on.
+    pub fn baseline(&self) -> usize {
+        self.baseline
+    }
+}
+
+/// Renders `blocks` side by side on a common baseline, separated by `gap` blank
+/// columns, such as big hours next to a smaller AM/PM.
+///
+/// Blocks are padded with blank rows above them until their baselines line up, and
+/// below them until they are as tall as the tallest one. Every block is padded to
+/// its widest row.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{layout::{self, Block}, BigText};
+///
+/// let hours = Block::from_text(&BigText::new("12", None));
+/// let period = Block::new(vec![String::from("PM")], 0);
+///
+/// let clock = layout::hstack_baseline(&[hours, period], 1);
+/// let rows: Vec<&str> = clock.lines().collect();
+/// assert_eq!(5, rows.len());
+/// assert!(rows[0].ends_with("   "));
+/// assert!(rows[4].ends_with(" PM"));
+/// ```
+pub fn hstack_baseline(blocks: &[Block], gap: usize) -> String {
+    let above = blocks.iter().map(Block::baseline).max().unwrap_or(0);
+    let blocks: Vec<Vec<String>> = blocks
+        .iter()
+        .map(|block| {
+            let padding = std::iter::repeat_n(String::new(), above - block.baseline);
+            pad_rows(padding.chain(block.rows.iter().cloned()).collect())
+        })
+        .collect();
+    let height = blocks.iter().map(Vec::len).max().unwrap_or(0);
+
+    join_blocks(&blocks, height, gap)
+}
+
+/// Renders `texts` on top of each other, separated by `gap` blank rows.
+///
+/// Every row is padded to the width of the widest text. Colors aren't included.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{layout, BigText};
+///
+/// let title = BigText::new("HI", None);
+/// let score = BigText::new("1", None);
+///
+/// let stacked = layout::vstack(&[&title, &score], 1);
+/// assert_eq!(11, stacked.lines().count());
+/// assert!(stacked.lines().all(|row| row.len() == title.to_string().lines().next().unwrap().len()));
+/// ```
+pub fn vstack(texts: &[&BigText], gap: usize) -> String {
+    let blocks: Vec<Vec<String>> = texts.iter().map(|text| padded_rows(text)).collect();
+    let width = blocks
+        .iter()
+        .map(|rows| block_width(rows))
+        .max()
+        .unwrap_or(0);
+
+    let mut output = String::new();
+    for (index, rows) in blocks.iter().enumerate() {
+        let gap_rows = if index > 0 { gap } else { 0 };
+        for row in std::iter::repeat_n(String::new(), gap_rows).chain(rows.iter().cloned()) {
+            let padding = width - display_width(&row);
+            output.push_str(&row);
+            output.push_str(&" ".repeat(padding));
+            output.push('\n');
+        }
+    }
+
+    output
+}
+
+/// Flows the lines of `text` into `count` columns of up to `height` rows, separated
+/// by `gap` blank columns, like the columns of a newspaper.
+///
+/// Every line of the text, including the lines it is [wrapped](BigText::set_width)
+/// into, fills the current column until the next one doesn't fit. Lines that are
+/// taller than `height` get a column of their own. When all the columns are
+/// filled, the remaining lines continue in a new set of columns below, after a
+/// blank row. Colors and effects aren't included.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{layout, BigText};
+///
+/// let items = BigText::new("1\n2\n3\n4", None);
+///
+/// // Two lines fit in every column of 11 rows
+/// let columns = layout::columns(&items, 2, 11, 2);
+/// assert_eq!(11, columns.lines().count());
+/// ```
+pub fn columns(text: &BigText, count: usize, height: usize, gap: usize) -> String {
+    let count = count.max(1);
+
+    // Filling the columns with whole lines
+    let mut columns: Vec<Vec<String>> = Vec::new();
+    let mut column: Vec<String> = Vec::new();
+    for rows in text.line_rows(&text.prepare(text.text())) {
+        let needed = if column.is_empty() {
+            rows.len()
+        } else {
+            column.len() + 1 + rows.len()
+        };
+        if needed > height && !column.is_empty() {
+            columns.push(std::mem::take(&mut column));
+        }
+        if !column.is_empty() {
+            column.push(String::new());
+        }
+        column.extend(rows);
+    }
+    if !column.is_empty() {
+        columns.push(column);
+    }
+
+    let pages: Vec<String> = columns
+        .chunks(count)
+        .map(|page| {
+            let blocks: Vec<Vec<String>> = page.iter().map(|rows| pad_rows(rows.clone())).collect();
+            let page_height = blocks.iter().map(Vec::len).max().unwrap_or(0);
+            join_blocks(&blocks, page_height, gap)
+        })
+        .collect();
+
+    pages.join("\n")
+}
+
+/// Centers the rendered `text` on a screen of `columns` by `rows`, with blank lines
+/// above it and every row padded on the left.
+///
+/// Texts larger than the screen aren't padded in that direction. Colors, the
+/// [line ending](BigText::set_line_ending), the
+/// [trailing newline](BigText::set_trailing_newline) and the
+/// [prefix](BigText::set_line_prefix) and [suffix](BigText::set_line_suffix) of
+/// every row, blank ones included, are kept. The padding goes after the prefix.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{layout, BigText};
+///
+/// let splash = layout::center(&BigText::new("I", None), 20, 9);
+/// let rows: Vec<&str> = splash.lines().collect();
+///
+/// assert_eq!(7, rows.len());
+/// assert_eq!("", rows[0]);
+/// assert_eq!("       ***** ", rows[2]);
+/// ```
+pub fn center(text: &BigText, columns: usize, rows: usize) -> String {
+    let rendered = text.render_rows(text.text());
+    let decoration = display_width(text.line_prefix()) + display_width(text.line_suffix());
+    let width = block_width(&rendered) + decoration;
+    let top = rows.saturating_sub(rendered.len()) / 2;
+    let left = " ".repeat(columns.saturating_sub(width) / 2);
+
+    let mut screen = vec![String::new(); top];
+    screen.extend(
+        text.colorize_rows(&rendered, &rendered)
+            .into_iter()
+            .map(|row| format!("{left}{row}")),
+    );
+
+    text.join_rows(screen)
+}
+
+/// Joins the rows of `blocks` side by side, separated by `gap` blank columns.
+fn join_blocks(blocks: &[Vec<String>], height: usize, gap: usize) -> String {
+    let separator = " ".repeat(gap);
+
+    let mut output = String::new();
+    for index in 0..height {
+        let row: Vec<String> = blocks
+            .iter()
+            .map(|rows| match rows.get(index) {
+                Some(row) => row.clone(),
+                None => " ".repeat(block_width(rows)),
+            })
+            .collect();
+        output.push_str(&row.join(&separator));
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Renders the rows of `text`, padded to the width of its widest row.
+fn padded_rows(text: &BigText) -> Vec<String> {
+    pad_rows(text.render_rows(text.text()))
+}
+
+/// Pads `rows` to the width of the widest row.
+fn pad_rows(rows: Vec<String>) -> Vec<String> {
+    let width = block_width(&rows);
+
+    rows.into_iter()
+        .map(|row| {
+            let padding = width - display_width(&row);
+            row + &" ".repeat(padding)
+        })
+        .collect()
+}
+
+/// Gets the amount of columns of the widest row.
+fn block_width(rows: &[String]) -> usize {
+    rows.iter().map(|row| display_width(row)).max().unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mirrored_brackets() {
+        assert_eq!(')', mirrored('('));
+        assert_eq!('«', mirrored('»'));
+        assert_eq!('A', mirrored('A'));
+    }
+
+    #[test]
+    fn hstack_baseline_pads_both_sides() {
+        let big = Block::from_text(&BigText::new("1", None));
+        let small = Block::new(
+            vec![String::from("ab"), String::from("cd"), String::from("gy")],
+            1,
+        );
+
+        let rows: Vec<String> = hstack_baseline(&[small, big], 0)
+            .lines()
+            .map(String::from)
+            .collect();
+        assert_eq!(6, rows.len());
+        assert!(rows[2].starts_with("  "));
+        assert!(rows[3].starts_with("ab"));
+        assert!(rows[4].starts_with("cd"));
+        assert!(rows[5].starts_with("gy"));
+        assert!(rows[5].trim_end().len() == 2);
+    }
+
+    #[test]
+    fn hstack_pads_shorter_texts() {
+        let tall = BigText::new("1\n1", None);
+        let short = BigText::new("1", None);
+
+        let stacked = hstack(&[&short, &tall], 2);
+        let rows: Vec<&str> = stacked.lines().collect();
+        assert_eq!(11, rows.len());
+        assert!(rows.iter().all(|row| row.len() == rows[0].len()));
+        assert!(
+            rows[10].starts_with(&" ".repeat(short.to_string().lines().next().unwrap().len() + 2))
+        );
+    }
+
+    #[test]
+    fn columns_overflow() {
+        let items = BigText::new("1\n2\n3", None);
+        let single = BigText::new("1", None).to_string();
+        let width = single.lines().next().unwrap().len();
+
+        // One line per column, the third line continues below
+        let rows: Vec<String> = columns(&items, 2, 5, 1).lines().map(String::from).collect();
+        assert_eq!(11, rows.len());
+        assert_eq!(width * 2 + 1, rows[0].len());
+        assert_eq!("", rows[5]);
+        assert_eq!(width, rows[6].len());
+    }
+
+    #[test]
+    fn center_larger_text() {
+        let text = BigText::new("HELLO", None);
+        assert_eq!(text.to_string(), center(&text, 4, 2));
+    }
+
+    #[test]
+    fn center_decorated() {
+        let mut text = BigText::new("I", None);
+        text.set_line_ending(LineEnding::CrLf)
+            .set_trailing_newline(false)
+            .set_line_prefix("# ");
+        let centered = center(&text, 12, 7);
+        let rows: Vec<&str> = centered.split("\r\n").collect();
+
+        assert_eq!(6, rows.len());
+        assert_eq!("# ", rows[0]);
+        assert_eq!("#   ***** ", rows[1]);
+        assert!(!centered.ends_with('\n'));
+    }
+
+    #[test]
+    fn stack_nothing() {
+        assert_eq!("", columns(&BigText::new("", None), 2, 5, 1).trim());
+        assert_eq!("", hstack(&[], 1));
+        assert_eq!("", vstack(&[], 1));
+    }
+
+    #[test]
+    fn custom_offset_repeats() {
+        let pattern = OffsetPattern::Custom(vec![0, 3]);
+        assert_eq!(0, pattern.offset(2));
+        assert_eq!(3, pattern.offset(3));
+        assert_eq!(0, OffsetPattern::Custom(Vec::new()).offset(5));
+    }
+
+    #[test]
+    fn flat_wave() {
+        assert_eq!(0, OffsetPattern::Wave { amplitude: 0 }.offset(3));
+    }
+}
@@ -35,13 +35,12 @@
 //! This will print to the console.
 //!
 //! ```sh
-//! string="ABC"
 //!  ***  ****    ***
 //! *   * *   *  *
 //! ***** ****  *
 //! *   * *   *  *
 //! *   * ****    ***
-//! string="0 == 1?"
+//!
 //! *****                             * ****
 //! *   *                             *     *
 //! *   *                             *   **
@@ -49,17 +48,96 @@
 //! *****                             *   *
 //! ```
 //!
+//! The font, colors, wrapping width, alignment and spacing can be changed with flags,
+//! see `cargo run -- --help` for all of them.
+//!
 //! **Note**: Unsupported character would be print as if it is a whitespace.
 
 #[allow(unused)]
 use std::{
-    collections::HashMap,
+    borrow::Cow,
+    collections::{BTreeSet, HashMap},
     fmt::Display,
     io::{self, Error, Write},
+    sync::Arc,
 };
 
-use character_maps::CharacterMap;
+use character_maps::{
+    CharacterMap, Fallback, Glyph, GlyphMetrics, GlyphProvider, MetricsMap, SequenceMap,
+};
+use color::{ColorFormat, ColorStyle};
+use effects::Effect;
+use layout::{Alignment, Direction, LineEnding, OffsetPattern};
+use normalize::Normalization;
+use unicode_normalization::char::is_combining_mark;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+pub mod animation;
+pub mod braille;
+pub mod canvas;
 pub mod character_maps;
+pub mod color;
+pub mod comment;
+#[cfg(any(feature = "gzip", feature = "zstd"))]
+mod compress;
+pub mod effects;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod fonts;
+pub mod history;
+#[cfg(feature = "kitty")]
+pub mod kitty;
+pub mod layout;
+mod macros;
+mod markdown;
+pub mod normalize;
+#[cfg(feature = "rayon")]
+mod parallel;
+pub mod printer;
+pub mod progress;
+mod random;
+pub mod raster;
+#[cfg(feature = "serde")]
+mod serialize;
+#[cfg(feature = "serde")]
+pub mod spec;
+pub mod svg;
+pub mod table;
+#[cfg(feature = "crossterm")]
+mod terminal;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "ratatui")]
+pub mod widget;
+
+#[doc(hidden)]
+pub use macros::print as __print;
+
+/// The amount of rows in every glyph of a [CharacterMap].
+const GLYPH_HEIGHT: usize = 5;
+/// The amount of columns an unsupported character takes up in a font without
+/// glyphs to measure, excluding spacing. Full-width characters take up twice as many.
+const UNKNOWN_WIDTH: usize = 5;
+
+/// The standard output the printers of the crate write to by default. With the
+/// `anstream` feature enabled, it is wrapped in an `anstream::AutoStream`, which
+/// makes colors work on legacy Windows consoles and strips them when the output
+/// isn't a terminal.
+#[cfg(not(feature = "anstream"))]
+pub type Stdout = io::Stdout;
+/// The standard output the printers of the crate write to by default. With the
+/// `anstream` feature enabled, it is wrapped in an `anstream::AutoStream`, which
+/// makes colors work on legacy Windows consoles and strips them when the output
+/// isn't a terminal.
+#[cfg(feature = "anstream")]
+pub type Stdout = anstream::Stdout;
+
+/// Gets a handle to the [Stdout].
+pub(crate) fn stdout() -> Stdout {
+    #[cfg(not(feature = "anstream"))]
+    return io::stdout();
+    #[cfg(feature = "anstream")]
+    return anstream::stdout();
+}
 
 /// A struct that prints strings in it's ascii-art form.
 ///
@@ -70,8 +148,8 @@ pub mod character_maps;
 /// when printing the ascii-art.
 ///
 /// If the character in the currently stored string to print isn't in the supported
-/// characters (not a key in the [CharacterMap]) it will print as a blank character
-/// (5 spaces).
+/// characters (not a key in the [CharacterMap]) it will print as a blank character,
+/// as wide as most glyphs of the font unless set with [BigText::set_unknown_width].
 ///
 /// # Examples
 /// ```rust
@@ -88,13 +166,73 @@ pub mod character_maps;
 /// let printer = BigText::new("HI", None);
 /// println!("{}", printer);
 /// ```
+#[derive(Debug, Clone)]
 pub struct BigText {
     /// The current text being stored.
     text: String,
     /// All the characters that can be printed.
     supported_characters: String,
-    /// The chracter map used to decide how to print the ASCII text.
-    character_map: CharacterMap,
+    /// The most common width of a single column glyph of the font, measured on the
+    /// glyphs of the lookup chain.
+    font_width: usize,
+    /// The chracter map used to decide how to print the ASCII text, which can be
+    /// shared with other [BigText]s.
+    character_map: Arc<CharacterMap>,
+    /// The maps looked up in order for characters missing from the `character_map`.
+    fallback_maps: Vec<Arc<CharacterMap>>,
+    /// The provider the glyphs are looked up in instead of the `character_map`.
+    glyph_provider: Option<Arc<dyn GlyphProvider>>,
+    /// The glyphs set and removed while a provider is set, looked up before it.
+    glyph_overrides: Arc<GlyphOverrides>,
+    /// The provider (or the `character_map`) followed by the fallback maps, the chain
+    /// every glyph is looked up in.
+    lookup: Fallback,
+    /// The glyphs of sequences of characters, matched before the characters.
+    sequence_map: Arc<SequenceMap>,
+    /// The baselines and advance widths of the characters.
+    glyph_metrics: Arc<MetricsMap>,
+    /// Whether missing characters are looked up again in the opposite case.
+    case_fallback: bool,
+    /// Whether the text is converted to uppercase before looking up the characters.
+    auto_uppercase: bool,
+    /// The characters that are replaced by other characters before looking them up.
+    substitutions: HashMap<char, char>,
+    /// The pattern used to vertically offset each character.
+    offset_pattern: OffsetPattern,
+    /// The function called for every zero-width character that is skipped.
+    zero_width_hook: Option<fn(char, usize)>,
+    /// The colors used when printing.
+    color: Option<ColorStyle>,
+    /// The escape codes used to print the colors.
+    color_format: ColorFormat,
+    /// Whether the text blinks.
+    blink: bool,
+    /// The Unicode normalization applied before looking up the characters.
+    normalization: Normalization,
+    /// Whether combining diacritics are removed before looking up the characters.
+    strip_diacritics: bool,
+    /// The amount of blank columns after every character.
+    spacing: usize,
+    /// The amount of columns of unsupported characters, [None] to use the `font_width`.
+    unknown_width: Option<usize>,
+    /// The amount of columns to wrap the text at.
+    width: Option<usize>,
+    /// How lines are aligned within the width.
+    alignment: Alignment,
+    /// The order the characters of a line are printed in.
+    direction: Direction,
+    /// Whether brackets are mirrored when printing right to left.
+    mirror_punctuation: bool,
+    /// The characters printed at the end of every row.
+    line_ending: LineEnding,
+    /// Whether the last row is followed by a line ending.
+    trailing_newline: bool,
+    /// The text printed before every row.
+    line_prefix: String,
+    /// The text printed after every row.
+    line_suffix: String,
+    /// The effects applied to the rendered text, in order.
+    effects: Vec<Arc<dyn Effect>>,
 }
 
 impl BigText {
@@ -119,21 +257,21 @@ impl BigText {
     ///     (
     ///         'H',
     ///         [
-    ///             String::from("H   H"),
-    ///             String::from("H   H"),
-    ///             String::from("HHHHH"),
-    ///             String::from("H   H"),
-    ///             String::from("H   H"),
+    ///             "H   H".into(),
+    ///             "H   H".into(),
+    ///             "HHHHH".into(),
+    ///             "H   H".into(),
+    ///             "H   H".into(),
     ///         ],
     ///     ),
     ///     (
     ///         'i',
     ///         [
-    ///             String::from("IIIII"),
-    ///             String::from("  I  "),
-    ///             String::from("  I  "),
-    ///             String::from("  I  "),
-    ///             String::from("IIIII"),
+    ///             "IIIII".into(),
+    ///             "  I  ".into(),
+    ///             "  I  ".into(),
+    ///             "  I  ".into(),
+    ///             "IIIII".into(),
     ///         ],
     ///     ),
     /// ]);
@@ -145,18 +283,66 @@ impl BigText {
 
         // Setting map to default map if None is given
         let character_map = match character_map {
-            None => character_maps::printables(),
-            Some(map) => map,
+            None => character_maps::shared_printables(),
+            Some(map) => Arc::new(map),
         };
 
-        // Getting supported charaters
-        let supported_characters = Self::get_supported_characters(&character_map);
-
-        Self {
+        let mut printer = Self {
             text,
-            supported_characters,
+            supported_characters: String::new(),
+            font_width: UNKNOWN_WIDTH,
             character_map,
-        }
+            fallback_maps: Vec::new(),
+            glyph_provider: None,
+            glyph_overrides: Arc::default(),
+            lookup: Fallback::default(),
+            sequence_map: Arc::default(),
+            glyph_metrics: Arc::default(),
+            case_fallback: false,
+            auto_uppercase: false,
+            substitutions: HashMap::new(),
+            offset_pattern: OffsetPattern::None,
+            zero_width_hook: None,
+            color: None,
+            color_format: ColorFormat::Ansi,
+            blink: false,
+            normalization: Normalization::None,
+            strip_diacritics: false,
+            spacing: 1,
+            unknown_width: None,
+            width: None,
+            alignment: Alignment::Left,
+            direction: Direction::LeftToRight,
+            mirror_punctuation: false,
+            line_ending: LineEnding::Lf,
+            trailing_newline: true,
+            line_prefix: String::new(),
+            line_suffix: String::new(),
+            effects: Vec::new(),
+        };
+
+        // Getting supported charaters
+        printer.refresh_supported_characters();
+        printer
+    }
+
+    /// Creates a [BigText] using the font named by the `PRINT_BIG_TEXT_FONT`
+    /// environment variable.
+    ///
+    /// The font is resolved with [fonts::resolve()], so it can be a built-in map, a
+    /// font in the font directories or a path. If the variable isn't set, the
+    /// default map is used like [BigText::new()].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// std::env::set_var("PRINT_BIG_TEXT_FONT", "digits");
+    /// let printer = BigText::from_env("42").unwrap();
+    /// assert_eq!(10, printer.character_map().len());
+    /// ```
+    pub fn from_env(text: &str) -> Result<Self, fonts::FontError> {
+        Ok(Self::new(text, fonts::from_env()?))
     }
 
     /// Gets the text currently text stored in the struct.
@@ -172,23 +358,76 @@ impl BigText {
         self.text.as_ref()
     }
 
-    /// Gets the all characters that the struct can printed.
+    /// Gets the all characters that the struct can printed, sorted by code point so
+    /// the output is the same on every run.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{character_maps, BigText};
+    ///
+    /// let printer = BigText::new("HI", Some(character_maps::digits()));
+    /// assert_eq!("0123456789", printer.supported_characters());
+    /// ```
+    pub fn supported_characters(&self) -> &str {
+        self.supported_characters.as_ref()
+    }
+
+    /// Gets an iterator over the [supported characters](BigText::supported_characters),
+    /// sorted by code point.
     ///
     /// # Examples
     /// ```rust
     /// use print_big_text_rs::BigText;
     ///
     /// let printer = BigText::new("HI", None);
+    /// assert_eq!(Some(' '), printer.supported_chars_iter().next());
+    /// assert!(printer.supported_chars_iter().is_sorted());
+    /// ```
+    pub fn supported_chars_iter(&self) -> impl Iterator<Item = char> + '_ {
+        self.supported_characters.chars()
+    }
+
+    /// Gets the characters of the stored string that aren't covered by the font and
+    /// would be printed as blanks, in the order they first appear.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
     ///
-    /// println!("{}", printer.supported_characters());
+    /// let printer = BigText::new("HI~THERE~", None);
+    /// assert_eq!(vec!['~'], printer.chars_missing());
     /// ```
+    pub fn chars_missing(&self) -> Vec<char> {
+        self.chars_missing_in(self.text())
+    }
+
+    /// Gets the characters of `text` that aren't covered by the font, in the order
+    /// they first appear.
     ///
-    /// This should show something simillar but in a different order:
-    /// ```text
-    /// *C#6]1@%"UAW,^QV(T;NIHREG4)OK3DZL0P?8&BJ72[S.$M!Y5F9X
+    /// Characters are looked up like when printing, so
+    /// [substitutions](BigText::set_substitution), [sequences](BigText::set_sequence_map)
+    /// and [fallback maps](BigText::add_fallback_map) are taken into account.
+    /// Whitespace isn't included, it's printed blank either way.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// let mut printer = BigText::new("", None);
+    /// assert_eq!(vec!['h', 'i'], printer.chars_missing_in("hi HI"));
+    ///
+    /// printer.set_case_fallback(true);
+    /// assert!(printer.chars_missing_in("hi HI").is_empty());
     /// ```
-    pub fn supported_characters(&self) -> &str {
-        self.supported_characters.as_ref()
+    pub fn chars_missing_in(&self, text: &str) -> Vec<char> {
+        let mut missing = Vec::new();
+        for cell in self.text_cells(text) {
+            if cell.glyph.is_none() && !cell.c.is_whitespace() && !missing.contains(&cell.c) {
+                missing.push(cell.c);
+            }
+        }
+
+        missing
     }
 
     /// Sets the text currently to print.
@@ -207,9 +446,39 @@ impl BigText {
         self
     }
 
+    /// Appends `text` to the end of the text currently to print.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    /// let mut printer = BigText::new("4", None);
+    ///
+    /// printer.append_text("2").append_text("0");
+    /// assert_eq!("420", printer.text());
+    /// ```
+    pub fn append_text(&mut self, text: &str) -> &mut Self {
+        self.text.push_str(text);
+        self
+    }
+
+    /// Removes all the text currently to print.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    /// let mut printer = BigText::new("69", None);
+    ///
+    /// printer.clear_text();
+    /// assert_eq!("", printer.text());
+    /// ```
+    pub fn clear_text(&mut self) -> &mut Self {
+        self.text.clear();
+        self
+    }
+
     /// Prints the stored string.
     ///
-    /// If [None] is provided for stream, the standard output would be used.
+    /// If [None] is provided for stream, the [standard output](Stdout) would be used.
     ///
     /// # Examples
     ///
@@ -243,148 +512,2275 @@ impl BigText {
     /// assert_eq!(" ***      * ****  \n*   *     *     * \n*****     *   **  \n*   *     *       \n*   *     *   *   \n", str);
     /// ```
     pub fn print(&self, stream: Option<&mut dyn Write>) -> Result<(), Error> {
-        let standard = &mut io::stdout();
+        let standard = &mut stdout();
         let stream = stream.unwrap_or(standard);
 
-        // Looping over 5 lines
-        for row in 0..5 {
-            // Looping over the all characters
-            for col in self.text().chars() {
-                // Printing Characters
-                match self.character_map.get(&col) {
-                    Some(arr) => write!(stream, "{} ", arr[row])?,
-                    None => write!(stream, "      ")?,
-                };
+        stream.write_all(self.output().as_bytes())
+    }
+
+    /// Prints the stored string like [print](BigText::print()), failing without
+    /// printing anything when the font doesn't support some of its characters.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{BigText, RenderError};
+    ///
+    /// let mut vec = Vec::new();
+    /// let printer = BigText::new("A~", None);
+    /// let error = printer.try_print(Some(&mut vec)).unwrap_err();
+    ///
+    /// assert!(matches!(error, RenderError::Unsupported(chars) if chars == [(1, '~')]));
+    /// assert!(vec.is_empty());
+    /// ```
+    pub fn try_print(&self, stream: Option<&mut dyn Write>) -> Result<(), RenderError> {
+        self.check_supported()?;
+        self.print(stream).map_err(RenderError::Io)
+    }
+
+    /// Renders the stored string like [to_string](ToString::to_string()), failing when
+    /// the font doesn't support some of its characters, for pipelines where blank
+    /// output is worse than failing.
+    ///
+    /// Whitespace is never unsupported. The errors point at the characters of the
+    /// stored string, before [normalization](BigText::set_normalization) and
+    /// [substitution](BigText::set_substitution).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{BigText, RenderError};
+    ///
+    /// let printer = BigText::new("HI", None);
+    /// assert_eq!(printer.to_string(), printer.try_render().unwrap());
+    ///
+    /// let printer = BigText::new("~HI~", None);
+    /// let error = printer.try_render().unwrap_err();
+    /// assert_eq!("unsupported characters: '~' at 0, '~' at 3", error.to_string());
+    /// ```
+    pub fn try_render(&self) -> Result<String, RenderError> {
+        self.check_supported()?;
+        Ok(self.output())
+    }
+
+    /// Fails with the positions of the characters of the stored string that the font
+    /// doesn't support.
+    ///
+    /// The characters are looked up like when printing, but the positions and
+    /// characters reported are the ones of the stored string they come from.
+    fn check_supported(&self) -> Result<(), RenderError> {
+        let (text, sources) = self.prepare_indexed(self.text());
+        let original: Vec<char> = self.text().chars().collect();
+
+        let mut positions: Vec<(usize, char)> = Vec::new();
+        for cell in self.text_cells_prepared(&text) {
+            if cell.glyph.is_some() || cell.c.is_whitespace() {
+                continue;
             }
+            // Characters prepared into several ones are only reported once
+            let source = sources[cell.index];
+            if positions.last().map(|(position, _)| *position) != Some(source) {
+                positions.push((source, original[source]));
+            }
+        }
+
+        match positions.is_empty() {
+            true => Ok(()),
+            false => Err(RenderError::Unsupported(positions)),
+        }
+    }
 
-            // Printing New Line
-            write!(stream, "\n")?;
+    /// Renders the stored string into any [fmt::Write](std::fmt::Write) sink, such as
+    /// an existing [String], a row at a time.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// let printer = BigText::new("HI", None);
+    /// let mut log = String::from("banner:\n");
+    /// printer.render_to(&mut log).unwrap();
+    ///
+    /// assert_eq!(format!("banner:\n{printer}"), log);
+    /// ```
+    pub fn render_to<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        let mut rows = self.rows().peekable();
+        while let Some(row) = rows.next() {
+            w.write_str(&row)?;
+            if self.trailing_newline || rows.peek().is_some() {
+                w.write_str(self.line_ending.as_str())?;
+            }
         }
 
         Ok(())
     }
 
-    /// Gets all the supported characters in the character_map.
-    fn get_supported_characters(map: &CharacterMap) -> String {
-        let mut supported_characters = String::new();
-
-        for key in map.keys() {
-            supported_characters.push(*key);
-        }
+    /// Gets the rendered rows of the stored string, without the newlines but with the
+    /// [prefix](BigText::set_line_prefix) and [suffix](BigText::set_line_suffix).
+    ///
+    /// Rows are rendered a line of text at a time as they are consumed, so they can
+    /// be interleaved with other output without rendering the whole text first.
+    /// When [colors](BigText::set_color), [blinking](BigText::set_blink) or
+    /// [effects](BigText::with_effect) are set, the whole text is rendered up front
+    /// to spread them over it.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// let printer = BigText::new("HI", None);
+    /// let mut rows = printer.rows();
+    ///
+    /// assert_eq!(Some(String::from("*   * ***** ")), rows.next());
+    /// assert_eq!(printer.to_string().lines().count(), rows.count() + 1);
+    /// ```
+    pub fn rows(&self) -> impl Iterator<Item = String> + '_ {
+        let eager = self.is_styled() || !self.effects.is_empty();
+        let rendered = eager.then(|| self.output_rows());
+        let lazy = match rendered {
+            None => Some(self.lazy_rows(&self.prepare(self.text()))),
+            Some(_) => None,
+        };
 
-        supported_characters
+        rendered
+            .into_iter()
+            .flatten()
+            .chain(lazy.into_iter().flatten())
+            .map(|row| self.decorate(row))
     }
 
-    /// Sets the `character_map` to use when printing.
+    /// Gets the amount of columns the stored string takes up when printed, the width
+    /// of its widest row including the [prefix](BigText::set_line_prefix) and
+    /// [suffix](BigText::set_line_suffix).
+    ///
+    /// The text is rendered to measure it, so the font, spacing, wrapping and
+    /// [effects](BigText::with_effect) are all taken into account.
     ///
     /// # Examples
     /// ```rust
-    /// use print_big_text_rs::{character_maps::{self, CharacterMap}, BigText};
-    /// use std::collections::HashMap;
+    /// use print_big_text_rs::BigText;
     ///
-    /// let map: CharacterMap = HashMap::from([
-    ///     (
-    ///         'A',
-    ///         [
-    ///             String::from("     "),
-    ///             String::from("     "),
-    ///             String::from("     "),
-    ///             String::from("     "),
-    ///             String::from("     "),
-    ///         ],
-    ///     ),
-    /// ]);
-    /// let mut printer = BigText::new("", None);
-    /// assert_eq!(&character_maps::printables(), printer.character_map());
+    /// let mut printer = BigText::new("HI", None);
+    /// assert_eq!(12, printer.rendered_width());
     ///
-    /// printer.set_character_map(map.clone());
-    /// assert_eq!(&map, printer.character_map());
+    /// printer.set_spacing(3);
+    /// assert_eq!(16, printer.rendered_width());
     /// ```
-    pub fn set_character_map(&mut self, character_map: CharacterMap) {
-        self.character_map = character_map;
-        // Resetting supported_characters
-        self.supported_characters = Self::get_supported_characters(&self.character_map);
+    pub fn rendered_width(&self) -> usize {
+        let decoration = display_width(&self.line_prefix) + display_width(&self.line_suffix);
+        let rows = self.render_rows(self.text());
+
+        match rows.iter().map(|row| display_width(row)).max() {
+            Some(width) => width + decoration,
+            None => 0,
+        }
     }
 
-    /// Gets the `character_map` to use when printing.
+    /// Gets the amount of rows the stored string takes up when printed, including the
+    /// blank rows between its lines.
     ///
     /// # Examples
     /// ```rust
-    /// use print_big_text_rs::{character_maps, BigText};
-    /// use std::collections::HashMap;
+    /// use print_big_text_rs::BigText;
     ///
-    /// let printer = BigText::new("", None);
-    /// assert_eq!(&character_maps::printables(), printer.character_map());
+    /// assert_eq!(5, BigText::new("HI", None).rendered_height());
+    /// assert_eq!(11, BigText::new("HI\nHI", None).rendered_height());
     /// ```
-    pub fn character_map(&self) -> &CharacterMap {
-        &self.character_map
+    pub fn rendered_height(&self) -> usize {
+        self.render_rows(self.text()).len()
     }
-}
 
-impl Display for BigText {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        // Looping over 5 lines
-        for row in 0..5 {
-            // Looping over the all characters
-            for col in self.text().chars() {
-                // Printing Characters
-                match self.character_map.get(&col) {
-                    Some(arr) => write!(f, "{} ", arr[row])?,
-                    None => write!(f, "      ")?,
-                };
+    /// Normalizes `text`, converts it to uppercase when
+    /// [enabled](BigText::set_auto_uppercase) and applies the
+    /// [substitutions](BigText::set_substitution), before its characters are looked
+    /// up.
+    fn prepare<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        let text = normalize::normalize(text, self.normalization, self.strip_diacritics);
+        let text = match self.auto_uppercase {
+            true => Cow::Owned(text.to_uppercase()),
+            false => text,
+        };
+        match self.substitutions.is_empty() {
+            true => text,
+            false => Cow::Owned(
+                text.chars()
+                    .map(|c| self.substitutions.get(&c).copied().unwrap_or(c))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Prepares `text` like [prepare](BigText::prepare()), also getting the position
+    /// within `text` of the character every prepared character comes from.
+    ///
+    /// A character is prepared together with the combining marks after it, the
+    /// characters they turn into come from the first one.
+    fn prepare_indexed(&self, text: &str) -> (String, Vec<usize>) {
+        let chars: Vec<char> = text.chars().collect();
+        let mut prepared = String::with_capacity(text.len());
+        let mut sources = Vec::with_capacity(chars.len());
+
+        let mut start = 0;
+        while start < chars.len() {
+            let end = chars[start + 1..]
+                .iter()
+                .position(|c| !is_combining_mark(*c))
+                .map_or(chars.len(), |length| start + 1 + length);
+            let cluster: String = chars[start..end].iter().collect();
+            for c in self.prepare(&cluster).chars() {
+                prepared.push(c);
+                sources.push(start);
             }
+            start = end;
+        }
+
+        (prepared, sources)
+    }
+
+    /// Gets the characters of `text` that take up a column when printed.
+    fn visible_chars(&self, text: &str) -> Vec<char> {
+        self.visible_chars_prepared(&self.prepare(text))
+    }
+
+    /// Gets the characters of the already [prepared](BigText::prepare()) `text` that
+    /// take up a column when printed.
+    ///
+    /// Zero-width characters are attached to the character before them, so they are
+    /// skipped after calling the zero-width hook.
+    fn visible_chars_prepared(&self, text: &str) -> Vec<char> {
+        self.visible_positions(text.chars().enumerate())
+            .into_iter()
+            .map(|(_, c)| c)
+            .collect()
+    }
 
-            // Printing New Line
-            write!(f, "\n")?;
+    /// Gets the characters that take up a column when printed, with their positions
+    /// within the prepared text, like
+    /// [visible_chars_prepared](BigText::visible_chars_prepared()).
+    fn visible_positions(
+        &self,
+        chars: impl IntoIterator<Item = (usize, char)>,
+    ) -> Vec<(usize, char)> {
+        let chars = chars.into_iter();
+        let mut visible = Vec::with_capacity(chars.size_hint().0);
+
+        for (position, c) in chars {
+            if is_zero_width(c) {
+                if let Some(hook) = self.zero_width_hook {
+                    hook(c, position);
+                }
+                continue;
+            }
+            visible.push((position, c));
         }
 
-        Ok(())
+        visible
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Renders the stored text into the rows that are printed, including colors.
+    fn output_rows(&self) -> Vec<String> {
+        let rows = self.render_rows(self.text());
+        match self.is_styled() {
+            false => rows,
+            true => self.colorize_rows(&rows, &rows),
+        }
+    }
 
-    #[test]
-    fn get_supported_characters() {
-        let map = HashMap::from([
-            (
-                'A',
-                [
-                    "".to_string(),
-                    "".to_string(),
-                    "".to_string(),
-                    "".to_string(),
-                    "".to_string(),
-                ],
-            ),
-            (
-                '1',
-                [
-                    "".to_string(),
-                    "".to_string(),
-                    "".to_string(),
-                    "".to_string(),
-                    "".to_string(),
-                ],
-            ),
-            (
-                '"',
-                [
-                    "".to_string(),
-                    "".to_string(),
-                    "".to_string(),
-                    "".to_string(),
-                    "".to_string(),
-                ],
-            ),
-        ]);
+    /// Checks if the rows are printed with any escape codes.
+    fn is_styled(&self) -> bool {
+        self.color.is_some() || self.blink
+    }
+
+    /// Renders the stored text into a single string with a line ending after every
+    /// row, allocated once for the whole output.
+    fn output(&self) -> String {
+        self.join_rows(self.output_rows())
+    }
+
+    /// Adds the [prefix](BigText::set_line_prefix) and
+    /// [suffix](BigText::set_line_suffix) to a rendered row.
+    fn decorate(&self, row: String) -> String {
+        match (self.line_prefix.is_empty(), self.line_suffix.is_empty()) {
+            (true, true) => row,
+            _ => format!("{}{row}{}", self.line_prefix, self.line_suffix),
+        }
+    }
+
+    /// Decorates `rows` and joins them with the [line ending](BigText::set_line_ending),
+    /// ending the last row with one when the
+    /// [trailing newline](BigText::set_trailing_newline) is enabled.
+    fn join_rows(&self, rows: Vec<String>) -> String {
+        let ending = self.line_ending.as_str();
+        let decoration = self.line_prefix.len() + self.line_suffix.len();
+        let mut output = String::with_capacity(
+            rows.iter()
+                .map(|row| row.len() + decoration + ending.len())
+                .sum(),
+        );
+
+        for (index, row) in rows.iter().enumerate() {
+            if index > 0 {
+                output.push_str(ending);
+            }
+            output.push_str(&self.line_prefix);
+            output.push_str(row);
+            output.push_str(&self.line_suffix);
+        }
+        if self.trailing_newline && !rows.is_empty() {
+            output.push_str(ending);
+        }
+
+        output
+    }
+
+    /// Colors rendered rows, spreading the colors over the filled columns of the
+    /// `layout` rows so partial renders are colored like the full text.
+    ///
+    /// The rows are also made to blink when [blinking](BigText::set_blink).
+    fn colorize_rows(&self, rows: &[String], layout: &[String]) -> Vec<String> {
+        let colored = self.color_rows(rows, layout);
+        match self.blink && self.color_format == ColorFormat::Ansi {
+            false => colored,
+            // Colored rows end with a reset, which also stops blinking
+            true => colored
+                .into_iter()
+                .map(|row| match row.is_empty() {
+                    true => row,
+                    false => format!("\x1b[5m{row}\x1b[25m"),
+                })
+                .collect(),
+        }
+    }
+
+    /// Colors rendered rows like [colorize_rows](BigText::colorize_rows()), without
+    /// blinking.
+    fn color_rows(&self, rows: &[String], layout: &[String]) -> Vec<String> {
+        match &self.color {
+            None => rows.to_vec(),
+            Some(style) => {
+                let width = layout
+                    .iter()
+                    .map(|row| display_width(row))
+                    .max()
+                    .unwrap_or(0);
+                // Spreading the colors over the filled columns only
+                let start = layout
+                    .iter()
+                    .filter_map(|row| {
+                        let filled = row.find(|c: char| !c.is_whitespace())?;
+                        Some(display_width(&row[..filled]))
+                    })
+                    .min()
+                    .unwrap_or(0);
+                rows.iter()
+                    .map(|row| color::colorize_row(style, self.color_format, row, start, width))
+                    .collect()
+            }
+        }
+    }
+
+    /// Renders `text` into the rows of its ascii-art form.
+    ///
+    /// The text is split into lines at newlines and wherever it is wider than the
+    /// [width](BigText::set_width), every line is aligned and separated from the next
+    /// by a blank row.
+    fn render_rows(&self, text: &str) -> Vec<String> {
+        self.render_prepared(&self.prepare(text))
+    }
+
+    /// Renders the already [prepared](BigText::prepare()) `text` like
+    /// [render_rows](BigText::render_rows()).
+    fn render_prepared(&self, text: &str) -> Vec<String> {
+        self.apply_effects(self.lazy_rows(text).collect())
+    }
+
+    /// Applies the [effects](BigText::with_effect) to the rendered rows.
+    fn apply_effects(&self, rows: Vec<String>) -> Vec<String> {
+        match self.effects.is_empty() {
+            true => rows,
+            false => effects::apply(&self.effects, rows),
+        }
+    }
+
+    /// Renders the [prepared](BigText::prepare()) `text` like
+    /// [render_rows](BigText::render_rows()), a single line of text at a time as the
+    /// rows are consumed, without the effects.
+    fn lazy_rows(&self, text: &str) -> impl Iterator<Item = String> + '_ {
+        separate_lines(self.line_rows(text))
+    }
+
+    /// Renders resolved cells of the text like [render_rows](BigText::render_rows()),
+    /// such as a part of the cells of [line_cells](BigText::line_cells()).
+    fn render_cells(&self, cells: &[Cell]) -> Vec<String> {
+        self.apply_effects(separate_lines(self.aligned_rows(self.split_lines(cells))).collect())
+    }
+
+    /// Renders the aligned rows of every line of the [prepared](BigText::prepare())
+    /// `text` separately, a line at a time as they are consumed.
+    fn line_rows(&self, text: &str) -> impl Iterator<Item = Vec<String>> + '_ {
+        self.aligned_rows(self.lines(text))
+    }
+
+    /// Renders the rows of every line separately, aligned within the widest line or
+    /// the [width](BigText::set_width), a line at a time as they are consumed.
+    fn aligned_rows<'a>(
+        &'a self,
+        lines: Vec<Vec<Cell<'a>>>,
+    ) -> impl Iterator<Item = Vec<String>> + 'a {
+        let width = self.align_width(&lines);
 
-        let supported_characters = BigText::get_supported_characters(&map);
-        assert!(supported_characters.contains("A"));
-        assert!(supported_characters.contains("\""));
-        assert!(supported_characters.contains("1"));
-        assert!(!supported_characters.contains("B"));
+        lines.into_iter().map(move |line| {
+            let padding = self.padding(&line, width);
+            self.render_line(&line, padding)
+        })
+    }
+
+    /// Splits the [prepared](BigText::prepare()) `text` into the lines that are
+    /// rendered, at newlines and wherever it is wider than the
+    /// [width](BigText::set_width).
+    fn lines(&self, text: &str) -> Vec<Vec<Cell<'_>>> {
+        self.split_lines(&self.line_cells(text))
+    }
+
+    /// Resolves the cells of the [prepared](BigText::prepare()) `text`, with the
+    /// brackets mirrored when [enabled](BigText::set_mirror_punctuation) for text
+    /// printed right to left.
+    fn line_cells(&self, text: &str) -> Vec<Cell<'_>> {
+        match self.direction == Direction::RightToLeft && self.mirror_punctuation {
+            true => {
+                self.text_cells_prepared(&text.chars().map(layout::mirrored).collect::<String>())
+            }
+            false => self.text_cells_prepared(text),
+        }
+    }
+
+    /// Splits cells into the lines that are rendered, at newlines and wherever they
+    /// are wider than the [width](BigText::set_width).
+    ///
+    /// Lines printed [right to left](Direction::RightToLeft) are reversed after
+    /// wrapping.
+    fn split_lines<'a>(&self, cells: &[Cell<'a>]) -> Vec<Vec<Cell<'a>>> {
+        let mut lines: Vec<Vec<Cell>> = cells
+            .split(|cell| cell.c == '\n')
+            .flat_map(|line| self.wrap(line))
+            .collect();
+        if self.direction == Direction::RightToLeft {
+            lines.iter_mut().for_each(|line| line.reverse());
+        }
+
+        lines
+    }
+
+    /// Resolves the cells of `text`, matching the longest
+    /// [sequences](BigText::set_sequence_map) before the single characters.
+    fn text_cells(&self, text: &str) -> Vec<Cell<'_>> {
+        self.text_cells_prepared(&self.prepare(text))
+    }
+
+    /// Resolves the cells of the already [prepared](BigText::prepare()) `text` like
+    /// [text_cells](BigText::text_cells()).
+    fn text_cells_prepared(&self, text: &str) -> Vec<Cell<'_>> {
+        let longest = self
+            .sequence_map
+            .keys()
+            .map(|key| key.chars().count())
+            .max()
+            .unwrap_or(0);
+        if longest == 0 {
+            return self.indexed_cells(&self.visible_positions(text.chars().enumerate()));
+        }
+
+        let chars: Vec<char> = text.chars().collect();
+        let mut cells = Vec::with_capacity(chars.len());
+        // The characters since the last sequence, with their positions
+        let mut run = Vec::new();
+        let mut index = 0;
+
+        while index < chars.len() {
+            let matched = (1..=longest.min(chars.len() - index))
+                .rev()
+                .find_map(|length| {
+                    let key: String = chars[index..index + length].iter().collect();
+                    self.sequence_map.get(&key).map(|glyph| (length, glyph))
+                });
+
+            match matched {
+                Some((length, glyph)) => {
+                    cells.extend(self.indexed_cells(&self.visible_positions(run.drain(..))));
+                    cells.push(Cell {
+                        c: chars[index],
+                        index,
+                        glyph: Some(glyph),
+                        width: self.glyph_width(chars[index], Some(glyph)),
+                        drop: 0,
+                        advance: None,
+                    });
+                    index += length;
+                }
+                None => {
+                    run.push((index, chars[index]));
+                    index += 1;
+                }
+            }
+        }
+        cells.extend(self.indexed_cells(&self.visible_positions(run)));
+
+        cells
+    }
+
+    /// Resolves the glyph and width of every character, positioned one after
+    /// another.
+    fn cells(&self, chars: &[char]) -> Vec<Cell<'_>> {
+        let chars: Vec<(usize, char)> = chars.iter().copied().enumerate().collect();
+        self.indexed_cells(&chars)
+    }
+
+    /// Resolves the glyph and width of every character at its position within the
+    /// prepared text.
+    ///
+    /// Ascii characters are only looked up in the map the first time they are seen.
+    fn indexed_cells(&self, chars: &[(usize, char)]) -> Vec<Cell<'_>> {
+        let mut ascii: [Option<Cell>; 128] = [None; 128];
+        let resolve = |c: char| {
+            let glyph = self.glyph(c);
+            let metrics = match glyph {
+                Some(_) => self.metrics(c),
+                None => GlyphMetrics::default(),
+            };
+            Cell {
+                c,
+                index: 0,
+                glyph,
+                width: match metrics.advance {
+                    Some(advance) => advance + self.spacing,
+                    None => self.glyph_width(c, glyph),
+                },
+                drop: metrics
+                    .baseline
+                    .map_or(0, |baseline| (GLYPH_HEIGHT - 1).saturating_sub(baseline)),
+                advance: metrics.advance,
+            }
+        };
+
+        chars
+            .iter()
+            .map(|&(index, c)| {
+                let cell = match ascii.get_mut(c as usize) {
+                    Some(cached) => *cached.get_or_insert_with(|| resolve(c)),
+                    None => resolve(c),
+                };
+                Cell { index, ..cell }
+            })
+            .collect()
+    }
+
+    /// Gets the width the lines are aligned within, the widest line is only measured
+    /// when the lines aren't aligned to the left and no width is set.
+    fn align_width(&self, lines: &[Vec<Cell>]) -> usize {
+        match (self.width, self.alignment) {
+            (Some(width), _) => width,
+            (None, Alignment::Left) => 0,
+            (None, _) => lines
+                .iter()
+                .map(|line| self.line_width(line))
+                .max()
+                .unwrap_or(0),
+        }
+    }
+
+    /// Gets the amount of columns a line is moved right to align it within `width`.
+    fn padding(&self, line: &[Cell], width: usize) -> usize {
+        match self.alignment {
+            Alignment::Left => 0,
+            Alignment::Center => width.saturating_sub(self.line_width(line)) / 2,
+            Alignment::Right => width.saturating_sub(self.line_width(line)),
+        }
+    }
+
+    /// Gets the amount of columns of the widest row of a rendered line, without
+    /// rendering it.
+    fn line_width(&self, line: &[Cell]) -> usize {
+        (0..self.line_height(line))
+            .map(|row| {
+                line.iter()
+                    .enumerate()
+                    .map(|(index, cell)| match self.glyph_row(cell, index, row) {
+                        Some(part) => cell.advance.unwrap_or(display_width(part)) + self.spacing,
+                        None => cell.width,
+                    })
+                    .sum()
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Splits a line into pieces that fit in the [width](BigText::set_width).
+    ///
+    /// Lines are broken at the last space that fits when possible, a single
+    /// character wider than the width is kept on its own.
+    fn wrap<'a>(&self, line: &[Cell<'a>]) -> Vec<Vec<Cell<'a>>> {
+        let width = match self.width {
+            Some(width) => width,
+            None => return vec![line.to_vec()],
+        };
+
+        let mut pieces = Vec::new();
+        let mut current: Vec<Cell> = Vec::new();
+        let mut current_width = 0;
+
+        for cell in line {
+            if current_width + cell.width > width && !current.is_empty() {
+                match current.iter().rposition(|cell| cell.c == ' ') {
+                    // Moving the last word to the next line
+                    Some(space) if space > 0 => {
+                        let rest = current.split_off(space + 1);
+                        current.pop();
+                        pieces.push(std::mem::replace(&mut current, rest));
+                    }
+                    _ => pieces.push(std::mem::take(&mut current)),
+                }
+                current_width = current.iter().map(|cell| cell.width).sum();
+            }
+
+            // Not starting a line with a space
+            if current.is_empty() && cell.c == ' ' && !pieces.is_empty() {
+                continue;
+            }
+            current.push(*cell);
+            current_width += cell.width;
+        }
+
+        pieces.push(current);
+        pieces
+    }
+
+    /// Gets the amount of columns a glyph takes up, including the spacing after it.
+    ///
+    /// Characters without a glyph take up the
+    /// [unknown width](BigText::set_unknown_width), or twice as many for full-width
+    /// characters such as CJK ideographs.
+    fn glyph_width(&self, c: char, glyph: Option<&Glyph>) -> usize {
+        let unknown_width = self.unknown_width.unwrap_or(self.font_width);
+        let glyph_width = match glyph {
+            Some(arr) => arr.iter().map(|part| display_width(part)).max(),
+            None => Some(unknown_width * c.width().unwrap_or(1).max(1)),
+        };
+
+        glyph_width.unwrap_or(0) + self.spacing
+    }
+
+    /// Gets the part of the glyph of the character at `index` within its line printed
+    /// on `row`, [None] for rows above and below shifted characters and unknown ones.
+    fn glyph_row<'a>(&self, cell: &Cell<'a>, index: usize, row: usize) -> Option<&'a str> {
+        let row = row.checked_sub(self.offset_pattern.offset(index) + cell.drop)?;
+        cell.glyph
+            .and_then(|glyph| glyph.get(row))
+            .map(|part| &**part)
+    }
+
+    /// Renders a single line of characters, moved right by `padding` columns.
+    fn render_line(&self, line: &[Cell], padding: usize) -> Vec<String> {
+        self.render_chunk(line, 0, self.line_height(line), padding)
+    }
+
+    /// Gets the amount of rows of a rendered line, including the rows below
+    /// characters that are shifted down and descenders.
+    fn line_height(&self, line: &[Cell]) -> usize {
+        let max_offset = line
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| self.offset_pattern.offset(index) + cell.drop)
+            .max()
+            .unwrap_or(0);
+
+        GLYPH_HEIGHT + max_offset
+    }
+
+    /// Renders `height` rows of a part of a line moved right by `padding` columns,
+    /// `first` being the index of the first character within the line.
+    fn render_chunk(
+        &self,
+        chunk: &[Cell],
+        first: usize,
+        height: usize,
+        padding: usize,
+    ) -> Vec<String> {
+        let spacing = " ".repeat(self.spacing);
+        let capacity = padding + chunk.iter().map(|cell| cell.width).sum::<usize>();
+
+        // Looping over all the lines
+        (0..height)
+            .map(|row| {
+                let mut line = String::with_capacity(capacity);
+                line.extend(std::iter::repeat_n(' ', padding));
+
+                // Looping over the all characters
+                for (index, cell) in chunk.iter().enumerate() {
+                    match self.glyph_row(cell, first + index, row) {
+                        Some(part) => {
+                            match cell.advance {
+                                Some(advance) => line.push_str(&fit(part, advance)),
+                                None => line.push_str(part),
+                            }
+                            line.push_str(&spacing);
+                        }
+                        None => line.extend(std::iter::repeat_n(' ', cell.width)),
+                    }
+                }
+
+                line
+            })
+            .collect()
+    }
+
+    /// Sets the `character_map` to use when printing.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{character_maps::{self, CharacterMap}, BigText};
+    /// use std::collections::HashMap;
+    ///
+    /// let map: CharacterMap = HashMap::from([
+    ///     (
+    ///         'A',
+    ///         [
+    ///             "     ".into(),
+    ///             "     ".into(),
+    ///             "     ".into(),
+    ///             "     ".into(),
+    ///             "     ".into(),
+    ///         ],
+    ///     ),
+    /// ]);
+    /// let mut printer = BigText::new("", None);
+    /// assert_eq!(&character_maps::printables(), printer.character_map());
+    ///
+    /// printer.set_character_map(map.clone());
+    /// assert_eq!(&map, printer.character_map());
+    /// ```
+    ///
+    /// A map wrapped in an [Arc] is shared instead of being copied, which is useful
+    /// when many [BigText]s use the same font:
+    ///
+    /// ```rust
+    /// use print_big_text_rs::{character_maps, BigText};
+    /// use std::sync::Arc;
+    ///
+    /// let font = Arc::new(character_maps::digits());
+    /// let mut printers = vec![BigText::new("1", None), BigText::new("2", None)];
+    /// for printer in &mut printers {
+    ///     printer.set_character_map(font.clone());
+    /// }
+    ///
+    /// assert!(Arc::ptr_eq(&font, printers[1].shared_character_map()));
+    /// ```
+    pub fn set_character_map(&mut self, character_map: impl Into<Arc<CharacterMap>>) {
+        self.character_map = character_map.into();
+        // Resetting supported_characters
+        self.refresh_supported_characters();
+    }
+
+    /// Sets the glyph of a character in the `character_map`, adding the character if
+    /// it isn't supported yet.
+    ///
+    /// A map shared with other [BigText]s is copied first, so they aren't changed.
+    /// While a [glyph provider](BigText::set_glyph_provider) is set, the glyph is kept
+    /// in overrides looked up before the provider instead, until the provider is
+    /// changed.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// let mut printer = BigText::new("~", None);
+    /// printer.set_glyph('~', ["     ", " * **", "* ** ", "     ", "     "].map(String::from));
+    /// assert!(printer.supported_characters().contains('~'));
+    /// assert!(printer.to_string().starts_with("      \n * ** \n"));
+    /// assert!(!BigText::new("", None).supported_characters().contains('~'));
+    /// ```
+    pub fn set_glyph(&mut self, c: char, glyph: [String; 5]) -> &mut Self {
+        // The lookup chain shares the maps, dropping it so they aren't copied
+        self.lookup = Fallback::default();
+        let glyph = glyph.map(Cow::Owned);
+        match self.glyph_provider {
+            Some(_) => {
+                Arc::make_mut(&mut self.glyph_overrides).insert(c, Some(glyph));
+            }
+            None => {
+                Arc::make_mut(&mut self.character_map).insert(c, glyph);
+            }
+        }
+        self.refresh_supported_characters();
+        self
+    }
+
+    /// Removes the glyph of a character from the `character_map`, returning it if the
+    /// character was supported.
+    ///
+    /// A map shared with other [BigText]s is copied first, so they aren't changed.
+    /// While a [glyph provider](BigText::set_glyph_provider) is set, the glyph of the
+    /// provider is hidden by the overrides instead, until the provider is changed.
+    /// The [fallback maps](BigText::add_fallback_map) are still looked up for it.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// let mut printer = BigText::new("A", None);
+    /// assert!(printer.remove_glyph('A').is_some());
+    /// assert!(printer.remove_glyph('A').is_none());
+    /// assert!(!printer.supported_characters().contains('A'));
+    /// assert_eq!(BigText::new(" ", None).to_string(), printer.to_string());
+    /// ```
+    pub fn remove_glyph(&mut self, c: char) -> Option<Glyph> {
+        if let Some(provider) = &self.glyph_provider {
+            let glyph = match self.glyph_overrides.get(&c) {
+                Some(glyph) => glyph.clone(),
+                None => provider.glyph(c).cloned(),
+            };
+            Arc::make_mut(&mut self.glyph_overrides).insert(c, None);
+            self.refresh_supported_characters();
+            return glyph;
+        }
+
+        // Not copying a shared map when there is nothing to remove
+        if !self.character_map.contains_key(&c) {
+            return None;
+        }
+
+        self.lookup = Fallback::default();
+        let glyph = Arc::make_mut(&mut self.character_map).remove(&c);
+        self.refresh_supported_characters();
+        glyph
+    }
+
+    /// Gets the most common width of the `glyphs` per column of their characters, so
+    /// full-width glyphs count as half as wide. Whitespace is left out and
+    /// [UNKNOWN_WIDTH] is used for fonts without other glyphs.
+    fn typical_width<'a>(glyphs: impl IntoIterator<Item = (char, &'a Glyph)>) -> usize {
+        let mut counts: HashMap<usize, usize> = HashMap::new();
+        for (c, glyph) in glyphs.into_iter().filter(|(c, _)| !c.is_whitespace()) {
+            let width = glyph.iter().map(|part| display_width(part)).max();
+            let columns = c.width().unwrap_or(1).max(1);
+            *counts
+                .entry(width.unwrap_or(0).div_ceil(columns))
+                .or_default() += 1;
+        }
+
+        // Preferring the narrower width on ties, so the result doesn't depend on the
+        // iteration order
+        counts
+            .into_iter()
+            .max_by_key(|(width, count)| (*count, std::cmp::Reverse(*width)))
+            .map_or(UNKNOWN_WIDTH, |(width, _)| width)
+    }
+
+    /// Rebuilds the lookup chain of the provider (or the `character_map`) followed by
+    /// the [fallback maps](BigText::add_fallback_map), and recomputes the supported
+    /// characters and the font width.
+    fn refresh_supported_characters(&mut self) {
+        let primary: Box<dyn GlyphProvider> = match &self.glyph_provider {
+            Some(provider) if self.glyph_overrides.is_empty() => Box::new(Arc::clone(provider)),
+            Some(provider) => Box::new(Overridden {
+                overrides: Arc::clone(&self.glyph_overrides),
+                provider: Arc::clone(provider),
+            }),
+            None => Box::new(Arc::clone(&self.character_map)),
+        };
+        let mut lookup = Fallback::new(vec![primary]);
+        for map in &self.fallback_maps {
+            lookup.push(Box::new(Arc::clone(map)));
+        }
+
+        // Providers that can't list their characters are assumed to cover the
+        // `character_map`
+        let mut supported_characters: BTreeSet<char> = match lookup.characters() {
+            Some(characters) => characters.into_iter().collect(),
+            None => self
+                .fallback_maps
+                .iter()
+                .chain([&self.character_map])
+                .flat_map(|map| map.keys().copied())
+                .collect(),
+        };
+        // Removed characters can still be supported by the fallback maps
+        for (c, glyph) in self.glyph_overrides.iter() {
+            if glyph.is_some() {
+                supported_characters.insert(*c);
+            } else if lookup.glyph(*c).is_none() {
+                supported_characters.remove(c);
+            }
+        }
+
+        // Measuring the glyphs that are printed, from the provider and the fallback maps
+        self.font_width = Self::typical_width(
+            supported_characters
+                .iter()
+                .filter_map(|c| lookup.glyph(*c).map(|glyph| (*c, glyph))),
+        );
+        self.supported_characters = supported_characters.into_iter().collect();
+        self.lookup = lookup;
+    }
+
+    /// Adds a map looked up for characters missing from the `character_map` (or the
+    /// [glyph provider](BigText::set_glyph_provider)) and the fallback maps added
+    /// before it, so a decorative font can be backed by a plain one.
+    ///
+    /// The maps are chained after the provider with a [Fallback], the same way a
+    /// provider chains its own fonts.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{character_maps, BigText};
+    ///
+    /// let mut printer = BigText::new("A1", Some(character_maps::ascii_letters()));
+    /// printer.add_fallback_map(character_maps::digits());
+    /// assert_eq!(BigText::new("A1", None).to_string(), printer.to_string());
+    /// assert!(printer.supported_characters().contains('1'));
+    /// ```
+    pub fn add_fallback_map(&mut self, map: impl Into<Arc<CharacterMap>>) -> &mut Self {
+        self.fallback_maps.push(map.into());
+        self.refresh_supported_characters();
+        self
+    }
+
+    /// Gets the fallback maps, in the order they are looked up in.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// assert!(BigText::new("HI", None).fallback_maps().is_empty());
+    /// ```
+    pub fn fallback_maps(&self) -> &[Arc<CharacterMap>] {
+        &self.fallback_maps
+    }
+
+    /// Removes all the fallback maps.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{character_maps, BigText};
+    ///
+    /// let mut printer = BigText::new("1", Some(character_maps::ascii_letters()));
+    /// printer.add_fallback_map(character_maps::digits()).clear_fallback_maps();
+    /// assert!(!printer.supported_characters().contains('1'));
+    /// ```
+    pub fn clear_fallback_maps(&mut self) -> &mut Self {
+        self.fallback_maps.clear();
+        self.refresh_supported_characters();
+        self
+    }
+
+    /// Gets the `character_map` to use when printing.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{character_maps, BigText};
+    /// use std::collections::HashMap;
+    ///
+    /// let printer = BigText::new("", None);
+    /// assert_eq!(&character_maps::printables(), printer.character_map());
+    /// ```
+    pub fn character_map(&self) -> &CharacterMap {
+        &self.character_map
+    }
+
+    /// Gets the shared `character_map`, to use it in other [BigText]s without copying
+    /// it.
+    ///
+    /// [BigText]s created without a map all share the same
+    /// [printables](character_maps::printables()) map.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    /// use std::sync::Arc;
+    ///
+    /// let first = BigText::new("A", None);
+    /// let second = BigText::new("B", None);
+    /// assert!(Arc::ptr_eq(first.shared_character_map(), second.shared_character_map()));
+    /// ```
+    pub fn shared_character_map(&self) -> &Arc<CharacterMap> {
+        &self.character_map
+    }
+
+    /// Sets the [GlyphProvider] the glyphs are looked up in instead of the
+    /// `character_map`, [None] to use the `character_map` again. The
+    /// [fallback maps](BigText::add_fallback_map) are still looked up after it.
+    ///
+    /// The [supported characters](BigText::supported_characters) are the
+    /// [characters](GlyphProvider::characters) of the provider and the fallback maps,
+    /// or the ones of the `character_map` if the provider can't list them.
+    ///
+    /// The glyphs [set](BigText::set_glyph) and [removed](BigText::remove_glyph)
+    /// while the previous provider was set are dropped.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{character_maps::{self, Fallback}, BigText};
+    /// use std::sync::Arc;
+    ///
+    /// let mut printer = BigText::new("A1", Some(character_maps::ascii_letters()));
+    /// let fallback = Fallback::new(vec![
+    ///     Box::new(character_maps::ascii_letters()),
+    ///     Box::new(character_maps::digits()),
+    /// ]);
+    /// printer.set_glyph_provider(Some(Arc::new(fallback)));
+    /// assert_eq!(BigText::new("A1", None).to_string(), printer.to_string());
+    /// ```
+    pub fn set_glyph_provider(&mut self, provider: Option<Arc<dyn GlyphProvider>>) -> &mut Self {
+        self.glyph_provider = provider;
+        self.glyph_overrides = Arc::default();
+        self.refresh_supported_characters();
+        self
+    }
+
+    /// Gets the [GlyphProvider] the glyphs are looked up in, if one is set.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// let printer = BigText::new("", None);
+    /// assert!(printer.glyph_provider().is_none());
+    /// ```
+    pub fn glyph_provider(&self) -> Option<&Arc<dyn GlyphProvider>> {
+        self.glyph_provider.as_ref()
+    }
+
+    /// Sets the glyphs of sequences of characters, such as emoji ZWJ sequences and
+    /// ligatures.
+    ///
+    /// Sequences are matched before the single characters, preferring the longest
+    /// sequence at every position. Zero-width characters such as joiners are part of
+    /// the sequences, so they aren't skipped within them.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{character_maps::SequenceMap, BigText};
+    ///
+    /// let glyph = ["fi".into(), "fi".into(), "fi".into(), "fi".into(), "fi".into()];
+    /// let mut printer = BigText::new("FIX", None);
+    /// printer.set_sequence_map(SequenceMap::from([(String::from("FI"), glyph)]));
+    /// assert!(printer.to_string().starts_with("fi *   * \n"));
+    /// ```
+    pub fn set_sequence_map(&mut self, sequence_map: impl Into<Arc<SequenceMap>>) -> &mut Self {
+        self.sequence_map = sequence_map.into();
+        self
+    }
+
+    /// Sets the [baselines and advance widths](GlyphMetrics) of the characters, for
+    /// fonts with descenders and proportional spacing.
+    ///
+    /// Glyphs with a baseline above the bottom row are moved down, adding rows below
+    /// the line for their descenders.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{
+    ///     character_maps::{self, GlyphMetrics, MetricsMap},
+    ///     BigText,
+    /// };
+    ///
+    /// let mut printer = BigText::new("AI", None);
+    /// printer.set_glyph_metrics(MetricsMap::from([
+    ///     ('A', GlyphMetrics { baseline: Some(3), ..Default::default() }),
+    ///     ('I', GlyphMetrics { advance: Some(3), ..Default::default() }),
+    /// ]));
+    ///
+    /// let rows: Vec<String> = printer.to_string().lines().map(String::from).collect();
+    /// assert_eq!(6, rows.len());
+    /// assert_eq!("      *** ", rows[0]);
+    /// assert_eq!(" ***    * ", rows[1]);
+    /// ```
+    pub fn set_glyph_metrics(&mut self, glyph_metrics: impl Into<Arc<MetricsMap>>) -> &mut Self {
+        self.glyph_metrics = glyph_metrics.into();
+        self
+    }
+
+    /// Gets the baselines and advance widths of the characters.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// assert!(BigText::new("", None).glyph_metrics().is_empty());
+    /// ```
+    pub fn glyph_metrics(&self) -> &MetricsMap {
+        &self.glyph_metrics
+    }
+
+    /// Gets the metrics of a character, the defaults for characters without metrics.
+    fn metrics(&self, c: char) -> GlyphMetrics {
+        self.glyph_metrics.get(&c).copied().unwrap_or_default()
+    }
+
+    /// Gets the glyphs of sequences of characters.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// assert!(BigText::new("", None).sequence_map().is_empty());
+    /// ```
+    pub fn sequence_map(&self) -> &SequenceMap {
+        &self.sequence_map
+    }
+
+    /// Looks up the glyph of a character in the lookup chain, the provider (or the
+    /// `character_map` if no provider is set) followed by the fallback maps.
+    ///
+    /// Missing characters are looked up again in the opposite case when the
+    /// [case fallback](BigText::set_case_fallback) is enabled.
+    fn glyph(&self, c: char) -> Option<&Glyph> {
+        self.lookup.glyph(c).or_else(|| match self.case_fallback {
+            true => opposite_case(c).and_then(|c| self.lookup.glyph(c)),
+            false => None,
+        })
+    }
+
+    /// Sets whether missing characters are looked up again in the opposite case,
+    /// so lowercase text is printed with the uppercase glyphs of the default font.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// let mut printer = BigText::new("hi", None);
+    /// printer.set_case_fallback(true);
+    /// assert_eq!(BigText::new("HI", None).to_string(), printer.to_string());
+    /// ```
+    pub fn set_case_fallback(&mut self, case_fallback: bool) -> &mut Self {
+        self.case_fallback = case_fallback;
+        self
+    }
+
+    /// Gets whether missing characters are looked up again in the opposite case.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// assert!(!BigText::new("hi", None).case_fallback());
+    /// ```
+    pub fn case_fallback(&self) -> bool {
+        self.case_fallback
+    }
+
+    /// Sets whether the text is converted to uppercase before its characters are
+    /// looked up, so lowercase text is printed with the uppercase-only default font.
+    ///
+    /// Unlike the [case fallback](BigText::set_case_fallback), lowercase glyphs of the
+    /// font are never used and characters like `ß` are converted to `SS`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// let mut printer = BigText::new("hello", None);
+    /// printer.set_auto_uppercase(true);
+    /// assert_eq!(BigText::new("HELLO", None).to_string(), printer.to_string());
+    /// ```
+    pub fn set_auto_uppercase(&mut self, auto_uppercase: bool) -> &mut Self {
+        self.auto_uppercase = auto_uppercase;
+        self
+    }
+
+    /// Gets whether the text is converted to uppercase before its characters are
+    /// looked up.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// assert!(!BigText::new("hello", None).auto_uppercase());
+    /// ```
+    pub fn auto_uppercase(&self) -> bool {
+        self.auto_uppercase
+    }
+
+    /// Replaces a character by another one before looking it up, for stylized output
+    /// such as leetspeak without new fonts.
+    ///
+    /// The text is substituted after [normalization](BigText::set_normalization) and
+    /// [uppercasing](BigText::set_auto_uppercase), so [sequences](BigText::set_sequence_map),
+    /// metrics and [missing characters](BigText::chars_missing) see the substituted
+    /// characters.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// let mut printer = BigText::new("HELLO", None);
+    /// printer.set_substitution('E', '3').set_substitution('O', '0');
+    /// assert_eq!(BigText::new("H3LL0", None).to_string(), printer.to_string());
+    /// ```
+    pub fn set_substitution(&mut self, from: char, to: char) -> &mut Self {
+        self.substitutions.insert(from, to);
+        self
+    }
+
+    /// Replaces all the substitutions, such as the [LEETSPEAK](normalize::LEETSPEAK)
+    /// ones.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// let mut printer = BigText::new("AB", None);
+    /// printer.set_substitutions([('A', '4')]);
+    /// assert_eq!(BigText::new("4B", None).to_string(), printer.to_string());
+    /// printer.set_substitutions([]);
+    /// assert_eq!(BigText::new("AB", None).to_string(), printer.to_string());
+    /// ```
+    pub fn set_substitutions(
+        &mut self,
+        substitutions: impl IntoIterator<Item = (char, char)>,
+    ) -> &mut Self {
+        self.substitutions = substitutions.into_iter().collect();
+        self
+    }
+
+    /// Gets the characters that are replaced by other characters before looking them
+    /// up.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// let mut printer = BigText::new("", None);
+    /// printer.set_substitution('E', '3');
+    /// assert_eq!(Some(&'3'), printer.substitutions().get(&'E'));
+    /// ```
+    pub fn substitutions(&self) -> &HashMap<char, char> {
+        &self.substitutions
+    }
+
+    /// Sets the pattern used to vertically offset each character.
+    ///
+    /// Characters that are shifted down make the output taller than the usual 5
+    /// rows, the space around them is filled with blanks.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{layout::OffsetPattern, BigText};
+    ///
+    /// let mut printer = BigText::new("11", None);
+    /// printer.set_offset_pattern(OffsetPattern::Alternating(1));
+    ///
+    /// assert_eq!(
+    ///     "    *       \n    *     * \n    *     * \n    *     * \n    *     * \n          * \n",
+    ///     printer.to_string()
+    /// );
+    /// ```
+    pub fn set_offset_pattern(&mut self, offset_pattern: OffsetPattern) -> &mut Self {
+        self.offset_pattern = offset_pattern;
+        self
+    }
+
+    /// Gets the pattern used to vertically offset each character.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{layout::OffsetPattern, BigText};
+    ///
+    /// let printer = BigText::new("HI", None);
+    /// assert_eq!(&OffsetPattern::None, printer.offset_pattern());
+    /// ```
+    pub fn offset_pattern(&self) -> &OffsetPattern {
+        &self.offset_pattern
+    }
+
+    /// Sets the function called for every zero-width character in the text.
+    ///
+    /// Zero-width joiners, variation selectors and combining marks don't take up a
+    /// column of their own, they are attached to the character before them and are
+    /// not printed. The hook receives the skipped character and its position (in
+    /// [char]s) in the text, allowing warnings to be emitted.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// let mut printer = BigText::new("E\u{301}", None);
+    /// printer.set_zero_width_hook(Some(|c, position| {
+    ///     eprintln!("skipped {:?} at {}", c, position);
+    /// }));
+    ///
+    /// assert_eq!(BigText::new("E", None).to_string(), printer.to_string());
+    /// ```
+    pub fn set_zero_width_hook(&mut self, hook: Option<fn(char, usize)>) -> &mut Self {
+        self.zero_width_hook = hook;
+        self
+    }
+
+    /// Sets the colors used when printing, [None] prints without any colors.
+    ///
+    /// Colors are printed using the escape codes of the
+    /// [color format](BigText::set_color_format), ANSI true color by default.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{
+    ///     color::{ColorSpace, ColorStyle, Gradient, HueDirection, Rgb},
+    ///     BigText,
+    /// };
+    ///
+    /// let mut gradient = Gradient::new(vec![Rgb::new(255, 0, 0), Rgb::new(0, 0, 255)]);
+    /// gradient.space = ColorSpace::Hsl;
+    /// gradient.hue_direction = HueDirection::Longer;
+    ///
+    /// let mut printer = BigText::new("HELLO", None);
+    /// printer.set_color(Some(ColorStyle::Gradient(gradient)));
+    /// println!("{}", printer);
+    /// ```
+    pub fn set_color(&mut self, color: Option<ColorStyle>) -> &mut Self {
+        self.color = color;
+        self
+    }
+
+    /// Gets the colors used when printing.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{color::{ColorStyle, Rgb}, BigText};
+    ///
+    /// let mut printer = BigText::new("HI", None);
+    /// assert_eq!(None, printer.color());
+    ///
+    /// printer.set_color(Some(ColorStyle::Solid(Rgb::WHITE)));
+    /// assert_eq!(Some(&ColorStyle::Solid(Rgb::WHITE)), printer.color());
+    /// ```
+    pub fn color(&self) -> Option<&ColorStyle> {
+        self.color.as_ref()
+    }
+
+    /// Sets the escape codes used to print colors.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{color::{ColorFormat, ColorStyle, Rgb}, BigText};
+    ///
+    /// let mut printer = BigText::new("I", None);
+    /// printer
+    ///     .set_color(Some(ColorStyle::Solid(Rgb::new(255, 0, 0))))
+    ///     .set_color_format(ColorFormat::Irc);
+    ///
+    /// // Red is mIRC color 4
+    /// assert!(printer.to_string().starts_with("\x0304***** \x0f"));
+    /// ```
+    pub fn set_color_format(&mut self, color_format: ColorFormat) -> &mut Self {
+        self.color_format = color_format;
+        self
+    }
+
+    /// Gets the escape codes used to print colors.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{color::ColorFormat, BigText};
+    ///
+    /// let printer = BigText::new("HI", None);
+    /// assert_eq!(ColorFormat::Ansi, printer.color_format());
+    /// ```
+    pub fn color_format(&self) -> ColorFormat {
+        self.color_format
+    }
+
+    /// Sets whether the text blinks, using the ANSI blink escape codes.
+    ///
+    /// Blinking isn't supported by the [IRC](ColorFormat::Irc) format and some
+    /// terminals, [Blink](animation::Blink) animates it instead.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// let mut printer = BigText::new("I", None);
+    /// printer.set_blink(true);
+    /// assert!(printer.to_string().starts_with("\x1b[5m***** \x1b[25m\n"));
+    /// ```
+    pub fn set_blink(&mut self, blink: bool) -> &mut Self {
+        self.blink = blink;
+        self
+    }
+
+    /// Gets whether the text blinks.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// assert!(!BigText::new("HI", None).blink());
+    /// ```
+    pub fn blink(&self) -> bool {
+        self.blink
+    }
+
+    /// Sets the Unicode normalization applied to the text before its characters are
+    /// looked up.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{normalize::Normalization, BigText};
+    ///
+    /// let mut printer = BigText::new("\u{FF28}I", None);
+    /// printer.set_normalization(Normalization::Nfkd);
+    /// assert_eq!(BigText::new("HI", None).to_string(), printer.to_string());
+    /// ```
+    pub fn set_normalization(&mut self, normalization: Normalization) -> &mut Self {
+        self.normalization = normalization;
+        self
+    }
+
+    /// Gets the Unicode normalization applied to the text before its characters are
+    /// looked up.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{normalize::Normalization, BigText};
+    ///
+    /// assert_eq!(Normalization::None, BigText::new("HI", None).normalization());
+    /// ```
+    pub fn normalization(&self) -> Normalization {
+        self.normalization
+    }
+
+    /// Sets whether combining diacritics are removed before the characters are looked
+    /// up, after the [normalization](BigText::set_normalization).
+    ///
+    /// Precomposed characters such as `é` are only stripped when they are decomposed
+    /// by the [Nfkd](Normalization::Nfkd) normalization first.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{normalize::Normalization, BigText};
+    ///
+    /// let mut printer = BigText::new("ÀÉ", None);
+    /// printer
+    ///     .set_normalization(Normalization::Nfkd)
+    ///     .set_strip_diacritics(true);
+    /// assert_eq!(BigText::new("AE", None).to_string(), printer.to_string());
+    /// ```
+    pub fn set_strip_diacritics(&mut self, strip_diacritics: bool) -> &mut Self {
+        self.strip_diacritics = strip_diacritics;
+        self
+    }
+
+    /// Gets whether combining diacritics are removed before the characters are looked
+    /// up.
+    pub fn strip_diacritics(&self) -> bool {
+        self.strip_diacritics
+    }
+
+    /// Sets the amount of blank columns printed after every character.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// let mut printer = BigText::new("11", None);
+    /// printer.set_spacing(0);
+    /// assert!(printer.to_string().starts_with("    *    *\n"));
+    /// ```
+    pub fn set_spacing(&mut self, spacing: usize) -> &mut Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Gets the amount of blank columns printed after every character.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// let printer = BigText::new("HI", None);
+    /// assert_eq!(1, printer.spacing());
+    /// ```
+    pub fn spacing(&self) -> usize {
+        self.spacing
+    }
+
+    /// Sets the amount of columns the blank of an unsupported character takes up,
+    /// excluding spacing. [None] uses the most common width of the glyphs that are
+    /// printed, from the [glyph provider](BigText::set_glyph_provider) or the
+    /// `character_map` and the [fallback maps](BigText::add_fallback_map), so unknown
+    /// characters line up with the font.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// let mut printer = BigText::new("~", None);
+    /// assert_eq!(" ".repeat(6), printer.to_string().lines().next().unwrap());
+    ///
+    /// printer.set_unknown_width(Some(2));
+    /// assert_eq!(" ".repeat(3), printer.to_string().lines().next().unwrap());
+    /// ```
+    pub fn set_unknown_width(&mut self, unknown_width: Option<usize>) -> &mut Self {
+        self.unknown_width = unknown_width;
+        self
+    }
+
+    /// Gets the amount of columns of unsupported characters, [None] when it is
+    /// derived from the font.
+    pub fn unknown_width(&self) -> Option<usize> {
+        self.unknown_width
+    }
+
+    /// Sets the amount of columns the text is wrapped at, [None] to never wrap.
+    ///
+    /// Lines are broken between words when possible. Every line of big text is
+    /// separated from the next by a blank row.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// let mut printer = BigText::new("HI HI", None);
+    /// printer.set_width(Some(20));
+    ///
+    /// let mut expected = BigText::new("HI", None).to_string();
+    /// expected.push('\n');
+    /// expected.push_str(&BigText::new("HI", None).to_string());
+    /// assert_eq!(expected, printer.to_string());
+    /// ```
+    pub fn set_width(&mut self, width: Option<usize>) -> &mut Self {
+        self.width = width;
+        self
+    }
+
+    /// Gets the amount of columns the text is wrapped at.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// let printer = BigText::new("HI", None);
+    /// assert_eq!(None, printer.width());
+    /// ```
+    pub fn width(&self) -> Option<usize> {
+        self.width
+    }
+
+    /// Sets how every line is aligned.
+    ///
+    /// Lines are aligned within the [width](BigText::set_width), or within the widest
+    /// line when there is no width.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{layout::Alignment, BigText};
+    ///
+    /// let mut printer = BigText::new("1", None);
+    /// printer.set_width(Some(10)).set_alignment(Alignment::Right);
+    /// assert!(printer.to_string().starts_with("        * \n"));
+    /// ```
+    pub fn set_alignment(&mut self, alignment: Alignment) -> &mut Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Gets how every line is aligned.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{layout::Alignment, BigText};
+    ///
+    /// let printer = BigText::new("HI", None);
+    /// assert_eq!(Alignment::Left, printer.alignment());
+    /// ```
+    pub fn alignment(&self) -> Alignment {
+        self.alignment
+    }
+
+    /// Sets the order the characters of every line are printed in.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{layout::Direction, BigText};
+    ///
+    /// let mut printer = BigText::new("AB CD", None);
+    /// printer.set_width(Some(18)).set_direction(Direction::RightToLeft);
+    /// assert_eq!(BigText::new("BA\nDC", None).to_string(), printer.to_string());
+    /// ```
+    pub fn set_direction(&mut self, direction: Direction) -> &mut Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Gets the order the characters of every line are printed in.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{layout::Direction, BigText};
+    ///
+    /// assert_eq!(Direction::LeftToRight, BigText::new("HI", None).direction());
+    /// ```
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    /// Sets whether direction-sensitive punctuation such as brackets is mirrored when
+    /// printing [right to left](Direction::RightToLeft), so `(` still opens.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{layout::Direction, BigText};
+    ///
+    /// let mut printer = BigText::new("(A)", None);
+    /// printer
+    ///     .set_direction(Direction::RightToLeft)
+    ///     .set_mirror_punctuation(true);
+    /// assert_eq!(BigText::new("(A)", None).to_string(), printer.to_string());
+    /// ```
+    pub fn set_mirror_punctuation(&mut self, mirror_punctuation: bool) -> &mut Self {
+        self.mirror_punctuation = mirror_punctuation;
+        self
+    }
+
+    /// Gets whether direction-sensitive punctuation is mirrored when printing right
+    /// to left.
+    pub fn mirror_punctuation(&self) -> bool {
+        self.mirror_punctuation
+    }
+
+    /// Sets the characters printed at the end of every row, such as `\r\n` for
+    /// output embedded in Windows files.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{layout::LineEnding, BigText};
+    ///
+    /// let mut printer = BigText::new("HI", None);
+    /// printer.set_line_ending(LineEnding::CrLf);
+    /// assert_eq!(
+    ///     BigText::new("HI", None).to_string().replace('\n', "\r\n"),
+    ///     printer.to_string()
+    /// );
+    /// ```
+    pub fn set_line_ending(&mut self, line_ending: LineEnding) -> &mut Self {
+        self.line_ending = line_ending;
+        self
+    }
+
+    /// Gets the characters printed at the end of every row.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{layout::LineEnding, BigText};
+    ///
+    /// assert_eq!(LineEnding::Lf, BigText::new("HI", None).line_ending());
+    /// ```
+    pub fn line_ending(&self) -> LineEnding {
+        self.line_ending
+    }
+
+    /// Sets whether the last row is followed by a line ending, disable it where an
+    /// extra blank line is unwanted.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// let mut printer = BigText::new("HI", None);
+    /// printer.set_trailing_newline(false);
+    /// assert!(!printer.to_string().ends_with('\n'));
+    /// assert_eq!(4, printer.to_string().matches('\n').count());
+    /// ```
+    pub fn set_trailing_newline(&mut self, trailing_newline: bool) -> &mut Self {
+        self.trailing_newline = trailing_newline;
+        self
+    }
+
+    /// Gets whether the last row is followed by a line ending.
+    pub fn trailing_newline(&self) -> bool {
+        self.trailing_newline
+    }
+
+    /// Sets the text printed before every row, such as `// ` to drop the output into
+    /// a source code comment.
+    ///
+    /// The prefix isn't colored and doesn't count towards the
+    /// [width](BigText::set_width).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// let mut printer = BigText::new("HI", None);
+    /// printer.set_line_prefix("// ");
+    /// assert!(printer.to_string().lines().all(|row| row.starts_with("// ")));
+    /// ```
+    pub fn set_line_prefix(&mut self, prefix: &str) -> &mut Self {
+        self.line_prefix = String::from(prefix);
+        self
+    }
+
+    /// Gets the text printed before every row.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// assert_eq!("", BigText::new("HI", None).line_prefix());
+    /// ```
+    pub fn line_prefix(&self) -> &str {
+        &self.line_prefix
+    }
+
+    /// Sets the text printed after every row, such as ` *` to close a box.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// let mut printer = BigText::new("I", None);
+    /// printer.set_line_prefix("* ").set_line_suffix(" *");
+    /// assert_eq!(Some("* *****  *"), printer.to_string().lines().next());
+    /// ```
+    pub fn set_line_suffix(&mut self, suffix: &str) -> &mut Self {
+        self.line_suffix = String::from(suffix);
+        self
+    }
+
+    /// Gets the text printed after every row.
+    pub fn line_suffix(&self) -> &str {
+        &self.line_suffix
+    }
+
+    /// Adds an [Effect] applied to the rendered text after all the current ones.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{effects::{Flip, Shadow}, BigText};
+    ///
+    /// let mut printer = BigText::new("1", None);
+    /// printer.with_effect(Shadow).with_effect(Flip);
+    /// assert_eq!(2, printer.effects().len());
+    /// assert_eq!(6, printer.to_string().lines().count());
+    /// ```
+    pub fn with_effect(&mut self, effect: impl Effect + 'static) -> &mut Self {
+        self.effects.push(Arc::new(effect));
+        self
+    }
+
+    /// Gets the [Effect]s applied to the rendered text, in order.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// let printer = BigText::new("HI", None);
+    /// assert!(printer.effects().is_empty());
+    /// ```
+    pub fn effects(&self) -> &[Arc<dyn Effect>] {
+        &self.effects
+    }
+
+    /// Removes all the [Effect]s.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{effects::Invert, BigText};
+    ///
+    /// let mut printer = BigText::new("HI", None);
+    /// printer.with_effect(Invert).clear_effects();
+    /// assert_eq!(BigText::new("HI", None).to_string(), printer.to_string());
+    /// ```
+    pub fn clear_effects(&mut self) -> &mut Self {
+        self.effects.clear();
+        self
+    }
+}
+
+/// The errors returned by the strict [BigText::try_print] and [BigText::try_render].
+#[derive(Debug)]
+pub enum RenderError {
+    /// The text contains characters the font doesn't support, with their positions.
+    Unsupported(Vec<(usize, char)>),
+    /// The output couldn't be written.
+    Io(Error),
+}
+
+impl Display for RenderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unsupported(chars) => {
+                write!(f, "unsupported characters")?;
+                for (index, (position, c)) in chars.iter().enumerate() {
+                    let separator = if index == 0 { ": " } else { ", " };
+                    write!(f, "{separator}{c:?} at {position}")?;
+                }
+                Ok(())
+            }
+            Self::Io(error) => write!(f, "failed to write output: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for RenderError {}
+
+/// A character of a line resolved to its glyph, so the map is only looked up once
+/// per character when rendering.
+#[derive(Debug, Clone, Copy)]
+struct Cell<'a> {
+    /// The character.
+    c: char,
+    /// The position of the character within the prepared text, the first one for
+    /// sequences.
+    index: usize,
+    /// The glyph of the character, [None] for unsupported characters.
+    glyph: Option<&'a Glyph>,
+    /// The amount of columns the character takes up, including the spacing after it.
+    width: usize,
+    /// The amount of rows the glyph is moved down to line up its baseline.
+    drop: usize,
+    /// The amount of columns the rows of the glyph are padded or cut to.
+    advance: Option<usize>,
+}
+
+/// The glyphs set and removed while a [GlyphProvider] is set, [None] for removed
+/// characters.
+type GlyphOverrides = HashMap<char, Option<Glyph>>;
+
+/// A [GlyphProvider] looking up the overrides before the provider.
+#[derive(Debug)]
+struct Overridden {
+    /// The glyphs looked up first.
+    overrides: Arc<GlyphOverrides>,
+    /// The provider looked up for the characters without overrides.
+    provider: Arc<dyn GlyphProvider>,
+}
+
+impl GlyphProvider for Overridden {
+    fn glyph(&self, c: char) -> Option<&Glyph> {
+        match self.overrides.get(&c) {
+            Some(glyph) => glyph.as_ref(),
+            None => self.provider.glyph(c),
+        }
+    }
+
+    fn characters(&self) -> Option<Vec<char>> {
+        let mut characters: BTreeSet<char> = self.provider.characters()?.into_iter().collect();
+        for (c, glyph) in self.overrides.iter() {
+            match glyph {
+                Some(_) => characters.insert(*c),
+                None => characters.remove(c),
+            };
+        }
+        Some(characters.into_iter().collect())
+    }
+}
+
+/// Joins the rendered rows of lines, separating them by a blank row.
+fn separate_lines(lines: impl Iterator<Item = Vec<String>>) -> impl Iterator<Item = String> {
+    lines
+        .enumerate()
+        .flat_map(|(index, rows)| (index > 0).then(String::new).into_iter().chain(rows))
+}
+
+/// Pads `part` with spaces or cuts it to `columns` columns.
+fn fit(part: &str, columns: usize) -> Cow<'_, str> {
+    let width = display_width(part);
+    if width == columns {
+        return Cow::Borrowed(part);
+    }
+    if width < columns {
+        return Cow::Owned(format!("{part}{}", " ".repeat(columns - width)));
+    }
+
+    let mut fitted = String::with_capacity(part.len());
+    let mut used = 0;
+    for c in part.chars() {
+        let c_width = c.width().unwrap_or(0);
+        if used + c_width > columns {
+            break;
+        }
+        fitted.push(c);
+        used += c_width;
+    }
+    fitted.extend(std::iter::repeat_n(' ', columns - used));
+
+    Cow::Owned(fitted)
+}
+
+/// Gets the character in the opposite case, [None] for characters without a case or
+/// whose opposite case is more than one character, like `ß`.
+fn opposite_case(c: char) -> Option<char> {
+    let opposite: Vec<char> = match c.is_lowercase() {
+        true => c.to_uppercase().collect(),
+        false => c.to_lowercase().collect(),
+    };
+
+    match opposite[..] {
+        [opposite] if opposite != c => Some(opposite),
+        _ => None,
+    }
+}
+
+/// Gets the amount of terminal columns `text` takes up, counting full-width
+/// characters such as CJK ideographs as two columns.
+pub(crate) fn display_width(text: &str) -> usize {
+    text.width()
+}
+
+/// Checks if a character doesn't take up any space when printed on its own.
+///
+/// This covers zero-width spaces and joiners, variation selectors and combining marks.
+fn is_zero_width(c: char) -> bool {
+    matches!(
+        c,
+        '\u{200B}'..='\u{200F}'
+            | '\u{2060}'..='\u{2064}'
+            | '\u{FEFF}'
+            | '\u{FE00}'..='\u{FE0F}'
+            | '\u{E0100}'..='\u{E01EF}'
+            | '\u{0300}'..='\u{036F}'
+            | '\u{1AB0}'..='\u{1AFF}'
+            | '\u{1DC0}'..='\u{1DFF}'
+            | '\u{20D0}'..='\u{20FF}'
+            | '\u{FE20}'..='\u{FE2F}'
+    )
+}
+
+impl PartialEq for BigText {
+    /// Compares everything that affects the output, the
+    /// [zero-width hooks](BigText::set_zero_width_hook) aren't compared and
+    /// [glyph providers](BigText::set_glyph_provider) and
+    /// [effects](BigText::with_effect) are only equal if they are the same ones.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{character_maps, BigText};
+    ///
+    /// let printer = BigText::new("HI", None);
+    /// assert_eq!(printer, printer.clone());
+    /// assert_ne!(printer, BigText::new("HI", Some(character_maps::ascii_letters())));
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+            && self.character_map == other.character_map
+            && self.fallback_maps == other.fallback_maps
+            && self.sequence_map == other.sequence_map
+            && self.glyph_metrics == other.glyph_metrics
+            && self.case_fallback == other.case_fallback
+            && self.auto_uppercase == other.auto_uppercase
+            && self.substitutions == other.substitutions
+            && match (&self.glyph_provider, &other.glyph_provider) {
+                (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+                (a, b) => a.is_none() && b.is_none(),
+            }
+            && self.glyph_overrides == other.glyph_overrides
+            && self.effects.len() == other.effects.len()
+            && self
+                .effects
+                .iter()
+                .zip(&other.effects)
+                .all(|(a, b)| Arc::ptr_eq(a, b))
+            && self.offset_pattern == other.offset_pattern
+            && self.color == other.color
+            && self.color_format == other.color_format
+            && self.blink == other.blink
+            && self.normalization == other.normalization
+            && self.strip_diacritics == other.strip_diacritics
+            && self.spacing == other.spacing
+            && self.unknown_width == other.unknown_width
+            && self.width == other.width
+            && self.alignment == other.alignment
+            && self.direction == other.direction
+            && self.mirror_punctuation == other.mirror_punctuation
+            && self.line_ending == other.line_ending
+            && self.trailing_newline == other.trailing_newline
+            && self.line_prefix == other.line_prefix
+            && self.line_suffix == other.line_suffix
+    }
+}
+
+impl Default for BigText {
+    /// Creates a [BigText] without text, using the
+    /// [printables](character_maps::printables()) map.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// let printer = BigText::default();
+    /// assert_eq!(BigText::new("", None), printer);
+    /// assert_eq!("", printer.text());
+    /// ```
+    fn default() -> Self {
+        Self::new("", None)
+    }
+}
+
+impl From<&str> for BigText {
+    /// Creates a [BigText] using the [printables](character_maps::printables()) map.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// let printer: BigText = "HI".into();
+    /// assert_eq!(BigText::new("HI", None), printer);
+    /// ```
+    fn from(text: &str) -> Self {
+        Self::new(text, None)
+    }
+}
+
+impl From<String> for BigText {
+    /// Creates a [BigText] using the [printables](character_maps::printables()) map.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// let printer = BigText::from(String::from("HI"));
+    /// assert_eq!("HI", printer.text());
+    /// ```
+    fn from(text: String) -> Self {
+        Self {
+            text,
+            ..Self::default()
+        }
+    }
+}
+
+impl TryFrom<(String, CharacterMap)> for BigText {
+    type Error = fonts::FontError;
+
+    /// Creates a [BigText] using the given map, after
+    /// [validating](fonts::validate_map()) it.
+    ///
+    /// Maps with [errors](fonts::Severity::Error), such as glyphs with rows of
+    /// different widths, are rejected.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{character_maps, BigText};
+    ///
+    /// let printer = BigText::try_from((String::from("42"), character_maps::digits())).unwrap();
+    /// assert_eq!("42", printer.text());
+    ///
+    /// let mut map = character_maps::digits();
+    /// map.get_mut(&'4').unwrap()[0] = "*".into();
+    /// assert!(BigText::try_from((String::from("42"), map)).is_err());
+    /// ```
+    fn try_from((text, character_map): (String, CharacterMap)) -> Result<Self, Self::Error> {
+        let errors: Vec<fonts::Diagnostic> = fonts::validate_map(&character_map)
+            .into_iter()
+            .filter(|diagnostic| diagnostic.severity == fonts::Severity::Error)
+            .collect();
+        if !errors.is_empty() {
+            return Err(fonts::FontError::Invalid(errors));
+        }
+
+        Ok(Self {
+            text,
+            ..Self::new("", Some(character_map))
+        })
+    }
+}
+
+impl Display for BigText {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.output())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_supported_characters() {
+        let map = HashMap::from([
+            ('A', ["".into(), "".into(), "".into(), "".into(), "".into()]),
+            ('1', ["".into(), "".into(), "".into(), "".into(), "".into()]),
+            ('"', ["".into(), "".into(), "".into(), "".into(), "".into()]),
+        ]);
+
+        let printer = BigText::new("", Some(map));
+        assert_eq!("\"1A", printer.supported_characters());
+    }
+
+    #[test]
+    fn zero_width() {
+        assert!(is_zero_width('\u{200D}'));
+        assert!(is_zero_width('\u{FE0F}'));
+        assert!(is_zero_width('\u{0301}'));
+        assert!(!is_zero_width('A'));
+        assert!(!is_zero_width(' '));
+    }
+
+    #[test]
+    fn visible_chars() {
+        let printer = BigText::new("", None);
+        assert_eq!(vec!['A', 'B'], printer.visible_chars("A\u{200D}B\u{FE0F}"));
+    }
+
+    #[test]
+    fn opposite_cases() {
+        assert_eq!(Some('H'), opposite_case('h'));
+        assert_eq!(Some('h'), opposite_case('H'));
+        assert_eq!(Some('Σ'), opposite_case('σ'));
+        assert_eq!(None, opposite_case('ß'));
+        assert_eq!(None, opposite_case('1'));
+    }
+
+    #[test]
+    fn full_width() {
+        let printer = BigText::new("", None);
+        let cells = printer.cells(&['A', '日']);
+        assert_eq!(6, cells[0].width);
+        assert_eq!(11, cells[1].width);
+        assert_eq!(4, display_width("日本"));
+
+        let mut printer = BigText::new("A日", None);
+        printer.set_width(Some(12));
+        assert_eq!(11, printer.to_string().lines().count());
+    }
+
+    #[test]
+    fn full_width_glyphs() {
+        let glyph = [
+            "漢漢".into(),
+            "漢漢".into(),
+            "漢漢".into(),
+            "漢漢".into(),
+            "漢漢".into(),
+        ];
+        let mut printer = BigText::new("X", Some(HashMap::from([('X', glyph)])));
+        printer.set_width(Some(11)).set_alignment(Alignment::Right);
+        assert!(printer.to_string().starts_with("      漢漢 \n"));
+    }
+
+    #[test]
+    fn unknown_width_from_font() {
+        let glyph = |row: &'static str| [0; GLYPH_HEIGHT].map(|_| Cow::Borrowed(row));
+        let map = HashMap::from([
+            ('A', glyph("***")),
+            ('B', glyph("***")),
+            ('漢', glyph("漢漢漢")),
+        ]);
+        assert_eq!(
+            3,
+            BigText::typical_width(map.iter().map(|(c, glyph)| (*c, glyph)))
+        );
+        assert_eq!(UNKNOWN_WIDTH, BigText::typical_width([]));
+
+        let printer = BigText::new("", Some(map));
+        assert_eq!(4, printer.cells(&['~'])[0].width);
+        assert_eq!(7, printer.cells(&['日'])[0].width);
+    }
+
+    #[test]
+    fn substituted_text() {
+        let glyph = [
+            "fi".into(),
+            "fi".into(),
+            "fi".into(),
+            "fi".into(),
+            "fi".into(),
+        ];
+        let mut printer = BigText::new("fx@", None);
+        printer
+            .set_auto_uppercase(true)
+            .set_substitution('X', 'I')
+            .set_substitution('@', '~')
+            .set_sequence_map(SequenceMap::from([(String::from("FI"), glyph)]));
+
+        assert!(printer.to_string().starts_with("fi       \n"));
+        assert_eq!(vec!['~'], printer.chars_missing());
+    }
+
+    #[test]
+    fn substituted_once() {
+        let glyph = ["".into(), "".into(), "".into(), "".into(), "".into()];
+        let mut printer = BigText::new("A", None);
+        printer
+            .set_substitution('A', 'B')
+            .set_substitution('B', 'C')
+            .set_sequence_map(SequenceMap::from([(String::from("ZZ"), glyph)]));
+        let expected = BigText::new("B", None);
+
+        assert_eq!(expected.to_string(), printer.to_string());
+        assert_eq!(
+            expected.typewriter(animation::RevealUnit::Character),
+            printer.typewriter(animation::RevealUnit::Character)
+        );
+        assert_eq!(
+            expected.to_markdown_blocks(1000),
+            printer.to_markdown_blocks(1000)
+        );
+    }
+
+    #[test]
+    fn unknown_width_from_provider() {
+        let glyph = |row: &'static str| [0; GLYPH_HEIGHT].map(|_| Cow::Borrowed(row));
+        let wide: CharacterMap = HashMap::from([('A', glyph("*******")), ('B', glyph("*******"))]);
+        let mut printer = BigText::new("", Some(HashMap::from([('A', glyph("*"))])));
+        assert_eq!(1, printer.cells(&['~'])[0].width - 1);
+
+        // The glyphs of the provider are the ones printed
+        printer.set_glyph_provider(Some(Arc::new(wide.clone())));
+        assert_eq!(7, printer.cells(&['~'])[0].width - 1);
+
+        printer
+            .set_glyph_provider(None)
+            .add_fallback_map(wide)
+            .add_fallback_map(HashMap::from([('C', glyph("*******"))]));
+        assert_eq!(7, printer.cells(&['~'])[0].width - 1);
+    }
+
+    #[test]
+    fn unsupported_positions() {
+        let unsupported = |printer: &BigText| match printer.try_render() {
+            Err(RenderError::Unsupported(chars)) => chars,
+            result => panic!("unexpected result: {result:?}"),
+        };
+
+        let mut printer = BigText::new("ﬁ€", None);
+        printer
+            .set_normalization(Normalization::Nfkd)
+            .set_auto_uppercase(true);
+        assert_eq!(vec![(1, '€')], unsupported(&printer));
+
+        let mut printer = BigText::new("Ab", None);
+        printer.set_substitution('A', '€').set_auto_uppercase(true);
+        assert_eq!(vec![(0, 'A')], unsupported(&printer));
+
+        let glyph = ["~".into(), "~".into(), "~".into(), "~".into(), "~".into()];
+        let mut printer = BigText::new("~X~", None);
+        printer.set_sequence_map(SequenceMap::from([(String::from("~X"), glyph)]));
+        assert_eq!(vec![(2, '~')], unsupported(&printer));
+    }
+
+    #[test]
+    fn edited_glyphs_with_provider() {
+        let glyph = |row: &'static str| [0; GLYPH_HEIGHT].map(|_| Cow::Borrowed(row));
+        let provider: CharacterMap = HashMap::from([('A', glyph("*")), ('B', glyph("*"))]);
+        let mut printer = BigText::new("A~", None);
+        printer.set_glyph_provider(Some(Arc::new(provider)));
+
+        printer.set_glyph('~', [0; GLYPH_HEIGHT].map(|_| String::from("##")));
+        assert_eq!("AB~", printer.supported_characters());
+        assert!(printer.to_string().starts_with("* ## \n"));
+
+        assert_eq!(Some(glyph("*")), printer.remove_glyph('A'));
+        assert_eq!("B~", printer.supported_characters());
+        assert!(printer.to_string().starts_with("  ## \n"));
+
+        // The edits are dropped with the provider
+        printer.set_glyph_provider(None);
+        assert!(printer.supported_characters().contains('A'));
+        assert!(!printer.supported_characters().contains('~'));
+    }
+
+    #[test]
+    fn fit_rows() {
+        assert_eq!("ab  ", fit("ab", 4));
+        assert_eq!("ab", fit("abc", 2));
+        assert_eq!("a ", fit("a漢", 2));
     }
 }
@@ -0,0 +1,61 @@
+//! A module containing macros that print [BigText]s like [print!] and [println!].
+
+use crate::BigText;
+use std::fmt::Arguments;
+
+/// Prints formatted text in its ascii-art form to the standard output, with the
+/// default printables map.
+///
+/// Like [print!], the output doesn't end with a newline, so the cursor is left at
+/// the end of the last row. Use [big_println!] to end it with a newline.
+///
+/// # Panics
+///
+/// Panics if writing to the standard output fails, like [print!].
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::big_print;
+///
+/// let score = 42;
+/// big_print!("{score}");
+/// println!(" points");
+/// ```
+#[macro_export]
+macro_rules! big_print {
+    ($($arg:tt)*) => {
+        $crate::__print(::std::format_args!($($arg)*), false)
+    };
+}
+
+/// Prints formatted text in its ascii-art form to the standard output followed by
+/// a newline, with the default printables map.
+///
+/// # Panics
+///
+/// Panics if writing to the standard output fails, like [println!].
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::big_println;
+///
+/// let score = 42;
+/// big_println!("SCORE: {}", score);
+/// ```
+#[macro_export]
+macro_rules! big_println {
+    ($($arg:tt)*) => {
+        $crate::__print(::std::format_args!($($arg)*), true)
+    };
+}
+
+/// Prints the rendered `args`, used by [big_print!] and [big_println!].
+pub fn print(args: Arguments, newline: bool) {
+    let rendered = BigText::new(&args.to_string(), None).to_string();
+
+    if newline {
+        print!("{rendered}");
+    } else {
+        print!("{}", rendered.strip_suffix('\n').unwrap_or(&rendered));
+    }
+}
@@ -1,20 +1,1217 @@
-use print_big_text_rs::BigText;
-use std::env;
+use clap::{
+    builder::PossibleValuesParser, Arg, Args, CommandFactory, Parser, Subcommand, ValueEnum,
+};
+use clap_complete::Shell;
+use print_big_text_rs::{
+    animation::{self, Animation, AnimationSession, Blink, Bounce, MatrixRain, RevealUnit},
+    canvas::Canvas,
+    character_maps::{self, CharacterMap},
+    color::{ColorChoice, ColorStyle, ParseColorError},
+    comment::{self, Language},
+    fonts::{self, FontError, FontFormat, Severity},
+    layout::{self, Alignment, Direction, LineEnding},
+    normalize::{Normalization, LEETSPEAK},
+    spec, BigText, RenderError,
+};
+use serde::Deserialize;
+use std::{
+    env,
+    fs::{self, File},
+    io::{self, BufRead, BufWriter, IsTerminal, Write},
+    path::PathBuf,
+    process::ExitCode,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+use terminal_size::{terminal_size, Height, Width};
+
+/// Prints text in it's ascii-art form.
+#[derive(Parser)]
+#[command(version, about, args_conflicts_with_subcommands = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    print: PrintArgs,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Renders a banner spec file.
+    Render {
+        /// The TOML or JSON spec file to render.
+        #[arg(long)]
+        spec: PathBuf,
+    },
+    /// Lists the available fonts and the amount of characters they support.
+    ListFonts,
+    /// Renders a sample text and the digits in a font.
+    Preview {
+        /// The font to preview, resolved like the --font option.
+        font: String,
+        /// The sample text, a pangram by default.
+        sample: Option<String>,
+        /// The amount of columns to wrap the sample at.
+        #[arg(short, long, default_value_t = 80)]
+        width: usize,
+    },
+    /// Converts a font between the JSON, TOML, FIGlet and template formats.
+    Convert {
+        /// The font to convert, resolved like the --font option.
+        input: String,
+        /// The file to write the converted font to.
+        output: PathBuf,
+        /// The format to convert to, guessed from the output extension by default.
+        #[arg(short, long, value_enum)]
+        to: Option<FormatArg>,
+    },
+    /// Prints the completion script of a shell, completing the names of the fonts
+    /// available when it is generated.
+    Completions {
+        /// The shell to generate the completions for.
+        shell: Shell,
+    },
+    /// Checks a font file for problems, failing if there are errors.
+    Validate {
+        /// The font file to check.
+        path: PathBuf,
+    },
+    /// Reports the Unicode blocks a font covers and the characters of a text file
+    /// missing from it.
+    Coverage {
+        /// The font to report on, resolved like the --font option.
+        font: String,
+        /// A text file whose missing characters are listed.
+        #[arg(long, value_name = "TEXTFILE")]
+        against: Option<PathBuf>,
+    },
+    /// Lists the characters supported by only one of two fonts.
+    Diff {
+        /// The first font, resolved like the --font option.
+        first: String,
+        /// The second font, resolved like the --font option.
+        second: String,
+    },
+}
+
+#[derive(Args)]
+struct PrintArgs {
+    /// The texts to print, each one is printed as its own banner. Lines are read from
+    /// the standard input when no text or "-" is given.
+    text: Vec<String>,
+
+    /// A built-in character map, a font in the font directories or a path to a font
+    /// file (.json, .toml, .flf or .txt). Defaults to the PRINT_BIG_TEXT_FONT
+    /// environment variable or "printables".
+    #[arg(short, long)]
+    font: Option<String>,
+
+    /// A font looked up for the characters missing from the --font, resolved like
+    /// it. Can be given multiple times, the fonts are looked up in order.
+    #[arg(long, value_name = "FONT")]
+    fallback: Vec<String>,
+
+    /// A hex color (#rrggbb), comma separated hex colors for a gradient or "rainbow".
+    /// "always", "never" or "auto" (the default) set when colors are written, auto
+    /// only writes colors to terminals. Can be given twice to set both.
+    #[arg(short, long)]
+    color: Vec<ColorArg>,
+
+    /// The same as --color always, kept for scripts written before --color could
+    /// set when colors are written.
+    #[arg(long, hide = true)]
+    ansi: bool,
+
+    /// The amount of columns to wrap the text at, the terminal width by default. 0
+    /// disables wrapping.
+    #[arg(short, long)]
+    width: Option<usize>,
+
+    /// How lines are aligned within the width, left by default.
+    #[arg(short, long, value_enum)]
+    align: Option<AlignArg>,
+
+    /// The amount of blank columns after every character, 1 by default.
+    #[arg(short, long)]
+    spacing: Option<usize>,
+
+    /// Replaces characters before printing them, as comma separated FROM=TO pairs
+    /// such as "E=3,A=4" or "leet" for leetspeak. Can be given multiple times.
+    #[arg(long, value_name = "SUBST")]
+    subst: Vec<SubstArg>,
+
+    /// Converts the texts to uppercase before printing them.
+    #[arg(short, long)]
+    uppercase: bool,
+
+    /// Prints missing characters with the glyph of the opposite case, such as
+    /// lowercase text with the uppercase glyphs of the default font.
+    #[arg(short, long)]
+    ignore_case: bool,
+
+    /// Fails instead of printing blanks for characters the font doesn't support.
+    #[arg(long)]
+    strict: bool,
+
+    /// Prints the characters of every line from right to left, mirroring brackets,
+    /// for right-to-left scripts such as Hebrew and Arabic.
+    #[arg(long)]
+    rtl: bool,
+
+    /// Ends every row with a carriage return and a line feed, for Windows files.
+    #[arg(long)]
+    crlf: bool,
+
+    /// Doesn't end the last row of the output with a newline.
+    #[arg(long)]
+    no_trailing_newline: bool,
+
+    /// Prints the text before every row, such as "// " for source code comments.
+    #[arg(long, value_name = "TEXT", allow_hyphen_values = true)]
+    line_prefix: Option<String>,
+
+    /// Prints the text after every row.
+    #[arg(long, value_name = "TEXT", allow_hyphen_values = true)]
+    line_suffix: Option<String>,
+
+    /// Writes the output to a file instead of the standard output.
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// Keeps reading lines from the standard input, printing each one as soon as it
+    /// arrives.
+    #[arg(long, conflicts_with = "text")]
+    follow: bool,
+
+    /// Shows the followed banners full screen, keeping the last COUNT of them to
+    /// review with the arrow keys.
+    #[cfg(feature = "crossterm")]
+    #[arg(long, value_name = "COUNT", requires = "follow")]
+    history: Option<usize>,
+
+    /// Scrolls the texts through the width like a marquee at SPEED columns per second
+    /// (10 by default), looping until interrupted.
+    #[arg(long, value_name = "SPEED", num_args = 0..=1, default_missing_value = "10")]
+    scroll: Option<f64>,
+
+    /// Reveals every banner one character (or column) at a time.
+    #[arg(
+        long,
+        value_name = "UNIT",
+        value_enum,
+        num_args = 0..=1,
+        default_missing_value = "character"
+    )]
+    typewriter: Option<RevealArg>,
+
+    /// Centers every banner on the screen, for splash screens. Without a terminal,
+    /// the screen is the width (80 columns by default) by 24 rows.
+    #[arg(
+        long,
+        conflicts_with_all = ["typewriter", "scroll", "matrix", "bounce", "comment"]
+    )]
+    center: bool,
+
+    /// Rains the characters of the font down the screen, revealing the texts in the
+    /// middle of it.
+    #[arg(long, conflicts_with_all = ["scroll", "typewriter"])]
+    matrix: bool,
+
+    /// Bounces the texts around the screen, changing colors on every wall hit, until
+    /// Enter is pressed.
+    #[arg(long, conflicts_with_all = ["scroll", "typewriter", "matrix"])]
+    bounce: bool,
+
+    /// Prints the texts inside a comment of the language, between two rules, for
+    /// banners at the top of source files.
+    #[arg(
+        long,
+        value_name = "LANG",
+        value_enum,
+        conflicts_with_all = ["scroll", "typewriter", "matrix", "bounce", "line_prefix", "line_suffix"]
+    )]
+    comment: Option<CommentArg>,
+
+    /// Makes the texts blink with ANSI escape codes, or by redrawing them until Enter
+    /// is pressed with `software`.
+    #[arg(
+        long,
+        value_name = "MODE",
+        value_enum,
+        num_args = 0..=1,
+        default_missing_value = "ansi",
+        conflicts_with_all = ["scroll", "typewriter", "matrix", "bounce"]
+    )]
+    blink: Option<BlinkArg>,
+
+    /// Normalizes the texts into a Unicode normalization form before looking up the
+    /// characters, nfkd lets accented characters fall back to their base character.
+    #[arg(long, value_name = "FORM", value_enum)]
+    normalize: Option<NormalizeArg>,
+
+    /// Removes combining diacritics before looking up the characters, normalizing
+    /// the texts with nfkd unless --normalize is given.
+    #[arg(long)]
+    strip_diacritics: bool,
+
+    /// The delay between the frames of --typewriter in milliseconds.
+    #[arg(long, default_value_t = 100)]
+    delay: u64,
+
+    /// Prints every supported character of the font instead of the texts.
+    #[arg(long)]
+    list_chars: bool,
+
+    /// Prints the banners as images with the kitty graphics protocol when the
+    /// terminal supports it, as text otherwise.
+    #[cfg(feature = "kitty")]
+    #[arg(
+        long,
+        conflicts_with_all = ["scroll", "typewriter", "center", "matrix", "bounce", "comment", "blink"]
+    )]
+    kitty: bool,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum RevealArg {
+    Character,
+    Column,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum BlinkArg {
+    Ansi,
+    Software,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum NormalizeArg {
+    Nfc,
+    Nfkd,
+}
+
+impl From<NormalizeArg> for Normalization {
+    fn from(value: NormalizeArg) -> Self {
+        match value {
+            NormalizeArg::Nfc => Normalization::Nfc,
+            NormalizeArg::Nfkd => Normalization::Nfkd,
+        }
+    }
+}
+
+impl From<RevealArg> for RevealUnit {
+    fn from(value: RevealArg) -> Self {
+        match value {
+            RevealArg::Character => RevealUnit::Character,
+            RevealArg::Column => RevealUnit::Column,
+        }
+    }
+}
+
+/// A value of the --color option, either when to write colors or the colors.
+#[derive(Clone)]
+enum ColorArg {
+    Choice(ColorChoice),
+    Style(ColorStyle),
+}
+
+impl FromStr for ColorArg {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "always" => Ok(Self::Choice(ColorChoice::Always)),
+            "never" => Ok(Self::Choice(ColorChoice::Never)),
+            "auto" => Ok(Self::Choice(ColorChoice::Auto)),
+            _ => s.parse().map(Self::Style),
+        }
+    }
+}
+
+/// A value of the --subst option, the characters replaced by other characters.
+#[derive(Clone)]
+struct SubstArg(Vec<(char, char)>);
+
+impl FromStr for SubstArg {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "leet" {
+            return Ok(Self(LEETSPEAK.to_vec()));
+        }
+
+        s.split(',')
+            .map(|pair| {
+                let mut chars = pair.chars();
+                match (chars.next(), chars.next(), chars.next(), chars.next()) {
+                    (Some(from), Some('='), Some(to), None) => Ok((from, to)),
+                    _ => Err(format!("invalid substitution {pair:?}, expected FROM=TO")),
+                }
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+/// The defaults of the options, read from the config file.
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    /// The default --font.
+    font: Option<String>,
+    /// The default --color, given before the ones on the command line.
+    color: Option<ConfigColor>,
+    /// The default --align.
+    align: Option<AlignArg>,
+    /// The default --spacing.
+    spacing: Option<usize>,
+    /// Directories searched for fonts before the default font directories.
+    font_dirs: Vec<PathBuf>,
+}
+
+/// The --color values in the config file, either one value or a list of them.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ConfigColor {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl Config {
+    /// Gets the path of the config file, `print-big-text/config.toml` in
+    /// `$XDG_CONFIG_HOME` or `~/.config`.
+    fn path() -> Option<PathBuf> {
+        let non_empty = |name| env::var_os(name).filter(|value| !value.is_empty());
+        let directory = non_empty("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| non_empty("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+        Some(directory.join("print-big-text").join("config.toml"))
+    }
+
+    /// Gets the directories fonts are searched in, the ones in the config file first.
+    fn font_dirs(&self) -> Vec<PathBuf> {
+        let mut dirs = self.font_dirs.clone();
+        dirs.extend(fonts::font_dirs());
+        dirs
+    }
+
+    /// Resolves a font name or path, searching the font directories.
+    fn resolve(&self, name: &str) -> Result<CharacterMap, FontError> {
+        fonts::resolve_in(name, &self.font_dirs())
+    }
+
+    /// Loads the config file, the defaults are used if there is no config file.
+    fn load() -> Result<Self, Box<dyn std::error::Error>> {
+        let path = match Self::path() {
+            Some(path) if path.is_file() => path,
+            _ => return Ok(Self::default()),
+        };
+
+        let data = fs::read_to_string(&path)?;
+        toml::from_str(&data)
+            .map_err(|error| format!("invalid config {}: {error}", path.display()).into())
+    }
+}
+
+#[derive(Clone, Copy, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+enum AlignArg {
+    Left,
+    Center,
+    Right,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum CommentArg {
+    Rust,
+    Python,
+    C,
+    Shell,
+}
+
+impl From<CommentArg> for Language {
+    fn from(value: CommentArg) -> Self {
+        match value {
+            CommentArg::Rust => Language::Rust,
+            CommentArg::Python => Language::Python,
+            CommentArg::C => Language::C,
+            CommentArg::Shell => Language::Shell,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum FormatArg {
+    Json,
+    Toml,
+    Figlet,
+    Template,
+}
+
+impl From<FormatArg> for FontFormat {
+    fn from(value: FormatArg) -> Self {
+        match value {
+            FormatArg::Json => FontFormat::Json,
+            FormatArg::Toml => FontFormat::Toml,
+            FormatArg::Figlet => FontFormat::Figlet,
+            FormatArg::Template => FontFormat::Template,
+        }
+    }
+}
+
+impl From<AlignArg> for Alignment {
+    fn from(value: AlignArg) -> Self {
+        match value {
+            AlignArg::Left => Alignment::Left,
+            AlignArg::Center => Alignment::Center,
+            AlignArg::Right => Alignment::Right,
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = Config::load().and_then(|config| match cli.command {
+        Some(Command::Render { spec }) => render_spec(spec),
+        Some(Command::Preview {
+            font,
+            sample,
+            width,
+        }) => preview(&config, &font, sample.as_deref(), width),
+        Some(Command::Convert { input, output, to }) => convert(&config, &input, output, to),
+        Some(Command::Completions { shell }) => {
+            completions(&config, shell);
+            Ok(())
+        }
+        Some(Command::Validate { path }) => validate(path),
+        Some(Command::Coverage { font, against }) => coverage(&config, &font, against),
+        Some(Command::Diff { first, second }) => diff(&config, &first, &second),
+        Some(Command::ListFonts) => {
+            list_fonts(&config);
+            Ok(())
+        }
+        None => print_texts(cli.print, config),
+    });
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Prints a banner spec file.
+fn render_spec(path: PathBuf) -> Result<(), Box<dyn std::error::Error>> {
+    print!("{}", spec::render_file(path)?);
+    Ok(())
+}
+
+/// Prints the available fonts.
+fn list_fonts(config: &Config) {
+    for font in fonts::list_in(&config.font_dirs()) {
+        println!(
+            "{:<16} {:>4} characters  {}",
+            font.name(),
+            font.character_count(),
+            font.source()
+        );
+    }
+}
+
+/// The text previewed when no sample is given.
+const PANGRAM: &str = "THE QUICK BROWN FOX JUMPS OVER THE LAZY DOG";
+
+/// Prints a sample text and the digits in a font.
+fn preview(
+    config: &Config,
+    font: &str,
+    sample: Option<&str>,
+    width: usize,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut printer = BigText::new(sample.unwrap_or(PANGRAM), Some(config.resolve(font)?));
+    printer.set_width(Some(width));
+
+    let mut stream = io::stdout();
+    printer.print(Some(&mut stream))?;
+    writeln!(stream)?;
+    printer.set_text("0123456789").print(Some(&mut stream))?;
+
+    Ok(())
+}
+
+/// Converts a font into another format.
+fn convert(
+    config: &Config,
+    input: &str,
+    output: PathBuf,
+    to: Option<FormatArg>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let format = match to {
+        Some(format) => format.into(),
+        None => FontFormat::from_path(&output).ok_or_else(|| {
+            format!(
+                "can't guess the format of {}, use --to to set it",
+                output.display()
+            )
+        })?,
+    };
+
+    let map = config.resolve(input)?;
+    fs::write(output, fonts::serialize(&map, format))?;
+    Ok(())
+}
+
+/// Prints the completion script of a shell.
+fn completions(config: &Config, shell: Shell) {
+    let names: Vec<String> = fonts::list_in(&config.font_dirs())
+        .into_iter()
+        .map(|font| String::from(font.name()))
+        .collect();
+    let font = |arg: Arg| arg.value_parser(PossibleValuesParser::new(names.clone()));
+
+    // Mutated positional arguments are moved to the end, so their indexes are kept
+    let mut command = Cli::command()
+        .mut_arg("font", font)
+        .mut_subcommand("preview", |command| {
+            command
+                .mut_arg("font", |arg| font(arg).index(1))
+                .mut_arg("sample", |arg| arg.index(2))
+        })
+        .mut_subcommand("convert", |command| {
+            command
+                .mut_arg("input", |arg| font(arg).index(1))
+                .mut_arg("output", |arg| arg.index(2))
+        });
+    clap_complete::generate(shell, &mut command, "print-big-text-rs", &mut io::stdout());
+}
+
+/// Prints the problems found in a font file.
+fn validate(path: PathBuf) -> Result<(), Box<dyn std::error::Error>> {
+    let diagnostics = fonts::validate_file(&path)?;
+    for diagnostic in &diagnostics {
+        println!("{diagnostic}");
+    }
+
+    let errors = diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.severity == Severity::Error)
+        .count();
+    let warnings = diagnostics.len() - errors;
+    println!("{}: {errors} errors, {warnings} warnings", path.display());
+
+    if errors > 0 {
+        Err(format!("{} is not a valid font", path.display()).into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Prints the Unicode blocks a font covers and the characters of `against` missing
+/// from it as tables.
+fn coverage(
+    config: &Config,
+    font: &str,
+    against: Option<PathBuf>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let map = config.resolve(font)?;
+
+    println!("{:<24} {:>9}", "BLOCK", "COVERED");
+    for block in fonts::coverage(&map) {
+        let covered = format!("{}/{}", block.covered(), block.total());
+        println!("{:<24} {covered:>9}", block.name());
+    }
+
+    let Some(path) = against else {
+        return Ok(());
+    };
+    let text = fs::read_to_string(&path)?;
+    let missing = BigText::new("", Some(map)).chars_missing_in(&text);
+
+    println!();
+    if missing.is_empty() {
+        println!("{} has no missing characters", path.display());
+        return Ok(());
+    }
+    println!("{:<10} {:>5}  CHARACTER", "CODE POINT", "COUNT");
+    for c in missing {
+        let code = format!("U+{:04X}", u32::from(c));
+        println!("{code:<10} {:>5}  {c}", text.matches(c).count());
+    }
+
+    Ok(())
+}
+
+/// Prints the characters supported by only one of the fonts `first` and `second`.
+fn diff(config: &Config, first: &str, second: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let diff = character_maps::coverage_diff(&config.resolve(first)?, &config.resolve(second)?);
+    if diff.is_empty() {
+        println!("{first} and {second} support the same characters");
+        return Ok(());
+    }
+
+    for (font, chars) in [(first, diff.only_first()), (second, diff.only_second())] {
+        if chars.is_empty() {
+            continue;
+        }
+        println!("only in {font} ({} characters):", chars.len());
+        for c in chars {
+            println!("  U+{:04X}  {c}", u32::from(*c));
+        }
+    }
+
+    Ok(())
+}
+
+/// Prints every text as its own banner.
+fn print_texts(args: PrintArgs, config: Config) -> Result<(), Box<dyn std::error::Error>> {
+    let font = args
+        .font
+        .or_else(|| {
+            env::var(fonts::FONT_VAR)
+                .ok()
+                .filter(|name| !name.is_empty())
+        })
+        .or_else(|| config.font.clone());
+    let map: CharacterMap = config.resolve(font.as_deref().unwrap_or("printables"))?;
+    let fallbacks = args
+        .fallback
+        .iter()
+        .map(|font| config.resolve(font))
+        .collect::<Result<Vec<CharacterMap>, _>>()?;
+
+    let mut choice = ColorChoice::Auto;
+    let mut color = None;
+    let config_color = match config.color {
+        Some(ConfigColor::One(value)) => vec![value],
+        Some(ConfigColor::Many(values)) => values,
+        None => Vec::new(),
+    }
+    .iter()
+    .map(|value| value.parse())
+    .collect::<Result<Vec<ColorArg>, ParseColorError>>()?;
+    let ansi = args.ansi.then_some(ColorArg::Choice(ColorChoice::Always));
+    for arg in config_color.into_iter().chain(ansi).chain(args.color) {
+        match arg {
+            ColorArg::Choice(value) => choice = value,
+            ColorArg::Style(value) => color = Some(value),
+        }
+    }
+
+    // Files are never terminals, so they are written as plain text unless forced
+    let is_terminal = args.output.is_none() && io::stdout().is_terminal();
+    let colorize = choice.should_colorize(is_terminal);
+    if !colorize {
+        color = None;
+    }
+
+    let width = match args.width {
+        Some(0) => None,
+        Some(width) => Some(width),
+        None if is_terminal => terminal_size().map(|(Width(width), _)| usize::from(width)),
+        None => None,
+    };
 
-fn main() -> Result<(), std::io::Error> {
     // Intializing the BigText struct
-    let mut printer = BigText::new("", None);
+    let mut printer = BigText::new("", Some(map));
+    printer
+        .set_color(color)
+        .set_width(width)
+        .set_alignment(args.align.or(config.align).unwrap_or(AlignArg::Left).into())
+        .set_spacing(args.spacing.or(config.spacing).unwrap_or(1));
+    printer
+        .set_case_fallback(args.ignore_case)
+        .set_auto_uppercase(args.uppercase)
+        .set_substitutions(args.subst.into_iter().flat_map(|subst| subst.0));
+    for map in fallbacks {
+        printer.add_fallback_map(map);
+    }
+    if args.rtl {
+        printer
+            .set_direction(Direction::RightToLeft)
+            .set_mirror_punctuation(true);
+    }
+    if args.crlf {
+        printer.set_line_ending(LineEnding::CrLf);
+    }
+    printer
+        .set_trailing_newline(!args.no_trailing_newline)
+        .set_line_prefix(args.line_prefix.as_deref().unwrap_or_default())
+        .set_line_suffix(args.line_suffix.as_deref().unwrap_or_default());
+    match (args.normalize, args.strip_diacritics) {
+        (Some(form), strip) => printer
+            .set_normalization(form.into())
+            .set_strip_diacritics(strip),
+        (None, true) => printer
+            .set_normalization(Normalization::Nfkd)
+            .set_strip_diacritics(true),
+        (None, false) => &mut printer,
+    };
+
+    let stream: Box<dyn Write> = match args.output {
+        Some(path) => Box::new(File::create(path)?),
+        // --color already decided if colors are written, the stream only makes them
+        // work on legacy Windows consoles
+        #[cfg(feature = "anstream")]
+        None => Box::new(anstream::AutoStream::new(
+            io::stdout(),
+            anstream::ColorChoice::Always,
+        )),
+        #[cfg(not(feature = "anstream"))]
+        None => Box::new(io::stdout()),
+    };
+    let mut stream = BufWriter::new(stream);
+
+    if args.list_chars {
+        list_chars(&mut printer, &mut stream)?;
+        stream.flush()?;
+        return Ok(());
+    }
+
+    // Reading from the standard input when there is no text
+    let texts = if args.text.is_empty() {
+        vec![String::from("-")]
+    } else {
+        args.text
+    };
+
+    if let Some(speed) = args.scroll {
+        printer.set_text(&joined_texts(texts)?);
+        check_strict(&printer, args.strict)?;
+        return scroll(&printer, width.unwrap_or(80), speed, &mut stream);
+    }
+
+    if let Some(language) = args.comment {
+        printer.set_text(&joined_texts(texts)?);
+        check_strict(&printer, args.strict)?;
+        stream.write_all(comment::comment_banner(&printer, language.into())?.as_bytes())?;
+        stream.flush()?;
+        return Ok(());
+    }
+
+    if args.matrix {
+        printer.set_text(&joined_texts(texts)?);
+        check_strict(&printer, args.strict)?;
+        let mut rain = MatrixRain::new(&printer, width.unwrap_or(80), screen_height(is_terminal));
+        AnimationSession::new(&mut stream)?.run(&mut rain, 30)?;
+        return Ok(());
+    }
+
+    if args.bounce {
+        if texts.iter().any(|text| text == "-") {
+            return Err(
+                "--bounce stops on Enter, the texts can't be read from the standard input".into(),
+            );
+        }
+
+        printer.set_text(&texts.join(" "));
+        check_strict(&printer, args.strict)?;
+        let mut bounce = Bounce::new(&printer, width.unwrap_or(80), screen_height(is_terminal));
+        if !colorize {
+            bounce.set_colors(Vec::new());
+        }
+        return run_until_enter(bounce, &mut stream);
+    }
+
+    match args.blink {
+        Some(BlinkArg::Software) => {
+            if texts.iter().any(|text| text == "-") {
+                return Err(
+                    "--blink software stops on Enter, the texts can't be read from the standard input"
+                        .into(),
+                );
+            }
+
+            printer.set_text(&texts.join("\n"));
+            check_strict(&printer, args.strict)?;
+            let blink = Blink::new(&printer, Duration::from_millis(500));
+            return run_until_enter(blink, &mut stream);
+        }
+        Some(BlinkArg::Ansi) if colorize => {
+            printer.set_blink(true);
+        }
+        Some(BlinkArg::Ansi) | None => {}
+    }
+
+    #[cfg(feature = "crossterm")]
+    if let Some(capacity) = args.history {
+        if !is_terminal {
+            return Err("--history shows the banners full screen, it needs a terminal".into());
+        }
+        return follow_with_history(&mut printer, texts, capacity, args.strict);
+    }
+
+    let mut mode = BannerMode::Plain;
+    if let Some(unit) = args.typewriter {
+        mode = BannerMode::Typewriter(unit.into(), Duration::from_millis(args.delay));
+    }
+    if args.center {
+        let columns = match terminal_size() {
+            Some((Width(columns), _)) if is_terminal => usize::from(columns),
+            _ => width.unwrap_or(80),
+        };
+        mode = BannerMode::Center(columns, screen_height(is_terminal));
+    }
+    // Images are only shown by terminals supporting them
+    #[cfg(feature = "kitty")]
+    if args.kitty && is_terminal && print_big_text_rs::kitty::is_supported() {
+        mode = BannerMode::Image(print_big_text_rs::raster::RasterOptions::terminal());
+    }
+
+    let mut printed = false;
+    for text in texts {
+        if text == "-" {
+            for line in io::stdin().lock().lines() {
+                print_banner(
+                    &mut printer,
+                    &line?,
+                    &mut stream,
+                    &mut printed,
+                    &mode,
+                    args.strict,
+                )?;
+
+                // Showing every line as soon as it arrives
+                if args.follow {
+                    stream.flush()?;
+                }
+            }
+        } else {
+            print_banner(
+                &mut printer,
+                &text,
+                &mut stream,
+                &mut printed,
+                &mode,
+                args.strict,
+            )?;
+        }
+    }
+
+    stream.flush()?;
+    Ok(())
+}
+
+/// Gets the amount of rows of the terminal, 24 when it isn't a terminal.
+fn screen_height(is_terminal: bool) -> usize {
+    match terminal_size() {
+        Some((_, Height(height))) if is_terminal => usize::from(height),
+        _ => 24,
+    }
+}
+
+/// Runs `animation` in the alternate screen until Enter is pressed.
+fn run_until_enter(
+    animation: impl Animation,
+    stream: &mut dyn Write,
+) -> Result<(), Box<dyn std::error::Error>> {
+    // Reading the standard input on another thread, so Enter stops the animation
+    let stop = Arc::new(AtomicBool::new(false));
+    let stdin_stop = Arc::clone(&stop);
+    thread::spawn(move || {
+        let _ = io::stdin().lock().read_line(&mut String::new());
+        stdin_stop.store(true, Ordering::Relaxed);
+    });
+
+    let mut animation = UntilStopped { animation, stop };
+    AnimationSession::new(stream)?.run(&mut animation, 30)?;
+    Ok(())
+}
+
+/// An input of the `--history` view.
+#[cfg(feature = "crossterm")]
+enum HistoryEvent {
+    /// A line to render as the newest banner.
+    Banner(String),
+    /// Arrow up or `k`, showing the previous banner.
+    Previous,
+    /// Arrow down or `j`, showing the next banner.
+    Next,
+    /// End or `G`, following the newest banner again.
+    Live,
+    /// `q` or Ctrl-C.
+    Quit,
+}
+
+/// Restores the terminal from raw mode when dropped.
+#[cfg(feature = "crossterm")]
+struct RawMode;
+
+#[cfg(feature = "crossterm")]
+impl RawMode {
+    fn enable() -> io::Result<Self> {
+        crossterm::terminal::enable_raw_mode()?;
+        Ok(Self)
+    }
+}
+
+#[cfg(feature = "crossterm")]
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = crossterm::terminal::disable_raw_mode();
+    }
+}
+
+/// Shows every text as a full-screen banner as soon as it arrives, keeping the last
+/// `capacity` banners to review with the arrow keys until `q` is pressed.
+///
+/// With `strict`, a line with characters the font doesn't support is an error.
+#[cfg(feature = "crossterm")]
+fn follow_with_history(
+    printer: &mut BigText,
+    texts: Vec<String>,
+    capacity: usize,
+    strict: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    use print_big_text_rs::history::BannerHistory;
+    use std::{io::Read, sync::mpsc};
+
+    let (sender, events) = mpsc::channel();
+    let banners = sender.clone();
+    thread::spawn(move || {
+        for text in texts {
+            let lines: Box<dyn Iterator<Item = String>> = match text.as_str() {
+                "-" => Box::new(io::stdin().lock().lines().map_while(Result::ok)),
+                _ => Box::new(std::iter::once(text)),
+            };
+            for line in lines {
+                if banners.send(HistoryEvent::Banner(line)).is_err() {
+                    return;
+                }
+            }
+        }
+    });
+
+    // The standard input carries the texts, so the keys are read from the terminal
+    let mut keys = File::open("/dev/tty")?;
+    thread::spawn(move || {
+        let mut buffer = [0; 8];
+        while let Ok(read) = keys.read(&mut buffer) {
+            let event = match &buffer[..read] {
+                b"\x1b[A" | b"\x1bOA" | b"k" => HistoryEvent::Previous,
+                b"\x1b[B" | b"\x1bOB" | b"j" => HistoryEvent::Next,
+                b"\x1b[F" | b"\x1bOF" | b"G" => HistoryEvent::Live,
+                [] | b"q" | [3] => HistoryEvent::Quit,
+                _ => continue,
+            };
+            if sender.send(event).is_err() {
+                return;
+            }
+        }
+    });
+
+    let _raw = RawMode::enable()?;
+    let mut session = AnimationSession::stdout()?;
+    let mut history = BannerHistory::new(capacity);
+    for event in events {
+        match event {
+            HistoryEvent::Banner(text) => {
+                printer.set_text(&text);
+                check_strict(printer, strict)?;
+                history.push(printer.rows().collect::<Vec<String>>().join("\n"));
+            }
+            HistoryEvent::Previous => {
+                history.previous();
+            }
+            HistoryEvent::Next => {
+                history.next();
+            }
+            HistoryEvent::Live => history.go_live(),
+            HistoryEvent::Quit => break,
+        }
+
+        // Raw mode doesn't return the cursor to the first column on line feeds
+        let status = match history.is_live() {
+            true => "live, up to review, q to quit",
+            false => "reviewing, down or End to follow, q to quit",
+        };
+        let rows: Vec<String> = history
+            .current()
+            .unwrap_or_default()
+            .lines()
+            .chain(["", status])
+            .map(|row| format!("{row}\r"))
+            .collect();
+        session.draw_rows(&rows)?;
+    }
+
+    Ok(())
+}
+
+/// An [Animation] running until it is stopped from another thread.
+struct UntilStopped<A: Animation> {
+    /// The running animation.
+    animation: A,
+    /// Set when the animation should stop.
+    stop: Arc<AtomicBool>,
+}
+
+impl<A: Animation> Animation for UntilStopped<A> {
+    fn frame(&mut self, t: Duration) -> Canvas {
+        self.animation.frame(t)
+    }
+
+    fn is_finished(&self, t: Duration) -> bool {
+        self.stop.load(Ordering::Relaxed) || self.animation.is_finished(t)
+    }
+}
 
-    // Getting args and removing the first one (program name)
-    let mut args = env::args();
-    args.next();
+/// Joins the texts into a single line, reading the lines of the standard input for
+/// `-`.
+fn joined_texts(texts: Vec<String>) -> io::Result<String> {
+    let mut lines = Vec::new();
+    for text in texts {
+        if text == "-" {
+            for line in io::stdin().lock().lines() {
+                lines.push(line?);
+            }
+        } else {
+            lines.push(text);
+        }
+    }
+
+    Ok(lines.join(" "))
+}
 
-    for i in args {
-        // Printing out the string
-        println!("string=\"{i}\"");
-        // Setting the text and printing the asii-art representation
-        printer.set_text(&i).print(None)?;
+/// Scrolls the text of the printer through `window` columns until interrupted.
+fn scroll(
+    printer: &BigText,
+    window: usize,
+    speed: f64,
+    stream: &mut dyn Write,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if !(speed > 0.0 && speed.is_finite()) {
+        return Err(format!("invalid scroll speed {speed}, expected a positive number").into());
     }
 
+    let marquee = printer.marquee(window);
+    let frames = (0..marquee.frame_count())
+        .cycle()
+        .map(|index| marquee.frame(index));
+    animation::play(frames, stream, Duration::from_secs_f64(1.0 / speed))?;
     Ok(())
 }
+
+/// The groups characters are listed in by `--list-chars`.
+const CHAR_GROUPS: [&str; 5] = [
+    "Uppercase letters",
+    "Lowercase letters",
+    "Digits",
+    "Punctuation",
+    "Other",
+];
+
+/// Gets the index of the [CHAR_GROUPS] a character is listed in.
+fn char_group(c: char) -> usize {
+    if c.is_uppercase() {
+        0
+    } else if c.is_lowercase() {
+        1
+    } else if c.is_numeric() {
+        2
+    } else if c.is_ascii_punctuation() {
+        3
+    } else {
+        4
+    }
+}
+
+/// Prints the supported characters of the font grouped by kind, skipping whitespace.
+fn list_chars(printer: &mut BigText, stream: &mut dyn Write) -> io::Result<()> {
+    let characters: Vec<char> = printer.supported_chars_iter().collect();
+
+    let mut printed = false;
+    for (index, name) in CHAR_GROUPS.iter().enumerate() {
+        let group: String = characters
+            .iter()
+            .filter(|c| char_group(**c) == index && !c.is_whitespace())
+            .collect();
+        if group.is_empty() {
+            continue;
+        }
+
+        if printed {
+            writeln!(stream)?;
+        }
+        writeln!(stream, "{name}:")?;
+        printer.set_text(&group).print(Some(stream))?;
+        printed = true;
+    }
+
+    Ok(())
+}
+
+/// How [print_banner] prints a banner.
+enum BannerMode {
+    /// Printed as text.
+    Plain,
+    /// Revealed in the given units with the given delay between frames.
+    Typewriter(RevealUnit, Duration),
+    /// Centered on a screen of the given columns and rows.
+    Center(usize, usize),
+    /// Printed as an image with the kitty graphics protocol.
+    #[cfg(feature = "kitty")]
+    Image(print_big_text_rs::raster::RasterOptions),
+}
+
+/// Prints a single banner in the given `mode`, separated from the previous one by a
+/// blank line using the line ending of the `printer`.
+///
+/// With `strict`, characters the font doesn't support are an error.
+fn print_banner(
+    printer: &mut BigText,
+    text: &str,
+    stream: &mut dyn Write,
+    printed: &mut bool,
+    mode: &BannerMode,
+    strict: bool,
+) -> io::Result<()> {
+    // Failing before the separator, so nothing is written for a rejected banner
+    printer.set_text(text);
+    check_strict(printer, strict)?;
+
+    if *printed {
+        // Ending the last row of the previous banner first when it wasn't ended
+        let ending = printer.line_ending().as_str();
+        match printer.trailing_newline() {
+            true => stream.write_all(ending.as_bytes())?,
+            false => stream.write_all(ending.repeat(2).as_bytes())?,
+        }
+    }
+    *printed = true;
+
+    match mode {
+        BannerMode::Plain => printer.print(Some(stream)),
+        BannerMode::Typewriter(unit, delay) => {
+            animation::play(printer.typewriter(*unit), stream, *delay)
+        }
+        BannerMode::Center(columns, rows) => {
+            stream.write_all(layout::center(printer, *columns, *rows).as_bytes())
+        }
+        #[cfg(feature = "kitty")]
+        BannerMode::Image(options) => printer.print_kitty(Some(stream), options),
+    }
+}
+
+/// Fails when `strict` is set and the font doesn't support some characters of the
+/// text of the `printer`, for `--strict`.
+fn check_strict(printer: &BigText, strict: bool) -> io::Result<()> {
+    match strict {
+        true => printer
+            .try_render()
+            .map(|_| ())
+            .map_err(|error| match error {
+                RenderError::Io(error) => error,
+                error => io::Error::other(error),
+            }),
+        false => Ok(()),
+    }
+}
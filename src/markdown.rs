@@ -0,0 +1,166 @@
+//! A module for rendering a [BigText] as Markdown fenced code blocks.
+
+use crate::BigText;
+use std::ops::Range;
+
+impl BigText {
+    /// Renders the stored string inside a Markdown fenced code block.
+    ///
+    /// Nothing inside the block is escaped. The fence is made longer than any run
+    /// of backticks in the ascii-art so it can't be closed early.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// let printer = BigText::new("A", None);
+    /// assert_eq!(
+    ///     "```text\n ***  \n*   * \n***** \n*   * \n*   * \n```\n",
+    ///     printer.to_markdown()
+    /// );
+    /// ```
+    pub fn to_markdown(&self) -> String {
+        fence(&self.render_rows(self.text()))
+    }
+
+    /// Renders the stored string as Markdown fenced code blocks, each at most
+    /// `limit` characters long.
+    ///
+    /// The text is split between characters so that every block fits in the limit,
+    /// which is useful for pasting long banners into places with a message size
+    /// limit. A single character that doesn't fit is still put in its own block.
+    /// Every block is rendered like a text of its own, so the limit takes the
+    /// alignment, wrapping and effects into account.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// let printer = BigText::new("HELLO", None);
+    /// let blocks = printer.to_markdown_blocks(100);
+    ///
+    /// assert_eq!(3, blocks.len());
+    /// assert!(blocks.iter().all(|block| block.chars().count() <= 100));
+    /// ```
+    pub fn to_markdown_blocks(&self, limit: usize) -> Vec<String> {
+        let cells = self.line_cells(&self.prepare(self.text()));
+        let block = |range: Range<usize>| fence(&self.render_cells(&cells[range]));
+        let fits = |block: &str| block.chars().count() <= limit;
+
+        let mut blocks = Vec::new();
+        let mut start = 0;
+        while start < cells.len() {
+            // The blocks already separate the lines
+            if cells[start].c == '\n' {
+                start += 1;
+                continue;
+            }
+
+            // Doubling the block while it fits, so long texts are rendered a
+            // logarithmic amount of times, then narrowing down the longest block
+            let mut fitting = (start + 1, block(start..start + 1));
+            let mut too_long = cells.len() + 1;
+            let mut step = 1;
+            while fitting.0 < cells.len() {
+                let end = (fitting.0 + step).min(cells.len());
+                let candidate = block(start..end);
+                if !fits(&candidate) {
+                    too_long = end;
+                    break;
+                }
+                fitting = (end, candidate);
+                step *= 2;
+            }
+            while too_long - fitting.0 > 1 {
+                let end = (fitting.0 + too_long) / 2;
+                let candidate = block(start..end);
+                match fits(&candidate) {
+                    true => fitting = (end, candidate),
+                    false => too_long = end,
+                }
+            }
+
+            blocks.push(fitting.1);
+            start = fitting.0;
+        }
+
+        blocks
+    }
+}
+
+/// Wraps rows in a fenced code block.
+fn fence(rows: &[String]) -> String {
+    let longest_run = rows
+        .iter()
+        .flat_map(|row| row.split(|c| c != '`'))
+        .map(str::len)
+        .max()
+        .unwrap_or(0);
+    let fence = "`".repeat((longest_run + 1).max(3));
+
+    let mut block = format!("{fence}text\n");
+    for row in rows {
+        block.push_str(row);
+        block.push('\n');
+    }
+    block.push_str(&fence);
+    block.push('\n');
+
+    block
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{character_maps::SequenceMap, effects::Shadow, layout::Alignment};
+
+    #[test]
+    fn blocks_keep_sequences() {
+        let glyph = [
+            "fi".into(),
+            "fi".into(),
+            "fi".into(),
+            "fi".into(),
+            "fi".into(),
+        ];
+        let mut printer = BigText::new("AFIB", None);
+        printer.set_sequence_map(SequenceMap::from([(String::from("FI"), glyph)]));
+
+        // Every block fits a single glyph and the sequence isn't split
+        let blocks = printer.to_markdown_blocks(50);
+        assert_eq!(3, blocks.len());
+        assert!(blocks[1].contains("fi \nfi "));
+        assert!(blocks.iter().all(|block| block.chars().count() <= 50));
+    }
+
+    #[test]
+    fn blocks_fit_layout() {
+        let mut printer = BigText::new("HELLO WORLD\nHI", None);
+        printer
+            .set_width(Some(40))
+            .set_alignment(Alignment::Right)
+            .with_effect(Shadow);
+
+        let blocks = printer.to_markdown_blocks(400);
+        assert!(blocks.len() > 1);
+        assert!(blocks.iter().all(|block| block.chars().count() <= 400));
+
+        // Every character that doesn't fit is still put in its own block
+        assert_eq!(13, printer.to_markdown_blocks(10).len());
+    }
+
+    #[test]
+    fn blocks_match_render() {
+        let printer = BigText::new("HI\nHI", None);
+        assert_eq!(
+            vec![printer.to_markdown()],
+            printer.to_markdown_blocks(1000)
+        );
+    }
+
+    #[test]
+    fn fence_longer_than_backticks() {
+        let rows = vec![String::from("a ```` b")];
+        assert_eq!("`````text\na ```` b\n`````\n", fence(&rows));
+    }
+}
@@ -0,0 +1,91 @@
+//! A module containing the Unicode [Normalization] and the substitutions applied to
+//! the text of a [BigText](crate::BigText) before its characters are looked up.
+
+use std::borrow::Cow;
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+
+/// The Unicode normalization form the text is converted to before rendering.
+///
+/// Decomposing the text lets accented characters fall back to the glyph of their
+/// base character, as the combining diacritics are skipped like every other
+/// zero-width character.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{normalize::Normalization, BigText};
+///
+/// let mut printer = BigText::new("CAFÉ", None);
+/// printer.set_normalization(Normalization::Nfkd);
+/// assert_eq!(BigText::new("CAFE", None).to_string(), printer.to_string());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Normalization {
+    /// The text is rendered as it is.
+    #[default]
+    None,
+    /// Canonical composition, combining base characters and diacritics into
+    /// precomposed characters for fonts that contain them.
+    Nfc,
+    /// Compatibility decomposition, splitting precomposed characters into their
+    /// base character and diacritics, and compatibility characters such as `ﬁ`
+    /// into their plain forms.
+    Nfkd,
+}
+
+/// The substitutions of leetspeak, replacing letters with digits and punctuation
+/// that look like them.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{normalize::LEETSPEAK, BigText};
+///
+/// let mut printer = BigText::new("LEET", None);
+/// printer.set_substitutions(LEETSPEAK);
+/// assert_eq!(BigText::new("1337", None).to_string(), printer.to_string());
+/// ```
+pub const LEETSPEAK: [(char, char); 7] = [
+    ('A', '4'),
+    ('B', '8'),
+    ('E', '3'),
+    ('G', '6'),
+    ('L', '1'),
+    ('O', '0'),
+    ('T', '7'),
+];
+
+/// Normalizes `text` into the given form, removing the combining diacritics when
+/// `strip_diacritics` is set.
+pub(crate) fn normalize(text: &str, form: Normalization, strip_diacritics: bool) -> Cow<'_, str> {
+    let normalized: Cow<str> = match form {
+        Normalization::None => Cow::Borrowed(text),
+        Normalization::Nfc => Cow::Owned(text.nfc().collect()),
+        Normalization::Nfkd => Cow::Owned(text.nfkd().collect()),
+    };
+
+    match strip_diacritics {
+        false => normalized,
+        true => Cow::Owned(
+            normalized
+                .chars()
+                .filter(|c| !is_combining_mark(*c))
+                .collect(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalization_forms() {
+        assert_eq!("é", normalize("e\u{301}", Normalization::Nfc, false));
+        assert_eq!("e\u{301}", normalize("é", Normalization::Nfkd, false));
+        assert_eq!("fi", normalize("ﬁ", Normalization::Nfkd, false));
+        assert_eq!("e", normalize("é", Normalization::Nfkd, true));
+        assert!(matches!(
+            normalize("é", Normalization::None, false),
+            Cow::Borrowed(_)
+        ));
+    }
+}
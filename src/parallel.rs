@@ -0,0 +1,96 @@
+//! A module for rendering very large texts on multiple threads.
+//!
+//! The helpers require the `rayon` feature.
+
+use crate::BigText;
+use rayon::prelude::*;
+
+/// The amount of characters of a line rendered by a single task.
+const CHUNK_SIZE: usize = 256;
+
+impl BigText {
+    /// Renders the stored string like [to_string](ToString::to_string()), splitting
+    /// it into chunks that are rendered in parallel.
+    ///
+    /// Every line is split into chunks of characters, the rows of every chunk are
+    /// rendered on the [rayon] thread pool and joined back together. This only pays
+    /// off for very long texts, such as huge banner files or posters.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// let printer = BigText::new(&"HELLO WORLD ".repeat(100), None);
+    /// assert_eq!(printer.to_string(), printer.par_render());
+    /// ```
+    pub fn par_render(&self) -> String {
+        let lines = self.lines(&self.prepare(self.text()));
+        let width = self.align_width(&lines);
+
+        let rendered: Vec<Vec<String>> = lines
+            .par_iter()
+            .map(|line| {
+                let height = self.line_height(line);
+                let padding = self.padding(line, width);
+                let chunks: Vec<Vec<String>> = line
+                    .par_chunks(CHUNK_SIZE)
+                    .enumerate()
+                    .map(|(index, chunk)| {
+                        // Only the first chunk starts with the padding
+                        let padding = if index == 0 { padding } else { 0 };
+                        self.render_chunk(chunk, index * CHUNK_SIZE, height, padding)
+                    })
+                    .collect();
+
+                // Stitching the rows of the chunks back together
+                chunks
+                    .into_iter()
+                    .reduce(|mut rows, chunk| {
+                        for (row, part) in rows.iter_mut().zip(chunk) {
+                            row.push_str(&part);
+                        }
+                        rows
+                    })
+                    .unwrap_or_else(|| self.render_line(line, padding))
+            })
+            .collect();
+
+        let mut rows = Vec::new();
+        for (index, line) in rendered.into_iter().enumerate() {
+            if index > 0 {
+                rows.push(String::new());
+            }
+            rows.extend(line);
+        }
+        rows = self.apply_effects(rows);
+        if self.is_styled() {
+            rows = self.colorize_rows(&rows, &rows);
+        }
+
+        self.join_rows(rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        color::{ColorStyle, Rgb},
+        layout::{Alignment, OffsetPattern},
+        BigText,
+    };
+
+    #[test]
+    fn par_render_matches() {
+        let text = "ABC DEF GHI ".repeat(60) + "\n\n" + &"1234567890".repeat(30);
+        let mut printer = BigText::new(&text, None);
+        printer
+            .set_alignment(Alignment::Center)
+            .set_offset_pattern(OffsetPattern::Wave { amplitude: 3 });
+        assert_eq!(printer.to_string(), printer.par_render());
+
+        printer
+            .set_width(Some(500))
+            .set_color(Some(ColorStyle::Solid(Rgb::new(0, 255, 0))));
+        assert_eq!(printer.to_string(), printer.par_render());
+    }
+}
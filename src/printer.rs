@@ -0,0 +1,94 @@
+//! A module containing a thread-safe handle for printing [BigText]s.
+
+use crate::{BigText, Stdout};
+use std::{
+    io::{self, Write},
+    sync::{Arc, Mutex, PoisonError},
+};
+
+/// A handle that serializes the printing of [BigText]s from multiple threads.
+///
+/// Every banner is rendered before the lock around the writer is taken, then
+/// written in one go. This keeps the rows of banners printed concurrently from
+/// interleaving with each other. The handle is cheap to clone, every clone writes
+/// to the same writer.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{printer::BigTextPrinter, BigText};
+/// use std::thread;
+///
+/// let printer = BigTextPrinter::stdout();
+///
+/// let handles: Vec<_> = (0..4)
+///     .map(|i| {
+///         let printer = printer.clone();
+///         thread::spawn(move || printer.print(&BigText::new(&i.to_string(), None)))
+///     })
+///     .collect();
+///
+/// for handle in handles {
+///     handle.join().unwrap().unwrap();
+/// }
+/// ```
+#[derive(Debug)]
+pub struct BigTextPrinter<W: Write> {
+    /// The writer shared by every clone of the handle.
+    writer: Arc<Mutex<W>>,
+}
+
+impl<W: Write> Clone for BigTextPrinter<W> {
+    fn clone(&self) -> Self {
+        Self {
+            writer: Arc::clone(&self.writer),
+        }
+    }
+}
+
+impl BigTextPrinter<Stdout> {
+    /// Creates a [BigTextPrinter] writing to the [standard output](Stdout).
+    pub fn stdout() -> Self {
+        Self::new(crate::stdout())
+    }
+}
+
+impl<W: Write> BigTextPrinter<W> {
+    /// Constructor Function for [BigTextPrinter].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{printer::BigTextPrinter, BigText};
+    ///
+    /// let printer = BigTextPrinter::new(Vec::new());
+    /// printer.print(&BigText::new("A", None)).unwrap();
+    ///
+    /// let vec = printer.into_inner().unwrap();
+    /// assert_eq!(" ***  \n*   * \n***** \n*   * \n*   * \n", String::from_utf8(vec).unwrap());
+    /// ```
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer: Arc::new(Mutex::new(writer)),
+        }
+    }
+
+    /// Prints a [BigText] to the shared writer without interleaving with other
+    /// threads.
+    pub fn print(&self, text: &BigText) -> io::Result<()> {
+        let mut rendered = Vec::new();
+        text.print(Some(&mut rendered))?;
+
+        // A panic in another thread doesn't leave the writer in a broken state
+        let mut writer = self.writer.lock().unwrap_or_else(PoisonError::into_inner);
+        writer.write_all(&rendered)?;
+        writer.flush()
+    }
+
+    /// Consumes the handle returning the writer.
+    ///
+    /// [None] is returned if there are still other clones of the handle.
+    pub fn into_inner(self) -> Option<W> {
+        Arc::try_unwrap(self.writer)
+            .ok()
+            .map(|mutex| mutex.into_inner().unwrap_or_else(PoisonError::into_inner))
+    }
+}
@@ -0,0 +1,376 @@
+//! A module containing a [BigProgress] bar and a [BigSpinner] for long-running
+//! tasks.
+
+use crate::{display_width, BigText, Stdout};
+use std::{
+    io::{self, Write},
+    thread,
+    time::Duration,
+};
+
+/// The character filling the done part of the bar, when the font of the percentage
+/// has no visible characters.
+const BAR_FILL: char = '*';
+
+/// The default frames of a [BigSpinner], a scaled up `| / - \`.
+const SPINNER_FRAMES: [[&str; 5]; 4] = [
+    ["  *  ", "  *  ", "  *  ", "  *  ", "  *  "],
+    ["    *", "   * ", "  *  ", " *   ", "*    "],
+    ["     ", "     ", "*****", "     ", "     "],
+    ["*    ", " *   ", "  *  ", "   * ", "    *"],
+];
+
+/// A progress bar showing the percentage in big digits next to a bar as tall as
+/// the digits.
+///
+/// Every [tick](BigProgress::tick) redraws the bar in place, by moving the cursor
+/// back up over the previous drawing with ANSI escape codes.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::progress::BigProgress;
+///
+/// let mut progress = BigProgress::new(Vec::new(), 200);
+/// progress.set_bar_width(10);
+/// progress.tick(50).unwrap();
+/// progress.tick(100).unwrap();
+///
+/// assert_eq!(50, progress.percentage());
+/// let output = String::from_utf8(progress.into_inner()).unwrap();
+/// assert!(output.contains("\x1b[5A"));
+/// ```
+#[derive(Debug)]
+pub struct BigProgress<W: Write> {
+    /// The writer the bar is drawn to.
+    writer: W,
+    /// The value of a finished task.
+    total: u64,
+    /// The current value.
+    value: u64,
+    /// The amount of columns of the bar.
+    bar_width: usize,
+    /// The printer used for the percentage.
+    printer: BigText,
+    /// The amount of rows drawn by the last tick.
+    drawn: usize,
+}
+
+impl BigProgress<Stdout> {
+    /// Creates a [BigProgress] drawing to the [standard output](Stdout).
+    pub fn stdout(total: u64) -> Self {
+        Self::new(crate::stdout(), total)
+    }
+}
+
+impl<W: Write> BigProgress<W> {
+    /// Constructor Function for [BigProgress], with a bar 40 columns wide.
+    pub fn new(writer: W, total: u64) -> Self {
+        Self {
+            writer,
+            total,
+            value: 0,
+            bar_width: 40,
+            printer: BigText::default(),
+            drawn: 0,
+        }
+    }
+
+    /// Sets the amount of columns of the bar.
+    pub fn set_bar_width(&mut self, bar_width: usize) -> &mut Self {
+        self.bar_width = bar_width;
+        self
+    }
+
+    /// Gets the amount of columns of the bar.
+    pub fn bar_width(&self) -> usize {
+        self.bar_width
+    }
+
+    /// Gets the printer used for the percentage, to change its font or spacing.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::progress::BigProgress;
+    ///
+    /// let mut progress = BigProgress::new(Vec::new(), 10);
+    /// progress.printer_mut().set_spacing(2);
+    /// ```
+    pub fn printer_mut(&mut self) -> &mut BigText {
+        &mut self.printer
+    }
+
+    /// Gets the current value.
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    /// Gets the percentage of the total that is done, between 0 and 100.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::progress::BigProgress;
+    ///
+    /// let mut progress = BigProgress::new(Vec::new(), 3);
+    /// progress.tick(1).unwrap();
+    /// assert_eq!(33, progress.percentage());
+    /// progress.tick(7).unwrap();
+    /// assert_eq!(100, progress.percentage());
+    /// ```
+    pub fn percentage(&self) -> u64 {
+        match self.total {
+            0 => 100,
+            // Large totals such as byte counts would overflow when multiplied
+            total => (self.value.min(total) as u128 * 100 / total as u128) as u64,
+        }
+    }
+
+    /// Sets the current value and redraws the bar in place.
+    pub fn tick(&mut self, value: u64) -> io::Result<()> {
+        self.value = value;
+
+        let rows = self.frame();
+        redraw(&mut self.writer, &mut self.drawn, &rows)
+    }
+
+    /// Renders the percentage and the bar, without the escape codes.
+    ///
+    /// The percentage is right-aligned to the width of `100%`, so the bar doesn't
+    /// move as the digits change. The bar is filled with the character the glyphs of
+    /// the font are drawn with, such as the full blocks of
+    /// [blocks](crate::character_maps::blocks()).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::progress::BigProgress;
+    ///
+    /// let mut progress = BigProgress::new(Vec::new(), 4);
+    /// progress.set_bar_width(8);
+    /// progress.tick(2).unwrap();
+    ///
+    /// let rows = progress.frame();
+    /// assert_eq!(5, rows.len());
+    /// assert!(rows[2].ends_with("|****    |"));
+    /// ```
+    pub fn frame(&self) -> Vec<String> {
+        let widest = self.printer.render_rows("100%");
+        let width = widest.iter().map(|row| display_width(row)).max();
+        let fill = widest
+            .iter()
+            .flat_map(|row| row.chars())
+            .find(|c| !c.is_whitespace())
+            .unwrap_or(BAR_FILL);
+
+        let filled = self.bar_width * self.percentage() as usize / 100;
+        let bar = format!(
+            "|{}{}|",
+            fill.to_string().repeat(filled),
+            " ".repeat(self.bar_width - filled)
+        );
+
+        self.printer
+            .render_rows(&format!("{}%", self.percentage()))
+            .into_iter()
+            .map(|row| {
+                let padding = width.unwrap_or(0).saturating_sub(display_width(&row));
+                format!("{}{row}  {bar}", " ".repeat(padding))
+            })
+            .collect()
+    }
+
+    /// Gets the writer back.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// A spinner cycling through large frames, for tasks without a known length.
+///
+/// Every [tick](BigSpinner::tick) redraws the next frame in place, like a
+/// [BigProgress].
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::progress::BigSpinner;
+/// use std::time::Duration;
+///
+/// let mut spinner = BigSpinner::new(Vec::new());
+/// spinner.set_interval(Duration::ZERO);
+///
+/// let mut ticks = 0;
+/// spinner.run_while(|| { ticks += 1; ticks <= 3 }).unwrap();
+///
+/// let output = String::from_utf8(spinner.into_inner()).unwrap();
+/// assert_eq!(2, output.matches("\x1b[5A").count());
+/// ```
+#[derive(Debug)]
+pub struct BigSpinner<W: Write> {
+    /// The writer the spinner is drawn to.
+    writer: W,
+    /// The rows of every frame.
+    frames: Vec<Vec<String>>,
+    /// The index of the next frame.
+    index: usize,
+    /// The time between frames.
+    interval: Duration,
+    /// The amount of rows drawn by the last tick.
+    drawn: usize,
+}
+
+impl BigSpinner<Stdout> {
+    /// Creates a [BigSpinner] drawing to the [standard output](Stdout).
+    pub fn stdout() -> Self {
+        Self::new(crate::stdout())
+    }
+}
+
+impl<W: Write> BigSpinner<W> {
+    /// Constructor Function for [BigSpinner], cycling through a scaled up `| / - \`
+    /// every 100 milliseconds.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            frames: SPINNER_FRAMES
+                .iter()
+                .map(|rows| rows.iter().map(|row| String::from(*row)).collect())
+                .collect(),
+            index: 0,
+            interval: Duration::from_millis(100),
+            drawn: 0,
+        }
+    }
+
+    /// Sets the frames to cycle through, rendering each of the `texts`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{progress::BigSpinner, BigText};
+    ///
+    /// let mut spinner = BigSpinner::new(Vec::new());
+    /// spinner.set_frames(&[BigText::new("1", None), BigText::new("2", None)]);
+    /// assert_eq!(BigText::new("1", None).to_string(), spinner.frame().join("\n") + "\n");
+    /// ```
+    pub fn set_frames(&mut self, texts: &[BigText]) -> &mut Self {
+        self.frames = texts
+            .iter()
+            .map(|text| text.render_rows(text.text()))
+            .collect();
+        self.index = 0;
+        self
+    }
+
+    /// Sets the time between frames used by [run_while](BigSpinner::run_while).
+    pub fn set_interval(&mut self, interval: Duration) -> &mut Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Gets the time between frames used by [run_while](BigSpinner::run_while).
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Gets the rows of the frame drawn by the next tick.
+    pub fn frame(&self) -> &[String] {
+        self.frames.get(self.index).map_or(&[], Vec::as_slice)
+    }
+
+    /// Redraws the next frame in place.
+    pub fn tick(&mut self) -> io::Result<()> {
+        let rows = self.frame().to_vec();
+        self.index = (self.index + 1) % self.frames.len().max(1);
+        redraw(&mut self.writer, &mut self.drawn, &rows)
+    }
+
+    /// Ticks every [interval](BigSpinner::set_interval) as long as `condition`
+    /// returns `true`.
+    pub fn run_while(&mut self, mut condition: impl FnMut() -> bool) -> io::Result<()> {
+        let mut first = true;
+        while condition() {
+            if !first {
+                thread::sleep(self.interval);
+            }
+            self.tick()?;
+            first = false;
+        }
+
+        Ok(())
+    }
+
+    /// Gets the writer back.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Draws `rows` over the `drawn` rows drawn before, clearing every row first.
+fn redraw(writer: &mut impl Write, drawn: &mut usize, rows: &[String]) -> io::Result<()> {
+    if *drawn > 0 {
+        write!(writer, "\x1b[{drawn}A")?;
+    }
+    for row in rows {
+        writeln!(writer, "\x1b[2K{row}")?;
+    }
+    writer.flush()?;
+    *drawn = rows.len();
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::character_maps;
+
+    #[test]
+    fn redraws_in_place() {
+        let mut progress = BigProgress::new(Vec::new(), 10);
+        progress.tick(0).unwrap();
+        progress.tick(10).unwrap();
+
+        let output = String::from_utf8(progress.into_inner()).unwrap();
+        assert_eq!(1, output.matches("\x1b[5A").count());
+        assert_eq!(10, output.matches("\x1b[2K").count());
+    }
+
+    #[test]
+    fn percentage_of_large_totals() {
+        let mut progress = BigProgress::new(Vec::new(), u64::MAX);
+        progress.value = u64::MAX / 2;
+        assert_eq!(49, progress.percentage());
+        progress.value = u64::MAX;
+        assert_eq!(100, progress.percentage());
+    }
+
+    #[test]
+    fn spinner_cycles() {
+        let mut spinner = BigSpinner::new(Vec::new());
+        let first = spinner.frame().to_vec();
+        for _ in 0..SPINNER_FRAMES.len() {
+            spinner.tick().unwrap();
+        }
+        assert_eq!(first, spinner.frame());
+    }
+
+    #[test]
+    fn fixed_width_frames() {
+        let mut progress = BigProgress::new(Vec::new(), 100);
+        progress.set_bar_width(4);
+        let width = |progress: &BigProgress<Vec<u8>>| display_width(&progress.frame()[0]);
+
+        progress.tick(5).unwrap();
+        let start = width(&progress);
+        progress.tick(100).unwrap();
+        assert_eq!(start, width(&progress));
+
+        progress
+            .printer_mut()
+            .set_character_map(character_maps::blocks());
+        assert!(progress.frame()[2].ends_with("|████|"));
+    }
+
+    #[test]
+    fn empty_total() {
+        let progress = BigProgress::new(Vec::new(), 0);
+        assert_eq!(100, progress.percentage());
+    }
+}
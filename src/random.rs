@@ -0,0 +1,48 @@
+//! A module containing a small random number generator for the effects and
+//! animations, which only need to look random and be reproducible.
+
+/// A xorshift random number generator.
+#[derive(Debug, Clone)]
+pub(crate) struct Random(u64);
+
+impl Random {
+    /// Creates a generator from a seed, the same seed always generates the same
+    /// numbers.
+    pub(crate) fn new(seed: u64) -> Self {
+        // A xorshift generator never leaves a state of 0
+        Self((seed ^ 0x9e37_79b9_7f4a_7c15).max(1))
+    }
+
+    /// Gets the next random number.
+    pub(crate) fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Gets a number below `bound`.
+    pub(crate) fn below(&mut self, bound: u64) -> u64 {
+        self.next() % bound.max(1)
+    }
+
+    /// Returns `true` with the given probability between 0 and 1.
+    pub(crate) fn chance(&mut self, probability: f64) -> bool {
+        let sample = (self.next() >> 11) as f64 / (1u64 << 53) as f64;
+        sample < probability
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reproducible() {
+        let mut first = Random::new(42);
+        let mut second = Random::new(42);
+        assert_eq!(first.next(), second.next());
+        assert!(!Random::new(0).chance(0.0));
+        assert!(Random::new(0).chance(1.0));
+    }
+}
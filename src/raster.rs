@@ -0,0 +1,296 @@
+//! A module for rasterizing a [BigText] into an RGBA pixel buffer.
+//!
+//! With the `image` feature enabled, the buffer can also be converted into an
+//! `image::RgbaImage` and saved as a PNG file.
+
+use crate::{canvas, color::Rgb, display_width, BigText};
+
+/// Options used when rasterizing a [BigText].
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{color::Rgb, raster::RasterOptions};
+///
+/// let options = RasterOptions {
+///     cell_width: 4,
+///     cell_height: 8,
+///     background: Some(Rgb::WHITE),
+///     ..Default::default()
+/// };
+/// assert_eq!(Rgb::BLACK, options.foreground);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RasterOptions {
+    /// The width of each cell in pixels.
+    pub cell_width: u32,
+    /// The height of each cell in pixels.
+    pub cell_height: u32,
+    /// The color of the filled cells, used when the [BigText] has no
+    /// [color](BigText::set_color()).
+    pub foreground: Rgb,
+    /// The color of the empty cells, [None] for transparent pixels.
+    pub background: Option<Rgb>,
+}
+
+impl Default for RasterOptions {
+    fn default() -> Self {
+        Self {
+            cell_width: 1,
+            cell_height: 1,
+            foreground: Rgb::BLACK,
+            background: None,
+        }
+    }
+}
+
+impl RasterOptions {
+    /// Options for displaying images in a terminal, with white filled cells the size
+    /// of a typical terminal cell on a transparent background.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{color::Rgb, raster::RasterOptions};
+    ///
+    /// let options = RasterOptions::terminal();
+    /// assert_eq!(Rgb::WHITE, options.foreground);
+    /// assert_eq!(None, options.background);
+    /// ```
+    pub fn terminal() -> Self {
+        Self {
+            cell_width: 8,
+            cell_height: 16,
+            foreground: Rgb::WHITE,
+            background: None,
+        }
+    }
+}
+
+/// An RGBA pixel buffer of a rendered [BigText].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Raster {
+    /// The width of the image in pixels.
+    width: u32,
+    /// The height of the image in pixels.
+    height: u32,
+    /// The pixels in row-major order, with 4 bytes (RGBA) per pixel.
+    pixels: Vec<u8>,
+}
+
+impl Raster {
+    /// Gets the width of the image in pixels.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Gets the height of the image in pixels.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Gets the pixels in row-major order, with 4 bytes (RGBA) per pixel.
+    pub fn pixels(&self) -> &[u8] {
+        self.pixels.as_ref()
+    }
+
+    /// Consumes the raster returning the pixels.
+    pub fn into_pixels(self) -> Vec<u8> {
+        self.pixels
+    }
+}
+
+impl BigText {
+    /// Rasterizes the stored string into an RGBA pixel buffer.
+    ///
+    /// Every non-whitespace cell of the ascii-art is drawn as a block of
+    /// `cell_width` × `cell_height` pixels, in the [color](BigText::set_color()) of
+    /// the [BigText] or the foreground if it has none. Returns [None] if the
+    /// dimensions of the image don't fit in a [u32] or its buffer in memory.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{raster::RasterOptions, BigText};
+    ///
+    /// let printer = BigText::new("I", None);
+    /// let raster = printer.rasterize(&RasterOptions::default()).unwrap();
+    ///
+    /// assert_eq!(6, raster.width());
+    /// assert_eq!(5, raster.height());
+    /// assert_eq!(6 * 5 * 4, raster.pixels().len());
+    /// ```
+    pub fn rasterize(&self, options: &RasterOptions) -> Option<Raster> {
+        let rows = self.render_rows(self.text());
+        let columns = rows.iter().map(|row| display_width(row)).max().unwrap_or(0);
+        let width = u32::try_from(columns)
+            .ok()?
+            .checked_mul(options.cell_width)?;
+        let height = u32::try_from(rows.len())
+            .ok()?
+            .checked_mul(options.cell_height)?;
+        let size = usize::try_from(width)
+            .ok()?
+            .checked_mul(usize::try_from(height).ok()?)?
+            .checked_mul(4)?;
+
+        let foreground = |column: usize| {
+            let color = match self.color() {
+                Some(style) => style.color_at(column, columns),
+                None => options.foreground,
+            };
+            [color.r, color.g, color.b, 255]
+        };
+        let background = match options.background {
+            Some(color) => [color.r, color.g, color.b, 255],
+            None => [0, 0, 0, 0],
+        };
+
+        let mut pixels = Vec::with_capacity(size);
+        for row in &rows {
+            let cells: Vec<bool> = canvas::row_cells(row)
+                .into_iter()
+                .map(|c| !c.is_whitespace())
+                .collect();
+
+            for _ in 0..options.cell_height {
+                for column in 0..columns {
+                    let pixel = match cells.get(column) {
+                        Some(true) => foreground(column),
+                        _ => background,
+                    };
+                    for _ in 0..options.cell_width {
+                        pixels.extend_from_slice(&pixel);
+                    }
+                }
+            }
+        }
+
+        Some(Raster {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    /// Rasterizes the stored string into an [image::RgbaImage].
+    ///
+    /// Returns [None] if the image is too large, see [BigText::rasterize()].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{raster::RasterOptions, BigText};
+    ///
+    /// let printer = BigText::new("HI", None);
+    /// let image = printer.to_image(&RasterOptions::default()).unwrap();
+    /// assert_eq!((12, 5), image.dimensions());
+    /// ```
+    #[cfg(feature = "image")]
+    pub fn to_image(&self, options: &RasterOptions) -> Option<image::RgbaImage> {
+        let raster = self.rasterize(options)?;
+        image::RgbaImage::from_raw(raster.width, raster.height, raster.pixels)
+    }
+
+    /// Rasterizes the stored string and saves it as a PNG image at `path`.
+    ///
+    /// A dimension error is returned if the image is too large.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use print_big_text_rs::{color::Rgb, raster::RasterOptions, BigText};
+    ///
+    /// let printer = BigText::new("HI", None);
+    /// let options = RasterOptions {
+    ///     cell_width: 8,
+    ///     cell_height: 16,
+    ///     background: Some(Rgb::WHITE),
+    ///     ..Default::default()
+    /// };
+    /// printer.save_png("banner.png", &options).unwrap();
+    /// ```
+    #[cfg(feature = "image")]
+    pub fn save_png<P: AsRef<std::path::Path>>(
+        &self,
+        path: P,
+        options: &RasterOptions,
+    ) -> image::ImageResult<()> {
+        use image::error::{ImageError, LimitError, LimitErrorKind};
+
+        self.to_image(options)
+            .ok_or_else(|| {
+                ImageError::Limits(LimitError::from_kind(LimitErrorKind::DimensionError))
+            })?
+            .save_with_format(path, image::ImageFormat::Png)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::{ColorStyle, Gradient};
+    use std::collections::HashMap;
+
+    #[test]
+    fn full_width_pixels() {
+        let glyph = [
+            "漢".into(),
+            "漢".into(),
+            "漢".into(),
+            "漢".into(),
+            "漢".into(),
+        ];
+        let printer = BigText::new("XX", Some(HashMap::from([('X', glyph)])));
+        let raster = printer.rasterize(&RasterOptions::default()).unwrap();
+        assert_eq!(6, raster.width());
+
+        // Both columns of the second glyph are filled, after its spacing
+        let filled: Vec<bool> = raster.pixels()[..6 * 4]
+            .chunks(4)
+            .map(|pixel| pixel[3] == 255)
+            .collect();
+        assert_eq!(vec![true, true, false, true, true, false], filled);
+    }
+
+    #[test]
+    fn printer_colors() {
+        let mut printer = BigText::new("A", None);
+        printer.set_color(Some(ColorStyle::Gradient(Gradient::new(vec![
+            Rgb::new(255, 0, 0),
+            Rgb::new(0, 0, 255),
+        ]))));
+        let raster = printer.rasterize(&RasterOptions::default()).unwrap();
+
+        // The middle row of "A" is filled from the first to the fifth column
+        let row = &raster.pixels()[2 * 6 * 4..3 * 6 * 4];
+        assert_eq!(&[255, 0, 0, 255], &row[..4]);
+        assert_eq!(&[51, 0, 204, 255], &row[4 * 4..5 * 4]);
+    }
+
+    #[test]
+    fn huge_cells() {
+        let printer = BigText::new("HI", None);
+        let options = RasterOptions {
+            cell_width: u32::MAX,
+            cell_height: u32::MAX,
+            ..Default::default()
+        };
+        assert_eq!(None, printer.rasterize(&options));
+
+        // The dimensions fit but the buffer doesn't
+        let options = RasterOptions {
+            cell_width: u32::MAX / 12,
+            cell_height: u32::MAX / 5,
+            ..Default::default()
+        };
+        assert_eq!(None, printer.rasterize(&options));
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn huge_image() {
+        let printer = BigText::new("HI", None);
+        let options = RasterOptions {
+            cell_height: u32::MAX,
+            ..Default::default()
+        };
+        assert_eq!(None, printer.to_image(&options));
+        assert!(printer.save_png("huge.png", &options).is_err());
+    }
+}
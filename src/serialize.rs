@@ -0,0 +1,122 @@
+//! A module implementing [Serialize] and [Deserialize] for [BigText].
+//!
+//! The implementations require the `serde` feature.
+
+use crate::{
+    character_maps::{self, CharacterMap, Glyph},
+    layout::Alignment,
+    BigText,
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{collections::BTreeMap, sync::Arc};
+
+/// The serialized form of a [BigText].
+#[derive(Serialize)]
+struct BigTextRef<'a> {
+    text: &'a str,
+    /// The font, left out when it is the default printables map.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    font: Option<BTreeMap<&'a char, &'a Glyph>>,
+    spacing: usize,
+    width: Option<usize>,
+    alignment: Alignment,
+}
+
+/// The deserialized form of a [BigText].
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BigTextData {
+    text: String,
+    #[serde(default)]
+    font: Option<CharacterMap>,
+    #[serde(default = "default_spacing")]
+    spacing: usize,
+    #[serde(default)]
+    width: Option<usize>,
+    #[serde(default)]
+    alignment: Alignment,
+}
+
+/// The spacing of a [BigText] created with [BigText::new()].
+fn default_spacing() -> usize {
+    1
+}
+
+/// Serializes the text, font, spacing, width and alignment of a [BigText].
+///
+/// The font is left out when it is the default [printables](character_maps::printables())
+/// map, so configured printers stay small. Colors, blinking, normalization, direction,
+/// line endings and decorations, unknown widths, offset patterns, sequences, glyph
+/// metrics, substitutions, glyph providers, effects and zero-width hooks aren't
+/// serialized.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{character_maps, BigText};
+///
+/// let mut printer = BigText::new("HI", None);
+/// printer.set_spacing(2);
+///
+/// let json = serde_json::to_string(&printer).unwrap();
+/// assert_eq!(
+///     r#"{"text":"HI","spacing":2,"width":null,"alignment":"left"}"#,
+///     json
+/// );
+///
+/// let printer: BigText = serde_json::from_str(&json).unwrap();
+/// assert_eq!(2, printer.spacing());
+/// assert_eq!(&character_maps::printables(), printer.character_map());
+/// ```
+impl Serialize for BigText {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let default = Arc::ptr_eq(
+            self.shared_character_map(),
+            &character_maps::shared_printables(),
+        );
+
+        BigTextRef {
+            text: self.text(),
+            font: (!default).then(|| self.character_map().iter().collect()),
+            spacing: self.spacing(),
+            width: self.width(),
+            alignment: self.alignment(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for BigText {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = BigTextData::deserialize(deserializer)?;
+        let mut printer = BigText::new(&data.text, data.font);
+        printer
+            .set_spacing(data.spacing)
+            .set_width(data.width)
+            .set_alignment(data.alignment);
+
+        Ok(printer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip_font() {
+        let mut printer = BigText::new("42", Some(character_maps::digits()));
+        printer.set_width(Some(20)).set_alignment(Alignment::Center);
+
+        let toml = toml::to_string(&printer).unwrap();
+        let deserialized: BigText = toml::from_str(&toml).unwrap();
+
+        assert_eq!(printer.character_map(), deserialized.character_map());
+        assert_eq!(printer.to_string(), deserialized.to_string());
+    }
+
+    #[test]
+    fn unknown_field() {
+        let json = r#"{"text": "HI", "colour": "red"}"#;
+        assert!(serde_json::from_str::<BigText>(json).is_err());
+    }
+}
@@ -0,0 +1,291 @@
+//! A module for loading and rendering declarative banner spec files.
+//!
+//! A spec describes a banner composed of multiple blocks of text, each with its own
+//! font and colors, and how the blocks are laid out. Specs can be written in TOML or
+//! JSON:
+//!
+//! ```toml
+//! layout = "vertical"
+//! gap = 1
+//!
+//! [[blocks]]
+//! text = "HELLO"
+//! color = "#ff0000"
+//!
+//! [[blocks]]
+//! text = "123"
+//! font = "digits"
+//! gradient = ["#00ff00", "#0000ff"]
+//! ```
+//!
+//! This module requires the `serde` feature.
+
+use crate::{
+    color::{ColorStyle, Gradient, ParseColorError, Rgb},
+    display_width,
+    fonts::{self, FontError},
+    BigText,
+};
+use serde::{Deserialize, Serialize};
+use std::{error::Error, fmt::Display, fs, io, path::Path};
+
+/// How the blocks of a [BannerSpec] are placed relative to each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SpecLayout {
+    /// Blocks are placed below each other.
+    #[default]
+    Vertical,
+    /// Blocks are placed next to each other.
+    Horizontal,
+}
+
+/// A single block of text in a [BannerSpec].
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BlockSpec {
+    /// The text to print.
+    pub text: String,
+    /// The font to use, any name or path accepted by [fonts::resolve()]. Defaults to
+    /// `"printables"`.
+    pub font: Option<String>,
+    /// A solid `#rrggbb` color for the block.
+    pub color: Option<String>,
+    /// The `#rrggbb` stops of a gradient for the block, used instead of `color`.
+    pub gradient: Option<Vec<String>>,
+}
+
+/// A banner composed of multiple blocks of text.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{spec::BannerSpec, BigText};
+///
+/// let spec = BannerSpec::from_toml(
+///     r#"
+///     [[blocks]]
+///     text = "HI"
+///
+///     [[blocks]]
+///     text = "42"
+///     font = "digits"
+///     "#,
+/// )
+/// .unwrap();
+///
+/// let expected = BigText::new("HI", None).to_string() + &BigText::new("42", None).to_string();
+/// assert_eq!(expected, spec.render().unwrap());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BannerSpec {
+    /// How the blocks are placed.
+    pub layout: SpecLayout,
+    /// The amount of blank lines (vertical) or columns (horizontal) between blocks.
+    pub gap: usize,
+    /// The blocks of text.
+    pub blocks: Vec<BlockSpec>,
+}
+
+/// The errors that can occur when loading or rendering a [BannerSpec].
+#[derive(Debug)]
+pub enum SpecError {
+    /// The spec file couldn't be read.
+    Io(io::Error),
+    /// The spec isn't valid JSON.
+    Json(serde_json::Error),
+    /// The spec isn't valid TOML.
+    Toml(toml::de::Error),
+    /// A block uses a font that doesn't exist.
+    UnknownFont(String),
+    /// The font file of a block couldn't be loaded.
+    Font(FontError),
+    /// A block uses an invalid color.
+    InvalidColor(ParseColorError),
+}
+
+impl Display for SpecError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "failed to read spec: {error}"),
+            Self::Json(error) => write!(f, "invalid JSON spec: {error}"),
+            Self::Toml(error) => write!(f, "invalid TOML spec: {error}"),
+            Self::UnknownFont(name) => write!(f, "unknown font \"{name}\""),
+            Self::Font(error) => error.fmt(f),
+            Self::InvalidColor(error) => error.fmt(f),
+        }
+    }
+}
+
+impl Error for SpecError {}
+
+impl BannerSpec {
+    /// Parses a spec from a JSON string.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::spec::{BannerSpec, SpecLayout};
+    ///
+    /// let spec = BannerSpec::from_json(r#"{"layout": "horizontal", "blocks": [{"text": "A"}]}"#)
+    ///     .unwrap();
+    /// assert_eq!(SpecLayout::Horizontal, spec.layout);
+    /// ```
+    pub fn from_json(data: &str) -> Result<Self, SpecError> {
+        serde_json::from_str(data).map_err(SpecError::Json)
+    }
+
+    /// Parses a spec from a TOML string.
+    pub fn from_toml(data: &str) -> Result<Self, SpecError> {
+        toml::from_str(data).map_err(SpecError::Toml)
+    }
+
+    /// Loads a spec from a file.
+    ///
+    /// Files with a `.json` extension are parsed as JSON, everything else as TOML.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, SpecError> {
+        let path = path.as_ref();
+        let data = fs::read_to_string(path).map_err(SpecError::Io)?;
+
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("json") => Self::from_json(&data),
+            _ => Self::from_toml(&data),
+        }
+    }
+
+    /// Renders all the blocks of the spec into a single string.
+    pub fn render(&self) -> Result<String, SpecError> {
+        let printers = self
+            .blocks
+            .iter()
+            .map(BlockSpec::printer)
+            .collect::<Result<Vec<BigText>, SpecError>>()?;
+
+        match self.layout {
+            SpecLayout::Vertical => {
+                let mut rendered = String::new();
+                for (index, printer) in printers.iter().enumerate() {
+                    if index > 0 {
+                        rendered.push_str(&"\n".repeat(self.gap));
+                    }
+                    rendered.push_str(&printer.to_string());
+                }
+                Ok(rendered)
+            }
+            SpecLayout::Horizontal => Ok(join_horizontal(&printers, self.gap)),
+        }
+    }
+}
+
+/// Renders `printers` next to each other, separated by `gap` blank columns and
+/// padded to the width of their widest row.
+fn join_horizontal(printers: &[BigText], gap: usize) -> String {
+    let blocks: Vec<(Vec<String>, Vec<String>)> = printers
+        .iter()
+        .map(|printer| (printer.render_rows(printer.text()), printer.output_rows()))
+        .collect();
+    let height = blocks.iter().map(|(plain, _)| plain.len()).max();
+
+    let mut rendered = String::new();
+    for row in 0..height.unwrap_or(0) {
+        let mut line = String::new();
+        for (index, (plain, output)) in blocks.iter().enumerate() {
+            if index > 0 {
+                line.push_str(&" ".repeat(gap));
+            }
+            let width = plain.iter().map(|row| display_width(row)).max();
+            let used = plain.get(row).map_or(0, |row| display_width(row));
+
+            line.push_str(output.get(row).map_or("", String::as_str));
+            line.push_str(&" ".repeat(width.unwrap_or(0) - used));
+        }
+        rendered.push_str(&line);
+        rendered.push('\n');
+    }
+
+    rendered
+}
+
+impl BlockSpec {
+    /// Creates the [BigText] described by the block.
+    fn printer(&self) -> Result<BigText, SpecError> {
+        let map = match &self.font {
+            None => None,
+            Some(name) => Some(fonts::resolve(name).map_err(|error| match error {
+                FontError::UnknownFont(name) => SpecError::UnknownFont(name),
+                error => SpecError::Font(error),
+            })?),
+        };
+        let mut printer = BigText::new(&self.text, map);
+
+        if let Some(stops) = &self.gradient {
+            let stops = stops
+                .iter()
+                .map(|stop| stop.parse::<Rgb>())
+                .collect::<Result<Vec<Rgb>, ParseColorError>>()
+                .map_err(SpecError::InvalidColor)?;
+            printer.set_color(Some(ColorStyle::Gradient(Gradient::new(stops))));
+        } else if let Some(color) = &self.color {
+            let color = color.parse().map_err(SpecError::InvalidColor)?;
+            printer.set_color(Some(ColorStyle::Solid(color)));
+        }
+
+        Ok(printer)
+    }
+}
+
+/// Loads a spec file and renders it in one call.
+///
+/// # Examples
+/// ```rust,no_run
+/// use print_big_text_rs::spec;
+///
+/// print!("{}", spec::render_file("motd.toml").unwrap());
+/// ```
+pub fn render_file<P: AsRef<Path>>(path: P) -> Result<String, SpecError> {
+    BannerSpec::load(path)?.render()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn full_width_horizontal() {
+        let glyph = [
+            "漢".into(),
+            "**".into(),
+            "**".into(),
+            "**".into(),
+            "**".into(),
+        ];
+        let mut wide = BigText::new("X", Some(HashMap::from([('X', glyph)])));
+        wide.set_spacing(0);
+        let rendered = join_horizontal(&[wide, BigText::new("I", None)], 1);
+
+        assert_eq!("漢 ***** ", rendered.lines().next().unwrap());
+    }
+
+    #[test]
+    fn font_file() {
+        let font = r#"{"-": ["   ", "   ", "---", "   ", "   "]}"#;
+        let path = std::env::temp_dir().join("print-big-text-rs-spec-font.json");
+        fs::write(&path, font).unwrap();
+
+        let spec = BannerSpec {
+            blocks: vec![BlockSpec {
+                text: String::from("--"),
+                font: Some(path.to_string_lossy().into_owned()),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let rendered = spec.render();
+        fs::remove_file(&path).unwrap();
+
+        let map = serde_json::from_str(font).unwrap();
+        assert_eq!(BigText::new("--", Some(map)).to_string(), rendered.unwrap());
+
+        assert!(matches!(spec.render(), Err(SpecError::UnknownFont(_))));
+    }
+}
@@ -0,0 +1,177 @@
+//! A module for rendering a [BigText] as an SVG image.
+
+use crate::{canvas, color::Rgb, display_width, BigText};
+use std::fmt::Write;
+
+/// How each cell of the ascii-art is drawn in the SVG image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SvgMode {
+    /// Every filled cell is drawn as a solid `<rect>`.
+    #[default]
+    Rects,
+    /// Every row is drawn as a monospaced `<text>` element.
+    Text,
+}
+
+/// Options used by [BigText::to_svg()].
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{color::Rgb, svg::SvgOptions};
+///
+/// let options = SvgOptions {
+///     foreground: Rgb::new(255, 0, 0),
+///     ..Default::default()
+/// };
+/// assert_eq!(10, options.cell_width);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SvgOptions {
+    /// The width of each cell in pixels.
+    pub cell_width: u32,
+    /// The height of each cell in pixels.
+    pub cell_height: u32,
+    /// The color of the filled cells.
+    pub foreground: Rgb,
+    /// The color of the whole image, [None] for a transparent background.
+    pub background: Option<Rgb>,
+    /// How the cells are drawn.
+    pub mode: SvgMode,
+}
+
+impl Default for SvgOptions {
+    fn default() -> Self {
+        Self {
+            cell_width: 10,
+            cell_height: 20,
+            foreground: Rgb::BLACK,
+            background: None,
+            mode: SvgMode::default(),
+        }
+    }
+}
+
+impl BigText {
+    /// Renders the stored string as an SVG image.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::{svg::SvgOptions, BigText};
+    ///
+    /// let printer = BigText::new("HI", None);
+    /// let svg = printer.to_svg(&SvgOptions::default());
+    ///
+    /// assert!(svg.starts_with("<svg"));
+    /// assert!(svg.contains("<rect"));
+    /// ```
+    pub fn to_svg(&self, options: &SvgOptions) -> String {
+        let rows = self.render_rows(self.text());
+        let columns = rows.iter().map(|row| display_width(row)).max().unwrap_or(0);
+        let width = columns as u32 * options.cell_width;
+        let height = rows.len() as u32 * options.cell_height;
+        let mut svg = String::new();
+
+        // Writing to a String never fails
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#
+        );
+        if let Some(background) = options.background {
+            let _ = writeln!(
+                svg,
+                r#"<rect width="100%" height="100%" fill="{}"/>"#,
+                background.to_hex()
+            );
+        }
+
+        match options.mode {
+            SvgMode::Rects => {
+                let _ = writeln!(svg, r#"<g fill="{}">"#, options.foreground.to_hex());
+                for (y, row) in rows.iter().enumerate() {
+                    for (x, cell) in canvas::row_cells(row).into_iter().enumerate() {
+                        if cell.is_whitespace() {
+                            continue;
+                        }
+                        let _ = writeln!(
+                            svg,
+                            r#"<rect x="{}" y="{}" width="{}" height="{}"/>"#,
+                            x as u32 * options.cell_width,
+                            y as u32 * options.cell_height,
+                            options.cell_width,
+                            options.cell_height
+                        );
+                    }
+                }
+                svg.push_str("</g>\n");
+            }
+            SvgMode::Text => {
+                let _ = writeln!(
+                    svg,
+                    r#"<g fill="{}" font-family="monospace" font-size="{}" xml:space="preserve">"#,
+                    options.foreground.to_hex(),
+                    options.cell_height
+                );
+                for (y, row) in rows.iter().enumerate() {
+                    let _ = writeln!(
+                        svg,
+                        r#"<text x="0" y="{}" textLength="{}">{}</text>"#,
+                        (y as u32 + 1) * options.cell_height,
+                        display_width(row) as u32 * options.cell_width,
+                        escape(row)
+                    );
+                }
+                svg.push_str("</g>\n");
+            }
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+/// Escapes the characters that have a special meaning in XML.
+pub(crate) fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn full_width_rects() {
+        let glyph = [
+            "漢".into(),
+            "漢".into(),
+            "漢".into(),
+            "漢".into(),
+            "漢".into(),
+        ];
+        let printer = BigText::new("XX", Some(HashMap::from([('X', glyph)])));
+        let svg = printer.to_svg(&SvgOptions::default());
+
+        assert!(svg.contains(r#"width="60" height="100""#));
+        // Both columns of the second glyph are filled, after its spacing
+        assert!(svg.contains(r#"<rect x="30" y="0" "#));
+        assert!(svg.contains(r#"<rect x="40" y="0" "#));
+        assert!(!svg.contains(r#"<rect x="20" y="0" "#));
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!("&lt;a href=&quot;&amp;&quot;&gt;", escape("<a href=\"&\">"));
+    }
+}
@@ -0,0 +1,209 @@
+//! A module containing a [Table] of labeled [BigText]s, such as scoreboards.
+
+use std::fmt::Display;
+
+use crate::{display_width, layout::Alignment, BigText};
+
+/// A cell of a [Table], a [BigText] under a small label.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{layout::Alignment, table::TableCell, BigText};
+///
+/// let mut cell = TableCell::new("HOME", BigText::new("3", None));
+/// cell.set_alignment(Alignment::Center);
+/// assert_eq!("HOME", cell.label());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableCell {
+    /// The label printed above the text.
+    label: String,
+    /// The text of the cell.
+    text: BigText,
+    /// How the label and text are aligned within the column.
+    alignment: Alignment,
+}
+
+impl TableCell {
+    /// Constructor Function for [TableCell], aligned to the left.
+    pub fn new(label: &str, text: BigText) -> Self {
+        Self {
+            label: String::from(label),
+            text,
+            alignment: Alignment::Left,
+        }
+    }
+
+    /// Gets the label printed above the text.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Gets the text of the cell.
+    pub fn text(&self) -> &BigText {
+        &self.text
+    }
+
+    /// Sets how the label and text are aligned within the column.
+    pub fn set_alignment(&mut self, alignment: Alignment) -> &mut Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Gets how the label and text are aligned within the column.
+    pub fn alignment(&self) -> Alignment {
+        self.alignment
+    }
+
+    /// Renders the label and the rows of the text.
+    fn rows(&self) -> Vec<String> {
+        let mut rows = vec![self.label.clone()];
+        rows.extend(self.text.render_rows(self.text.text()));
+        rows
+    }
+}
+
+/// A grid of [TableCell]s separated by lines.
+///
+/// Every column is as wide as its widest cell and every row as tall as its tallest
+/// cell. Colors aren't included.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::{layout::Alignment, table::{Table, TableCell}, BigText};
+///
+/// let mut home = TableCell::new("HOME", BigText::new("3", None));
+/// let mut away = TableCell::new("AWAY", BigText::new("1", None));
+/// home.set_alignment(Alignment::Center);
+/// away.set_alignment(Alignment::Center);
+///
+/// let mut table = Table::new();
+/// table.add_row(vec![home, away]);
+///
+/// let scoreboard = table.to_string();
+/// assert!(scoreboard.starts_with(" HOME  |  AWAY \n"));
+/// assert_eq!(6, scoreboard.lines().count());
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Table {
+    /// The rows of cells.
+    rows: Vec<Vec<TableCell>>,
+}
+
+impl Table {
+    /// Constructor Function for [Table], creating an empty table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a row of cells below the current rows.
+    pub fn add_row(&mut self, cells: Vec<TableCell>) -> &mut Self {
+        self.rows.push(cells);
+        self
+    }
+
+    /// Gets the rows of cells.
+    pub fn rows(&self) -> &[Vec<TableCell>] {
+        &self.rows
+    }
+
+    /// Renders the table into rows of text.
+    fn render(&self) -> Vec<String> {
+        let rendered: Vec<Vec<Vec<String>>> = self
+            .rows
+            .iter()
+            .map(|cells| cells.iter().map(TableCell::rows).collect())
+            .collect();
+
+        let columns = self.rows.iter().map(Vec::len).max().unwrap_or(0);
+        let widths: Vec<usize> = (0..columns)
+            .map(|column| {
+                rendered
+                    .iter()
+                    .filter_map(|cells| cells.get(column))
+                    .flatten()
+                    .map(|row| display_width(row))
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+        let separator: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+        let separator = separator.join("-+-");
+
+        let mut output = Vec::new();
+        for (index, (cells, rows)) in self.rows.iter().zip(&rendered).enumerate() {
+            if index > 0 {
+                output.push(separator.clone());
+            }
+
+            let height = rows.iter().map(Vec::len).max().unwrap_or(0);
+            for line in 0..height {
+                let columns: Vec<String> = widths
+                    .iter()
+                    .enumerate()
+                    .map(|(column, width)| {
+                        let row = rows.get(column).and_then(|rows| rows.get(line));
+                        let alignment = cells.get(column).map(TableCell::alignment);
+                        align(
+                            row.map_or("", String::as_str),
+                            *width,
+                            alignment.unwrap_or_default(),
+                        )
+                    })
+                    .collect();
+                output.push(columns.join(" | "));
+            }
+        }
+
+        output
+    }
+}
+
+impl Display for Table {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.render() {
+            writeln!(f, "{row}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Pads `row` to `width` columns with the given alignment.
+fn align(row: &str, width: usize, alignment: Alignment) -> String {
+    let padding = width.saturating_sub(display_width(row));
+    let left = match alignment {
+        Alignment::Left => 0,
+        Alignment::Center => padding / 2,
+        Alignment::Right => padding,
+    };
+
+    format!("{}{row}{}", " ".repeat(left), " ".repeat(padding - left))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn separators() {
+        let mut table = Table::new();
+        table
+            .add_row(vec![TableCell::new("A", BigText::new("1", None))])
+            .add_row(vec![
+                TableCell::new("B", BigText::new("2", None)),
+                TableCell::new("C", BigText::new("", None)),
+            ]);
+
+        let rows: Vec<String> = table.to_string().lines().map(String::from).collect();
+        assert_eq!(13, rows.len());
+        assert_eq!("-------+--", rows[6]);
+        assert!(rows.iter().all(|row| row.chars().count() == 10));
+    }
+
+    #[test]
+    fn align_right() {
+        assert_eq!("  ab", align("ab", 4, Alignment::Right));
+        assert_eq!(" ab ", align("ab", 4, Alignment::Center));
+    }
+}
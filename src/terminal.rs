@@ -0,0 +1,49 @@
+//! A module for drawing a [BigText] at positions in the terminal.
+//!
+//! This module requires the `crossterm` feature.
+
+use crate::BigText;
+use crossterm::{
+    cursor::{MoveTo, RestorePosition, SavePosition},
+    queue,
+    style::Print,
+};
+use std::io::{Error, Write};
+
+impl BigText {
+    /// Draws the stored string with its top left corner at column `x` and row `y`
+    /// of the terminal.
+    ///
+    /// The cursor is moved back to where it was before drawing, so the content
+    /// around the ascii-art isn't disturbed. If [None] is provided for stream, the
+    /// [standard output](crate::Stdout) would be used.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use print_big_text_rs::BigText;
+    ///
+    /// let mut vec = Vec::new();
+    /// let printer = BigText::new("A", None);
+    /// printer.draw_at(Some(&mut vec), 10, 2).unwrap();
+    ///
+    /// let output = String::from_utf8(vec).unwrap();
+    /// assert!(output.starts_with("\x1b7\x1b[3;11H ***  "));
+    /// assert!(output.ends_with("\x1b8"));
+    /// ```
+    pub fn draw_at(&self, stream: Option<&mut dyn Write>, x: u16, y: u16) -> Result<(), Error> {
+        let standard = &mut crate::stdout();
+        let mut stream = stream.unwrap_or(standard);
+
+        queue!(&mut stream, SavePosition)?;
+        for (index, row) in self.output_rows().iter().enumerate() {
+            queue!(
+                &mut stream,
+                MoveTo(x, y.saturating_add(index as u16)),
+                Print(row)
+            )?;
+        }
+        queue!(&mut stream, RestorePosition)?;
+
+        stream.flush()
+    }
+}
@@ -0,0 +1,106 @@
+//! A module exposing a JavaScript friendly API through [wasm_bindgen].
+//!
+//! The bindings require the `wasm` feature, and can be built for the browser as a
+//! `cdylib` with
+//! `cargo rustc --lib --target wasm32-unknown-unknown --features wasm --crate-type cdylib`,
+//! followed by `wasm-bindgen` on the built module.
+//!
+//! ```js
+//! import { Printer } from "./pkg/print_big_text_rs.js";
+//!
+//! const printer = new Printer("HI");
+//! printer.setFont("letters");
+//! document.body.innerHTML = printer.renderHtml();
+//! ```
+
+use crate::{character_maps, fonts, svg, BigText};
+use wasm_bindgen::prelude::*;
+
+/// A [BigText] that can be used from JavaScript.
+#[wasm_bindgen]
+pub struct Printer {
+    /// The wrapped printer.
+    printer: BigText,
+}
+
+#[wasm_bindgen]
+impl Printer {
+    /// Creates a printer using the printables map.
+    #[wasm_bindgen(constructor)]
+    pub fn new(text: &str) -> Printer {
+        Printer {
+            printer: BigText::from(text),
+        }
+    }
+
+    /// Gets the text currently to print.
+    pub fn text(&self) -> String {
+        String::from(self.printer.text())
+    }
+
+    /// Sets the text currently to print.
+    #[wasm_bindgen(js_name = setText)]
+    pub fn set_text(&mut self, text: &str) {
+        self.printer.set_text(text);
+    }
+
+    /// Sets the font to one of the [built-in maps](Printer::font_names()).
+    #[wasm_bindgen(js_name = setFont)]
+    pub fn set_font(&mut self, name: &str) -> Result<(), JsError> {
+        let map = character_maps::from_name(name)
+            .ok_or_else(|| JsError::new(&fonts::FontError::UnknownFont(name.into()).to_string()))?;
+        self.printer.set_character_map(map);
+        Ok(())
+    }
+
+    /// Sets the font to a JSON character map.
+    #[wasm_bindgen(js_name = setFontJson)]
+    pub fn set_font_json(&mut self, data: &str) -> Result<(), JsError> {
+        let map = fonts::parse(data, fonts::FontFormat::Json)?;
+        self.printer.set_character_map(map);
+        Ok(())
+    }
+
+    /// Gets the names of the built-in maps.
+    #[wasm_bindgen(js_name = fontNames)]
+    pub fn font_names() -> Vec<String> {
+        std::iter::once("printables")
+            .chain(character_maps::sources().iter().map(|source| source.name()))
+            .map(String::from)
+            .collect()
+    }
+
+    /// Renders the text into its ascii-art form.
+    pub fn render(&self) -> String {
+        self.printer.to_string()
+    }
+
+    /// Renders the text into a `<pre>` element with the `print-big-text` class.
+    #[wasm_bindgen(js_name = renderHtml)]
+    pub fn render_html(&self) -> String {
+        format!(
+            "<pre class=\"print-big-text\">{}</pre>",
+            svg::escape(&self.render())
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_html() {
+        let mut printer = Printer::new("<");
+        printer.set_text("HI");
+        printer.set_font("letters").unwrap();
+
+        let html = printer.render_html();
+        assert!(html.starts_with("<pre class=\"print-big-text\">*   * *****"));
+        assert!(html.ends_with("\n</pre>"));
+        assert_eq!(
+            character_maps::sources().len() + 1,
+            Printer::font_names().len()
+        );
+    }
+}
@@ -0,0 +1,124 @@
+//! A module integrating [BigText] with [ratatui] so it can be drawn as a widget.
+//!
+//! This module requires the `ratatui` feature.
+
+use crate::{display_width, BigText};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    widgets::Widget,
+};
+use unicode_width::UnicodeWidthChar;
+
+/// A [Widget] drawing a [BigText] with a [Style].
+///
+/// The ascii-art is drawn from the top left corner of the area and clipped to it.
+/// Whitespace cells are left untouched so the widget can be drawn on top of other
+/// widgets. If the [BigText] has [colors](BigText::set_color), they are used as the
+/// foreground color of the filled cells.
+///
+/// # Examples
+/// ```rust
+/// use print_big_text_rs::BigText;
+/// use ratatui::{buffer::Buffer, layout::Rect, style::{Style, Stylize}, widgets::Widget};
+///
+/// let printer = BigText::new("HI", None);
+/// let area = Rect::new(0, 0, 20, 5);
+/// let mut buffer = Buffer::empty(area);
+///
+/// printer.widget().style(Style::new().bold()).render(area, &mut buffer);
+/// assert_eq!("*", buffer[(0, 0)].symbol());
+/// ```
+#[derive(Clone)]
+pub struct BigTextWidget<'a> {
+    /// The text being drawn.
+    text: &'a BigText,
+    /// The style of the filled cells.
+    style: Style,
+}
+
+impl BigTextWidget<'_> {
+    /// Sets the style of the filled cells.
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl BigText {
+    /// Creates a [BigTextWidget] drawing the stored string.
+    pub fn widget(&self) -> BigTextWidget<'_> {
+        BigTextWidget {
+            text: self,
+            style: Style::default(),
+        }
+    }
+}
+
+impl Widget for BigTextWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        let rows = self.text.render_rows(self.text.text());
+        let width = rows.iter().map(|row| display_width(row)).max().unwrap_or(0);
+
+        for (y, row) in rows.iter().take(area.height as usize).enumerate() {
+            // Full-width characters take up two cells, the second one is skipped
+            let mut x = 0;
+            for cell in row.chars() {
+                let columns = cell.width().unwrap_or(0);
+                if x + columns > area.width as usize {
+                    break;
+                }
+
+                if columns > 0 && !cell.is_whitespace() {
+                    let mut style = self.style;
+                    if let Some(color) = self.text.color() {
+                        let color = color.color_at(x, width);
+                        style = style.fg(Color::Rgb(color.r, color.g, color.b));
+                    }
+
+                    let position = (area.x + x as u16, area.y + y as u16);
+                    if let Some(target) = buf.cell_mut(position) {
+                        target.set_char(cell).set_style(style);
+                    }
+                }
+                x += columns;
+            }
+        }
+    }
+}
+
+impl Widget for &BigText {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.widget().render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn full_width_cells() {
+        let glyph = [
+            "漢".into(),
+            "漢".into(),
+            "漢".into(),
+            "漢".into(),
+            "漢".into(),
+        ];
+        let printer = BigText::new("XX", Some(HashMap::from([('X', glyph)])));
+        let area = Rect::new(0, 0, 5, 5);
+        let mut buffer = Buffer::empty(area);
+
+        printer.widget().render(area, &mut buffer);
+        assert_eq!("漢", buffer[(0, 0)].symbol());
+        assert_eq!("漢", buffer[(3, 0)].symbol());
+        // The second glyph doesn't fit in the last column
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 5));
+        printer.widget().render(buffer.area, &mut buffer);
+        assert_eq!(" ", buffer[(3, 0)].symbol());
+    }
+}
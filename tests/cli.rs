@@ -0,0 +1,630 @@
+use std::{
+    io::{Read, Write},
+    process::{Command, Output, Stdio},
+    sync::mpsc,
+    thread,
+    time::Duration,
+};
+
+use print_big_text_rs::{character_maps, layout, BigText};
+
+/// Runs the binary with the given arguments and standard input.
+fn run(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_print-big-text-rs"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn test_arguments() {
+    let output = run(&["A", "1"], "");
+    let expected =
+        BigText::new("A", None).to_string() + "\n" + &BigText::new("1", None).to_string();
+
+    assert!(output.status.success());
+    assert_eq!(expected, String::from_utf8(output.stdout).unwrap());
+}
+
+#[test]
+fn test_stdin() {
+    let output = run(&[], "A\n1\n");
+    let expected =
+        BigText::new("A", None).to_string() + "\n" + &BigText::new("1", None).to_string();
+    assert_eq!(expected, String::from_utf8(output.stdout).unwrap());
+
+    let output = run(&["A", "-"], "1\n");
+    assert_eq!(expected, String::from_utf8(output.stdout).unwrap());
+}
+
+#[test]
+fn test_unknown_font() {
+    let output = run(&["--font", "nope", "A"], "");
+    assert!(!output.status.success());
+}
+
+#[test]
+fn test_style_fonts() {
+    let output = run(&["--font", "blocks", "HI"], "");
+    let expected = BigText::new("HI", Some(character_maps::blocks())).to_string();
+    assert_eq!(expected, String::from_utf8(output.stdout).unwrap());
+
+    let output = run(&["--font", "slant", "HI"], "");
+    let expected = BigText::new("HI", Some(character_maps::slant())).to_string();
+    assert_eq!(expected, String::from_utf8(output.stdout).unwrap());
+}
+
+#[test]
+fn test_font_file() {
+    let font = r#"{"-": ["   ", "   ", "---", "   ", "   "]}"#;
+    let path = std::env::temp_dir().join("print-big-text-rs-cli-font.json");
+    std::fs::write(&path, font).unwrap();
+
+    let output = run(&["--font", path.to_str().unwrap()], "--\n");
+    std::fs::remove_file(&path).unwrap();
+
+    let map = serde_json::from_str(font).unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        BigText::new("--", Some(map)).to_string(),
+        String::from_utf8(output.stdout).unwrap()
+    );
+}
+
+#[test]
+fn test_list_fonts() {
+    let dir = std::env::temp_dir().join("print-big-text-rs-cli-fonts");
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("dash.json"), r#"{"-": ["", "", "-", "", ""]}"#).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_print-big-text-rs"))
+        .arg("list-fonts")
+        .env("PRINT_BIG_TEXT_FONTS", &dir)
+        .output()
+        .unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert!(output.status.success());
+    assert!(lines[0].starts_with("printables"));
+    assert!(lines.contains(&"digits             10 characters  built-in"));
+    assert!(lines
+        .iter()
+        .any(|line| line.starts_with("dash                1 characters  ")));
+}
+
+#[test]
+fn test_list_chars() {
+    let output = run(&["--font", "digits", "--list-chars"], "");
+    let digits = BigText::new("0123456789", Some(character_maps::digits()));
+
+    assert!(output.status.success());
+    assert_eq!(
+        format!("Digits:\n{digits}"),
+        String::from_utf8(output.stdout).unwrap()
+    );
+}
+
+#[test]
+fn test_preview() {
+    let output = run(&["preview", "printables", "HI", "--width", "6"], "");
+    let mut printer = BigText::new("HI", None);
+    printer.set_width(Some(6));
+    let expected = printer.to_string() + "\n" + &printer.set_text("0123456789").to_string();
+
+    assert!(output.status.success());
+    assert_eq!(expected, String::from_utf8(output.stdout).unwrap());
+}
+
+#[test]
+fn test_coverage() {
+    let path = std::env::temp_dir().join("print-big-text-rs-cli-coverage.txt");
+    std::fs::write(&path, "HELLO, WORLD!\n").unwrap();
+
+    let output = run(
+        &["coverage", "letters", "--against", path.to_str().unwrap()],
+        "",
+    );
+    let output = String::from_utf8(output.stdout).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(output.contains("Basic Latin"));
+    assert!(output.contains("26/95"));
+    assert!(output.contains("U+002C"));
+    assert!(output.contains("U+0021"));
+    assert!(!output.contains("U+0020"));
+}
+
+#[test]
+fn test_diff() {
+    let output = String::from_utf8(run(&["diff", "digits", "letters"], "").stdout).unwrap();
+    assert!(output.starts_with("only in digits (10 characters):\n  U+0030  0\n"));
+    assert!(output.contains("only in letters (26 characters):\n  U+0041  A\n"));
+
+    let output = String::from_utf8(run(&["diff", "digits", "digits"], "").stdout).unwrap();
+    assert_eq!("digits and digits support the same characters\n", output);
+}
+
+#[test]
+fn test_validate() {
+    let path = std::env::temp_dir().join("print-big-text-rs-cli-validate.json");
+
+    std::fs::write(&path, r#"{"-": ["   ", "   ", "---", "   ", "   "]}"#).unwrap();
+    let output = run(&["validate", path.to_str().unwrap()], "");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(output.status.success());
+    assert!(stdout.starts_with("warning: missing 94 printable ASCII characters"));
+    assert!(stdout.ends_with(": 0 errors, 1 warnings\n"));
+
+    std::fs::write(&path, r#"{"-": ["", "", "---"]}"#).unwrap();
+    let output = run(&["validate", path.to_str().unwrap()], "");
+    std::fs::remove_file(&path).unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(!output.status.success());
+    assert!(stdout.contains("error: '-': has 3 rows, expected 5\n"));
+    assert!(stdout.contains("error: '-': rows have different widths [0, 0, 3]\n"));
+}
+
+#[test]
+fn test_convert() {
+    let path = std::env::temp_dir().join("print-big-text-rs-cli-convert.toml");
+
+    let output = run(&["convert", "digits", path.to_str().unwrap()], "");
+    assert!(output.status.success());
+    let data = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(
+        character_maps::digits(),
+        toml::from_str::<character_maps::CharacterMap>(&data).unwrap()
+    );
+
+    let output = run(&["convert", "digits", "digits.font"], "");
+    assert!(!output.status.success());
+}
+
+#[test]
+fn test_output() {
+    let path = std::env::temp_dir().join("print-big-text-rs-cli-output.txt");
+    let file = path.to_str().unwrap();
+
+    let output = run(&["-o", file, "--color", "#ff0000", "A"], "");
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+    assert_eq!(
+        BigText::new("A", None).to_string(),
+        std::fs::read_to_string(&path).unwrap()
+    );
+
+    run(&["-o", file, "-c", "#ff0000", "-c", "always", "A"], "");
+    let written = std::fs::read_to_string(&path).unwrap();
+    assert!(written.contains("\x1b[38;2;255;0;0m"));
+
+    // --ansi is still accepted as --color always
+    run(&["-o", file, "-c", "#ff0000", "--ansi", "A"], "");
+    let ansi = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(written, ansi);
+}
+
+#[test]
+fn test_color_choice() {
+    let colored = |args: &[&str], variables: &[(&str, &str)]| {
+        let output = Command::new(env!("CARGO_BIN_EXE_print-big-text-rs"))
+            .args(args)
+            .arg("A")
+            .env_remove("NO_COLOR")
+            .env_remove("CLICOLOR")
+            .env_remove("CLICOLOR_FORCE")
+            .envs(variables.iter().copied())
+            .output()
+            .unwrap();
+        String::from_utf8(output.stdout).unwrap().contains('\x1b')
+    };
+
+    // The standard output is a pipe, so it isn't colored by default
+    assert!(!colored(&["-c", "rainbow"], &[]));
+    assert!(colored(&["-c", "rainbow", "-c", "always"], &[]));
+    assert!(colored(&["-c", "rainbow"], &[("CLICOLOR_FORCE", "1")]));
+    assert!(!colored(
+        &["-c", "rainbow"],
+        &[("CLICOLOR_FORCE", "1"), ("NO_COLOR", "1")]
+    ));
+    assert!(!colored(&["-c", "always"], &[]));
+}
+
+#[test]
+fn test_width_align() {
+    let output = run(&["--width", "14", "--align", "center", "HI HI"], "");
+    let mut printer = BigText::new("HI HI", None);
+    printer
+        .set_width(Some(14))
+        .set_alignment(print_big_text_rs::layout::Alignment::Center);
+
+    assert!(output.status.success());
+    assert_eq!(
+        printer.to_string(),
+        String::from_utf8(output.stdout).unwrap()
+    );
+
+    // Pipes aren't terminals, so the text isn't wrapped by default
+    let output = run(&["HI HI"], "");
+    assert_eq!(
+        BigText::new("HI HI", None).to_string(),
+        String::from_utf8(output.stdout).unwrap()
+    );
+}
+
+#[test]
+fn test_follow() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_print-big-text-rs"))
+        .arg("--follow")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut stdin = child.stdin.take().unwrap();
+    let mut stdout = child.stdout.take().unwrap();
+
+    // The banner is read while the standard input is still open
+    let expected = BigText::new("A", None).to_string();
+    stdin.write_all(b"A\n").unwrap();
+    let (sender, receiver) = mpsc::channel();
+    let length = expected.len();
+    thread::spawn(move || {
+        let mut banner = vec![0; length];
+        stdout.read_exact(&mut banner).unwrap();
+        sender.send(banner).unwrap();
+    });
+    let banner = receiver.recv_timeout(Duration::from_secs(10)).unwrap();
+
+    drop(stdin);
+    assert!(child.wait().unwrap().success());
+    assert_eq!(expected, String::from_utf8(banner).unwrap());
+}
+
+#[test]
+fn test_scroll_speed() {
+    let output = run(&["--scroll", "0", "A"], "");
+    assert!(!output.status.success());
+}
+
+#[test]
+fn test_typewriter() {
+    let output = run(&["--typewriter", "--delay", "0", "HI"], "");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    assert!(output.status.success());
+    assert!(stdout.starts_with("\x1b[2K*   * \n"));
+    assert!(stdout.contains("\x1b[5A\x1b[2K*   * ***** \n"));
+}
+
+#[test]
+fn test_matrix_conflicts() {
+    let output = run(&["--matrix", "--scroll", "10", "A"], "");
+    assert!(!output.status.success());
+}
+
+#[test]
+fn test_bounce() {
+    // The animation stops right away when the standard input is closed
+    let output = run(&["--bounce", "HI"], "");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    assert!(output.status.success());
+    assert!(stdout.starts_with("\x1b[?1049h"));
+    assert!(stdout.ends_with("\x1b[?1049l"));
+    assert!(!run(&["--bounce"], "HI").status.success());
+}
+
+#[test]
+fn test_blink() {
+    let output = run(&["I", "--blink", "--color", "always"], "");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.starts_with("\x1b[5m***** \x1b[25m\n"));
+
+    // Blinking is an escape code too, so it is left out with the colors
+    let output = run(&["I", "--blink", "--color", "never"], "");
+    assert_eq!(run(&["I"], "").stdout, output.stdout);
+    let output = run(&["I", "--blink"], "");
+    assert_eq!(run(&["I"], "").stdout, output.stdout);
+
+    let output = run(&["--blink", "software", "I"], "");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(output.status.success());
+    assert!(stdout.ends_with("\x1b[?1049l"));
+}
+
+#[test]
+fn test_fallback() {
+    let output = run(&["-f", "letters", "--fallback", "digits", "A1"], "");
+    assert_eq!(run(&["A1"], "").stdout, output.stdout);
+}
+
+#[test]
+fn test_subst() {
+    assert_eq!(
+        run(&["H3LL0"], "").stdout,
+        run(&["--subst", "E=3,O=0", "HELLO"], "").stdout
+    );
+    assert_eq!(
+        run(&["1337"], "").stdout,
+        run(&["--subst", "leet", "LEET"], "").stdout
+    );
+    assert!(!run(&["--subst", "E3", "HELLO"], "").status.success());
+}
+
+#[test]
+fn test_uppercase() {
+    assert_eq!(
+        run(&["STRASSE"], "").stdout,
+        run(&["-u", "straße"], "").stdout
+    );
+}
+
+#[test]
+fn test_ignore_case() {
+    assert_eq!(run(&["HI"], "").stdout, run(&["-i", "hi"], "").stdout);
+}
+
+#[test]
+fn test_rtl() {
+    assert_eq!(
+        run(&["(BA)"], "").stdout,
+        run(&["(AB)", "--rtl"], "").stdout
+    );
+}
+
+#[test]
+fn test_line_endings() {
+    let expected = String::from_utf8(run(&["A", "1"], "").stdout).unwrap();
+
+    let output = run(&["A", "1", "--crlf"], "").stdout;
+    assert_eq!(
+        expected.replace('\n', "\r\n"),
+        String::from_utf8(output).unwrap()
+    );
+
+    let output = run(&["A", "1", "--no-trailing-newline"], "").stdout;
+    assert_eq!(
+        expected.trim_end_matches('\n'),
+        String::from_utf8(output).unwrap()
+    );
+}
+
+#[test]
+fn test_line_prefix() {
+    let output = run(&["I", "--line-prefix", "# ", "--line-suffix", " #"], "");
+    let output = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(5, output.lines().count());
+    assert!(output
+        .lines()
+        .all(|row| row.starts_with("# ") && row.ends_with(" #")));
+}
+
+#[test]
+fn test_comment() {
+    let output = String::from_utf8(run(&["HI", "--comment", "python"], "").stdout).unwrap();
+    let rows: Vec<&str> = output.lines().collect();
+    assert_eq!(7, rows.len());
+    assert!(rows.iter().all(|row| row.starts_with('#')));
+    assert!(rows[0].starts_with("# ==="));
+
+    let output = String::from_utf8(run(&["HI", "--comment", "c"], "").stdout).unwrap();
+    assert!(output.starts_with("/* ==="));
+    assert!(output.ends_with("=== */\n"));
+
+    let output = String::from_utf8(run(&["I", "--comment", "rust", "--crlf"], "").stdout).unwrap();
+    assert_eq!(7, output.matches("\r\n").count());
+    assert!(!run(&["I", "--comment", "rust", "--line-prefix", "//"], "")
+        .status
+        .success());
+}
+
+#[test]
+fn test_center() {
+    let output = run(&["I", "--center", "--width", "20"], "");
+    let mut printer = BigText::new("I", None);
+    printer.set_width(Some(20));
+    assert_eq!(
+        layout::center(&printer, 20, 24),
+        String::from_utf8(output.stdout).unwrap()
+    );
+
+    let output = String::from_utf8(run(&["I", "--center", "--crlf"], "").stdout).unwrap();
+    assert_eq!(output.matches('\n').count(), output.matches("\r\n").count());
+    assert!(output.ends_with("***** \r\n"));
+}
+
+#[test]
+fn test_strict() {
+    let output = run(&["A~B", "--strict"], "");
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    assert_eq!(
+        "error: unsupported characters: '~' at 1\n",
+        String::from_utf8(output.stderr).unwrap()
+    );
+
+    assert!(run(&["AB", "--strict"], "").status.success());
+
+    // Nothing is written for the rejected banner, not even the separator
+    let output = run(&["--strict"], "A\n~\n");
+    assert!(!output.status.success());
+    assert_eq!(run(&["A"], "").stdout, output.stdout);
+
+    for mode in [&["--comment", "rust"][..], &["--typewriter"], &["--matrix"]] {
+        let output = run(&[&["A~", "--strict"], mode].concat(), "");
+        assert!(!output.status.success());
+        assert!(output.stdout.is_empty());
+    }
+}
+
+#[test]
+fn test_normalize() {
+    let expected = run(&["CAFE"], "").stdout;
+    assert_eq!(expected, run(&["CAFÉ", "--normalize", "nfkd"], "").stdout);
+    assert_eq!(expected, run(&["CAFÉ", "--strip-diacritics"], "").stdout);
+    assert_ne!(expected, run(&["CAFÉ"], "").stdout);
+}
+
+#[test]
+fn test_config() {
+    let dir = std::env::temp_dir().join("print-big-text-rs-cli-config");
+    std::fs::create_dir_all(dir.join("print-big-text")).unwrap();
+    let config = dir.join("print-big-text").join("config.toml");
+    let run_with_config = |args: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_print-big-text-rs"))
+            .args(args)
+            .env("XDG_CONFIG_HOME", &dir)
+            .env_remove("NO_COLOR")
+            .env_remove("CLICOLOR_FORCE")
+            .output()
+            .unwrap()
+    };
+
+    std::fs::write(
+        &config,
+        "font = \"digits\"\nspacing = 2\ncolor = [\"#ff0000\", \"always\"]\n",
+    )
+    .unwrap();
+    let mut printer = BigText::new("1A", Some(character_maps::digits()));
+    printer.set_spacing(2);
+
+    // Flags override the config
+    let output = run_with_config(&["--color", "never", "1A"]);
+    assert_eq!(
+        printer.to_string(),
+        String::from_utf8(output.stdout).unwrap()
+    );
+    let output = run_with_config(&["1A"]);
+    assert!(String::from_utf8(output.stdout).unwrap().contains('\x1b'));
+
+    std::fs::write(&config, "fonts = \"digits\"\n").unwrap();
+    let output = run_with_config(&["1A"]);
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert!(!output.status.success());
+}
+
+#[test]
+fn test_completions() {
+    let dir = std::env::temp_dir().join("print-big-text-rs-cli-completions");
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("dashes.json"), r#"{"-": ["", "", "-", "", ""]}"#).unwrap();
+
+    for shell in ["bash", "zsh", "fish"] {
+        let output = Command::new(env!("CARGO_BIN_EXE_print-big-text-rs"))
+            .args(["completions", shell])
+            .env("PRINT_BIG_TEXT_FONTS", &dir)
+            .output()
+            .unwrap();
+        let script = String::from_utf8(output.stdout).unwrap();
+
+        assert!(output.status.success(), "{shell}");
+        assert!(script.contains("punctuation"), "{shell}");
+        assert!(script.contains("dashes"), "{shell}");
+    }
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_font_env() {
+    let run_with_font = |args: &[&str]| {
+        let output = Command::new(env!("CARGO_BIN_EXE_print-big-text-rs"))
+            .args(args)
+            .env("PRINT_BIG_TEXT_FONT", "digits")
+            .output()
+            .unwrap();
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    let digits = BigText::new("1A", Some(character_maps::digits()));
+    assert_eq!(digits.to_string(), run_with_font(&["1A"]));
+    assert_eq!(
+        BigText::new("1A", None).to_string(),
+        run_with_font(&["--font", "printables", "1A"])
+    );
+}
+
+#[test]
+fn test_font_discovery() {
+    let dir = std::env::temp_dir().join("print-big-text-rs-cli-discovery");
+    let data_fonts = dir.join("data").join("print-big-text").join("fonts");
+    let config_fonts = dir.join("config-fonts");
+    std::fs::create_dir_all(&data_fonts).unwrap();
+    std::fs::create_dir_all(&config_fonts).unwrap();
+    std::fs::create_dir_all(dir.join("print-big-text")).unwrap();
+
+    let font = |row: &str| format!(r#"{{"-": ["   ", "   ", "{row}", "   ", "   "]}}"#);
+    std::fs::write(data_fonts.join("dash.json"), font("---")).unwrap();
+    std::fs::write(data_fonts.join("equal.json"), font("===")).unwrap();
+    // Directories in the config are searched first
+    std::fs::write(config_fonts.join("equal.json"), font("≡≡≡")).unwrap();
+    std::fs::write(
+        dir.join("print-big-text").join("config.toml"),
+        format!("font_dirs = [{:?}]\n", config_fonts.to_str().unwrap()),
+    )
+    .unwrap();
+
+    let run_with_dirs = |args: &[&str]| {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_print-big-text-rs"))
+            .args(args)
+            .env("XDG_DATA_HOME", dir.join("data"))
+            .env("XDG_CONFIG_HOME", &dir)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+        child.stdin.take().unwrap().write_all(b"-\n").unwrap();
+        String::from_utf8(child.wait_with_output().unwrap().stdout).unwrap()
+    };
+
+    let dash = run_with_dirs(&["--font", "dash"]);
+    let equal = run_with_dirs(&["--font", "equal"]);
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!("---", dash.lines().nth(2).unwrap().trim());
+    assert_eq!("≡≡≡", equal.lines().nth(2).unwrap().trim());
+}
+
+#[cfg(feature = "kitty")]
+#[test]
+fn test_kitty_pipe() {
+    // Pipes aren't terminals, even in a terminal supporting the protocol
+    let output = Command::new(env!("CARGO_BIN_EXE_print-big-text-rs"))
+        .args(["--kitty", "--color", "#ff0000", "HI"])
+        .env("KITTY_WINDOW_ID", "1")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(
+        BigText::new("HI", None).to_string(),
+        String::from_utf8(output.stdout).unwrap()
+    );
+}
+
+#[cfg(feature = "anstream")]
+#[test]
+fn test_anstream_colors() {
+    // Colors forced with --color are kept by the wrapped standard output
+    let output = run(&["--color", "#ff0000", "--color", "always", "I"], "");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(5, stdout.matches("\x1b[38;2;255;0;0m").count());
+
+    let output = run(&["--color", "#ff0000", "I"], "");
+    assert_eq!(
+        BigText::new("I", None).to_string(),
+        String::from_utf8(output.stdout).unwrap()
+    );
+}
@@ -1,6 +1,24 @@
-use std::collections::HashMap;
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    thread,
+};
 
-use print_big_text_rs::BigText;
+#[cfg(feature = "serde")]
+use print_big_text_rs::spec::{BannerSpec, SpecError};
+use print_big_text_rs::{
+    character_maps::{self, CharacterMap, Fallback, GlyphProvider, SequenceMap},
+    color::{ColorStyle, Rgb},
+    layout::{Alignment, OffsetPattern},
+    printer::BigTextPrinter,
+    raster::RasterOptions,
+    svg::{SvgMode, SvgOptions},
+    BigText,
+};
 
 #[test]
 fn test_text() {
@@ -10,7 +28,10 @@ fn test_text() {
 
 #[test]
 fn test_supported_characters() {
-    let _printer = BigText::new("Ab1", None);
+    let printer = BigText::new("Ab1", None);
+    let first = printer.supported_characters();
+    assert_eq!(first, BigText::new("Ab1", None).supported_characters());
+    assert!(first.starts_with(" !\"#"));
 }
 
 #[test]
@@ -35,35 +56,35 @@ fn test_print() -> Result<(), std::io::Error> {
 
 #[test]
 fn test_character_map() {
-    let map: HashMap<char, [String; 5]> = HashMap::from([
+    let map: CharacterMap = HashMap::from([
         (
             'A',
             [
-                String::from("     "),
-                String::from("     "),
-                String::from("     "),
-                String::from("     "),
-                String::from("     "),
+                String::from("     ").into(),
+                String::from("     ").into(),
+                String::from("     ").into(),
+                String::from("     ").into(),
+                String::from("     ").into(),
             ],
         ),
         (
             '1',
             [
-                String::from("     "),
-                String::from("     "),
-                String::from("     "),
-                String::from("     "),
-                String::from("     "),
+                String::from("     ").into(),
+                String::from("     ").into(),
+                String::from("     ").into(),
+                String::from("     ").into(),
+                String::from("     ").into(),
             ],
         ),
         (
             '"',
             [
-                String::from("     "),
-                String::from("     "),
-                String::from("     "),
-                String::from("     "),
-                String::from("     "),
+                String::from("     ").into(),
+                String::from("     ").into(),
+                String::from("     ").into(),
+                String::from("     ").into(),
+                String::from("     ").into(),
             ],
         ),
     ]);
@@ -73,35 +94,35 @@ fn test_character_map() {
 
 #[test]
 fn test_set_character_map() {
-    let map: HashMap<char, [String; 5]> = HashMap::from([
+    let map: CharacterMap = HashMap::from([
         (
             'A',
             [
-                String::from("     "),
-                String::from("     "),
-                String::from("     "),
-                String::from("     "),
-                String::from("     "),
+                String::from("     ").into(),
+                String::from("     ").into(),
+                String::from("     ").into(),
+                String::from("     ").into(),
+                String::from("     ").into(),
             ],
         ),
         (
             '1',
             [
-                String::from("     "),
-                String::from("     "),
-                String::from("     "),
-                String::from("     "),
-                String::from("     "),
+                String::from("     ").into(),
+                String::from("     ").into(),
+                String::from("     ").into(),
+                String::from("     ").into(),
+                String::from("     ").into(),
             ],
         ),
         (
             '"',
             [
-                String::from("     "),
-                String::from("     "),
-                String::from("     "),
-                String::from("     "),
-                String::from("     "),
+                String::from("     ").into(),
+                String::from("     ").into(),
+                String::from("     ").into(),
+                String::from("     ").into(),
+                String::from("     ").into(),
             ],
         ),
     ]);
@@ -109,3 +130,338 @@ fn test_set_character_map() {
     printer.set_character_map(map.clone());
     assert_eq!(&map, printer.character_map())
 }
+
+#[test]
+fn test_offset_pattern() {
+    let mut printer = BigText::new("AA", None);
+    printer.set_offset_pattern(OffsetPattern::Alternating(2));
+    let output = printer.to_string();
+
+    assert_eq!(7, output.lines().count());
+    assert_eq!(" ***        ", output.lines().next().unwrap());
+    assert_eq!("*****  ***  ", output.lines().nth(2).unwrap());
+    assert_eq!("      *   * ", output.lines().last().unwrap());
+}
+
+#[test]
+fn test_map_sources() {
+    let total: usize = character_maps::sources()
+        .iter()
+        // The maps combined by the printables
+        .take(5)
+        .map(|source| source.character_count())
+        .sum();
+    assert_eq!(character_maps::printables().len(), total);
+    assert_eq!(
+        Some(character_maps::punctuation()),
+        character_maps::from_name("punctuation")
+    );
+}
+
+#[test]
+fn test_fallback_maps() {
+    let decorative = CharacterMap::from([('A', [0; 5].map(|_| Cow::Borrowed("#####")))]);
+    let mut printer = BigText::new("AB1?", Some(decorative));
+    printer
+        .add_fallback_map(character_maps::ascii_letters())
+        .add_fallback_map(character_maps::printables());
+
+    // The first map supporting a character is used
+    assert_eq!(
+        "##### ****      * ",
+        &printer.to_string().lines().next().unwrap()[..18]
+    );
+    assert_eq!(
+        character_maps::printables().len(),
+        printer.supported_characters().chars().count()
+    );
+}
+
+#[test]
+fn test_fallback_maps_after_provider() {
+    let decorative = CharacterMap::from([('A', [0; 5].map(|_| Cow::Borrowed("#####")))]);
+    let provider: Arc<dyn GlyphProvider> = Arc::new(Fallback::new(vec![Box::new(decorative)]));
+    let mut printer = BigText::new("AB1", Some(character_maps::digits()));
+    printer
+        .add_fallback_map(character_maps::printables())
+        .set_glyph_provider(Some(provider));
+
+    // The provider replaces the character map, the fallback maps are looked up after it
+    assert_eq!(
+        "##### ****      * ",
+        &printer.to_string().lines().next().unwrap()[..18]
+    );
+    assert!(printer.supported_characters().contains('A'));
+    assert!(printer.chars_missing_in("AB1").is_empty());
+
+    printer.clear_fallback_maps();
+    assert_eq!("A", printer.supported_characters());
+    assert_eq!(vec!['B', '1'], printer.chars_missing_in("AB1"));
+}
+
+#[test]
+fn test_sequences() {
+    let glyph = |row: &str| [0; 5].map(|_| Cow::Owned(String::from(row)));
+    let mut printer = BigText::new("A\u{1F469}\u{200D}\u{1F4BB}\u{1F469}", None);
+    printer.set_sequence_map(SequenceMap::from([
+        (String::from("\u{1F469}"), glyph("W")),
+        (String::from("\u{1F469}\u{200D}\u{1F4BB}"), glyph("WC")),
+    ]));
+
+    // The longest sequence is matched, the joiner isn't skipped
+    assert_eq!(" ***  WC W ", printer.to_string().lines().next().unwrap());
+    assert!(printer.chars_missing().is_empty());
+    assert_eq!(vec!['\u{1F4BB}'], printer.chars_missing_in("\u{1F4BB}A"));
+}
+
+#[test]
+fn test_symbols() {
+    let mut printer = BigText::new("├─┤", Some(character_maps::symbols()));
+    printer.set_spacing(0);
+    let output = printer.to_string();
+
+    assert_eq!("  *         *  ", output.lines().next().unwrap());
+    assert_eq!("  ***********  ", output.lines().nth(2).unwrap());
+}
+
+#[cfg(feature = "kana")]
+#[test]
+fn test_kana_width() {
+    // Kana take up as many columns as unsupported full-width characters
+    let blank = BigText::new("アー", Some(character_maps::ascii_letters())).to_string();
+    let kana = BigText::new("アー", Some(character_maps::katakana())).to_string();
+    assert_eq!(
+        blank.lines().next().unwrap().len(),
+        kana.lines().next().unwrap().len()
+    );
+}
+
+#[test]
+fn test_to_svg() {
+    let printer = BigText::new("I", None);
+    let options = SvgOptions {
+        cell_width: 2,
+        cell_height: 4,
+        foreground: Rgb::new(255, 0, 0),
+        background: Some(Rgb::WHITE),
+        mode: SvgMode::Rects,
+    };
+    let svg = printer.to_svg(&options);
+
+    assert!(svg.contains(r#"width="12" height="20""#));
+    assert!(svg.contains(r##"<g fill="#ff0000">"##));
+    assert!(svg.contains(r##"fill="#ffffff""##));
+    assert_eq!(
+        printer.to_string().matches('*').count(),
+        svg.matches("<rect x=").count()
+    );
+
+    let svg = printer.to_svg(&SvgOptions {
+        mode: SvgMode::Text,
+        ..Default::default()
+    });
+    assert_eq!(5, svg.matches("<text").count());
+}
+
+#[test]
+fn test_zero_width_characters() {
+    let mut printer = BigText::new("A\u{200D}1\u{0308}", None);
+    assert_eq!(BigText::new("A1", None).to_string(), printer.to_string());
+
+    static SKIPPED: AtomicUsize = AtomicUsize::new(0);
+    printer.set_zero_width_hook(Some(|_, _| {
+        SKIPPED.fetch_add(1, Ordering::SeqCst);
+    }));
+    printer.to_string();
+    assert_eq!(2, SKIPPED.load(Ordering::SeqCst));
+}
+
+#[test]
+fn test_rasterize() {
+    let printer = BigText::new("A", None);
+    let options = RasterOptions {
+        cell_width: 2,
+        cell_height: 3,
+        foreground: Rgb::new(1, 2, 3),
+        background: None,
+    };
+    let raster = printer.rasterize(&options).unwrap();
+
+    assert_eq!(12, raster.width());
+    assert_eq!(15, raster.height());
+    // The top left cell of "A" is empty and the one next to it is filled
+    assert_eq!(&[0, 0, 0, 0], &raster.pixels()[0..4]);
+    assert_eq!(&[1, 2, 3, 255], &raster.pixels()[8..12]);
+}
+
+#[cfg(feature = "kitty")]
+#[test]
+fn test_print_kitty_chunks() -> Result<(), std::io::Error> {
+    let mut vec = Vec::new();
+    let printer = BigText::new("HELLO WORLD", None);
+    let options = RasterOptions {
+        cell_width: 4,
+        cell_height: 4,
+        ..Default::default()
+    };
+    printer.print_kitty(Some(&mut vec), &options)?;
+    let output = String::from_utf8(vec).unwrap_or_default();
+
+    assert!(output.contains("\x1b_Gm=1;"));
+    assert!(output.contains("\x1b_Gm=0;"));
+    assert!(output.ends_with("\x1b\\\n"));
+    Ok(())
+}
+
+#[test]
+fn test_color() {
+    let mut printer = BigText::new("I", None);
+    printer.set_color(Some(ColorStyle::Solid(Rgb::new(255, 0, 0))));
+    let output = printer.to_string();
+
+    assert_eq!(5, output.matches("\x1b[38;2;255;0;0m").count());
+    assert_eq!(5, output.matches("\x1b[0m").count());
+
+    printer.set_color(None);
+    assert!(!printer.to_string().contains('\x1b'));
+}
+
+#[test]
+fn test_markdown_blocks() {
+    let printer = BigText::new("ABCDEFGH", None);
+    let blocks = printer.to_markdown_blocks(120);
+    let texts: usize = blocks
+        .iter()
+        .map(|block| block.lines().nth(1).unwrap().len() / 6)
+        .sum();
+
+    assert!(blocks.len() > 1);
+    assert_eq!(8, texts);
+    assert!(blocks.iter().all(|block| block.starts_with("```text\n")));
+    assert_eq!(
+        vec![printer.to_markdown()],
+        printer.to_markdown_blocks(10_000)
+    );
+}
+
+#[test]
+fn test_concurrent_printer() {
+    let printer = BigTextPrinter::new(Vec::new());
+    let handles: Vec<_> = ["A", "1", "?"]
+        .into_iter()
+        .map(|text| {
+            let printer = printer.clone();
+            thread::spawn(move || {
+                for _ in 0..20 {
+                    printer.print(&BigText::new(text, None)).unwrap();
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    let output = String::from_utf8(printer.into_inner().unwrap()).unwrap();
+    let lines: Vec<&str> = output.lines().collect();
+    let banners: Vec<String> = ["A", "1", "?"]
+        .iter()
+        .map(|text| BigText::new(text, None).to_string())
+        .collect();
+
+    assert_eq!(60 * 5, lines.len());
+    for chunk in lines.chunks(5) {
+        let banner = chunk.join("\n") + "\n";
+        assert!(banners.contains(&banner));
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_banner_spec() {
+    let spec = BannerSpec::from_json(
+        r##"{
+            "layout": "horizontal",
+            "gap": 2,
+            "blocks": [
+                {"text": "1", "font": "digits"},
+                {"text": "AB", "color": "#ff0000"}
+            ]
+        }"##,
+    )
+    .unwrap();
+    let rendered = spec.render().unwrap();
+    let first = rendered.lines().next().unwrap();
+
+    assert_eq!(5, rendered.lines().count());
+    assert!(first.starts_with("    *    \x1b[38;2;255;0;0m"));
+
+    let spec = BannerSpec::from_toml("[[blocks]]\ntext = \"A\"\nfont = \"nope\"").unwrap();
+    assert!(matches!(spec.render(), Err(SpecError::UnknownFont(_))));
+}
+
+#[cfg(feature = "ratatui")]
+#[test]
+fn test_widget_clipping() {
+    use ratatui::{buffer::Buffer, layout::Rect, style::Color, widgets::Widget};
+
+    let mut printer = BigText::new("AA", None);
+    printer.set_color(Some(ColorStyle::Solid(Rgb::new(0, 255, 0))));
+    let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 3));
+    printer.render(Rect::new(1, 1, 7, 2), &mut buffer);
+
+    let lines: Vec<String> = (0..3)
+        .map(|y| (0..8).map(|x| buffer[(x, y)].symbol()).collect())
+        .collect();
+    assert_eq!(vec!["        ", "  ***   ", " *   * *"], lines);
+    assert_eq!(Color::Rgb(0, 255, 0), buffer[(2, 1)].fg);
+    assert_eq!(" ", buffer[(0, 2)].symbol());
+}
+
+#[test]
+fn test_wrapping_and_alignment() {
+    let mut printer = BigText::new("AB CD\nE", None);
+    printer
+        .set_width(Some(15))
+        .set_alignment(Alignment::Center)
+        .set_spacing(2);
+    let output = printer.to_string();
+    let lines: Vec<&str> = output.lines().collect();
+
+    // "AB", "CD" and "E" on their own lines, separated by blank rows
+    assert_eq!(17, lines.len());
+    assert_eq!(" ***   ****   ", lines[0]);
+    assert_eq!("", lines[5]);
+    assert_eq!("  ***  ***    ", lines[6]);
+    assert_eq!("    *****  ", lines[12]);
+}
+
+#[test]
+fn test_rows() {
+    let mut printer = BigText::new("AB CD\nE", None);
+    printer
+        .set_alignment(Alignment::Right)
+        .set_offset_pattern(OffsetPattern::Wave { amplitude: 2 });
+    let rows: Vec<String> = printer.rows().collect();
+    assert_eq!(printer.to_string(), rows.join("\n") + "\n");
+
+    printer.set_color(Some(ColorStyle::Solid(Rgb::new(255, 0, 0))));
+    let rows: Vec<String> = printer.rows().collect();
+    assert_eq!(printer.to_string(), rows.join("\n") + "\n");
+}
+
+#[test]
+fn test_rendered_size() {
+    let mut printer = BigText::new("AB CD\nE", None);
+    printer
+        .set_width(Some(15))
+        .set_offset_pattern(OffsetPattern::Wave { amplitude: 2 })
+        .set_line_prefix("# ");
+    let output = printer.to_string();
+
+    assert_eq!(output.lines().count(), printer.rendered_height());
+    assert_eq!(
+        output.lines().map(|row| row.chars().count()).max(),
+        Some(printer.rendered_width())
+    );
+}